@@ -0,0 +1,168 @@
+use ::anstyle::{Color as AnstyleColor, Effects, RgbColor, Style};
+
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::style::TextStyle;
+
+pub fn to_anstyle_color(color: &Color) -> AnstyleColor {
+    AnstyleColor::Rgb(RgbColor(color.r, color.g, color.b))
+}
+
+/// Map text style flags to anstyle effects.
+pub fn to_anstyle_effects(style: &TextStyle) -> Effects {
+    let flags = [
+        (style.bold, Effects::BOLD),
+        (style.italic, Effects::ITALIC),
+        (style.underline, Effects::UNDERLINE),
+        (style.undercurl, Effects::CURLY_UNDERLINE),
+        (style.strikethrough, Effects::STRIKETHROUGH),
+    ];
+    flags
+        .into_iter()
+        .filter(|(set, _)| *set)
+        .fold(Effects::new(), |acc, (_, e)| acc | e)
+}
+
+macro_rules! anstyle_group {
+    ($color_type:ident, $style_type:ident { $($field:ident),+ $(,)? }) => {
+        paste::paste! {
+            #[derive(Debug, Clone)]
+            pub struct [<Anstyle $color_type>] {
+                $(pub $field: Option<AnstyleColor>,)+
+            }
+
+            impl [<Anstyle $color_type>] {
+                fn from_palette(group: &crate::palette::$color_type) -> Self {
+                    Self {
+                        $($field: group.$field.as_ref().map(to_anstyle_color),)+
+                    }
+                }
+            }
+
+            /// Per-slot effects; `Effects::new()` where no style is set.
+            #[derive(Debug, Clone)]
+            pub struct [<Anstyle $style_type>] {
+                $(pub $field: Effects,)+
+            }
+
+            impl [<Anstyle $style_type>] {
+                fn from_palette(group: &crate::palette::$style_type) -> Self {
+                    Self {
+                        $($field: group.$field.map_or(Effects::new(), |s| to_anstyle_effects(&s)),)+
+                    }
+                }
+            }
+        }
+    };
+}
+
+crate::palette::color_fields!(anstyle_group);
+
+#[derive(Debug, Clone)]
+pub struct AnstyleTheme {
+    pub base: AnstyleBaseColors,
+    pub semantic: AnstyleSemanticColors,
+    pub diff: AnstyleDiffColors,
+    pub surface: AnstyleSurfaceColors,
+    pub typography: AnstyleTypographyColors,
+    pub syntax: AnstyleSyntaxColors,
+    pub editor: AnstyleEditorColors,
+    pub terminal_ansi: AnstyleTerminalAnsiColors,
+    pub effects: AnstyleEffects,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnstyleEffects {
+    pub base: AnstyleBaseStyles,
+    pub semantic: AnstyleSemanticStyles,
+    pub diff: AnstyleDiffStyles,
+    pub surface: AnstyleSurfaceStyles,
+    pub typography: AnstyleTypographyStyles,
+    pub syntax: AnstyleSyntaxStyles,
+    pub editor: AnstyleEditorStyles,
+    pub terminal_ansi: AnstyleTerminalAnsiStyles,
+}
+
+pub fn to_anstyle_theme(palette: &Palette) -> AnstyleTheme {
+    AnstyleTheme {
+        base: AnstyleBaseColors::from_palette(&palette.base),
+        semantic: AnstyleSemanticColors::from_palette(&palette.semantic),
+        diff: AnstyleDiffColors::from_palette(&palette.diff),
+        surface: AnstyleSurfaceColors::from_palette(&palette.surface),
+        typography: AnstyleTypographyColors::from_palette(&palette.typography),
+        syntax: AnstyleSyntaxColors::from_palette(&palette.syntax),
+        editor: AnstyleEditorColors::from_palette(&palette.editor),
+        terminal_ansi: AnstyleTerminalAnsiColors::from_palette(&palette.terminal_ansi),
+        effects: AnstyleEffects {
+            base: AnstyleBaseStyles::from_palette(&palette.styles.base),
+            semantic: AnstyleSemanticStyles::from_palette(&palette.styles.semantic),
+            diff: AnstyleDiffStyles::from_palette(&palette.styles.diff),
+            surface: AnstyleSurfaceStyles::from_palette(&palette.styles.surface),
+            typography: AnstyleTypographyStyles::from_palette(&palette.styles.typography),
+            syntax: AnstyleSyntaxStyles::from_palette(&palette.styles.syntax),
+            editor: AnstyleEditorStyles::from_palette(&palette.styles.editor),
+            terminal_ansi: AnstyleTerminalAnsiStyles::from_palette(&palette.styles.terminal_ansi),
+        },
+    }
+}
+
+/// Foreground and effects for one slot, e.g. `("semantic", "error")`.
+pub fn slot_style(palette: &Palette, section: &str, field: &str) -> Style {
+    let effects = palette.styles.get(section, field).map_or(Effects::new(), to_anstyle_effects);
+    Style::new()
+        .fg_color(palette.slot(section, field).map(to_anstyle_color))
+        .effects(effects)
+}
+
+/// Styles for CLI help and log output.
+///
+/// The first eight fields line up with `clap::builder::Styles`:
+///
+/// ```ignore
+/// let s = palette_core::anstyle::to_cli_styles(&palette);
+/// let styles = clap::builder::Styles::styled()
+///     .header(s.header)
+///     .usage(s.usage)
+///     .literal(s.literal)
+///     .placeholder(s.placeholder)
+///     .error(s.error)
+///     .valid(s.valid)
+///     .invalid(s.invalid)
+///     .context(s.context);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CliStyles {
+    pub header: Style,
+    pub usage: Style,
+    pub literal: Style,
+    pub placeholder: Style,
+    pub error: Style,
+    pub valid: Style,
+    pub invalid: Style,
+    pub context: Style,
+    pub warning: Style,
+    pub info: Style,
+    pub debug: Style,
+}
+
+pub fn to_cli_styles(palette: &Palette) -> CliStyles {
+    let fg = |colors: &[Option<Color>]| {
+        let color = colors.iter().find_map(|c| c.as_ref());
+        Style::new().fg_color(color.map(to_anstyle_color))
+    };
+    let header = fg(&[palette.typography.title, palette.syntax.keywords]).bold();
+
+    CliStyles {
+        header,
+        usage: header,
+        literal: fg(&[palette.syntax.functions, palette.syntax.keywords]).bold(),
+        placeholder: fg(&[palette.syntax.parameters, palette.syntax.variables]),
+        error: fg(&[palette.semantic.error]).bold(),
+        valid: fg(&[palette.semantic.success]),
+        invalid: fg(&[palette.semantic.warning]),
+        context: fg(&[palette.typography.comment, palette.base.foreground_dark]),
+        warning: fg(&[palette.semantic.warning]).bold(),
+        info: fg(&[palette.semantic.info]),
+        debug: fg(&[palette.semantic.hint, palette.base.foreground_dark]),
+    }
+}
@@ -0,0 +1,380 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::contrast::SlotRef;
+use crate::error::PaletteError;
+use crate::manifest::PaletteManifest;
+use crate::manipulation::BlendSpace;
+use crate::palette::Palette;
+
+/// Where the Base24 extension slots fall back to in a plain Base16 scheme:
+/// `base10`/`base11` to `base00`, the bright colors to their normal ones.
+const BASE24_FALLBACK: [usize; 8] = [0x00, 0x00, 0x08, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E];
+
+/// Manifest slots filled from a scheme, following the Base16 styling
+/// guidelines. Indices `0x10..=0x17` are Base24 slots; see [`Base16Scheme::color`].
+const IMPORT_SLOTS: &[(&str, &str, usize)] = &[
+    ("base", "background", 0x00),
+    ("base", "background_dark", 0x10),
+    ("base", "background_highlight", 0x01),
+    ("base", "foreground", 0x05),
+    ("base", "foreground_dark", 0x04),
+    ("base", "border", 0x01),
+    ("base", "border_highlight", 0x0D),
+    ("semantic", "success", 0x0B),
+    ("semantic", "warning", 0x0A),
+    ("semantic", "error", 0x08),
+    ("semantic", "info", 0x0D),
+    ("semantic", "hint", 0x0C),
+    ("diff", "added", 0x0B),
+    ("diff", "added_fg", 0x0B),
+    ("diff", "modified", 0x0E),
+    ("diff", "modified_fg", 0x0E),
+    ("diff", "removed", 0x08),
+    ("diff", "removed_fg", 0x08),
+    ("diff", "ignored", 0x03),
+    ("surface", "menu", 0x01),
+    ("surface", "sidebar", 0x01),
+    ("surface", "statusline", 0x01),
+    ("surface", "float", 0x01),
+    ("surface", "popup", 0x01),
+    ("surface", "overlay", 0x02),
+    ("surface", "highlight", 0x01),
+    ("surface", "selection", 0x02),
+    ("surface", "search", 0x0A),
+    ("typography", "comment", 0x03),
+    ("typography", "gutter", 0x03),
+    ("typography", "line_number", 0x03),
+    ("typography", "selection_text", 0x05),
+    ("typography", "link", 0x09),
+    ("typography", "title", 0x0D),
+    ("syntax", "keywords", 0x0E),
+    ("syntax", "keywords_fn", 0x0E),
+    ("syntax", "functions", 0x0D),
+    ("syntax", "variables", 0x08),
+    ("syntax", "variables_builtin", 0x08),
+    ("syntax", "parameters", 0x08),
+    ("syntax", "properties", 0x08),
+    ("syntax", "types", 0x0A),
+    ("syntax", "types_builtin", 0x0A),
+    ("syntax", "constants", 0x09),
+    ("syntax", "numbers", 0x09),
+    ("syntax", "booleans", 0x09),
+    ("syntax", "strings", 0x0B),
+    ("syntax", "strings_doc", 0x0B),
+    ("syntax", "strings_escape", 0x0C),
+    ("syntax", "strings_regex", 0x0C),
+    ("syntax", "operators", 0x05),
+    ("syntax", "punctuation", 0x05),
+    ("syntax", "punctuation_bracket", 0x05),
+    ("syntax", "annotations", 0x0A),
+    ("syntax", "attributes", 0x09),
+    ("syntax", "constructor", 0x0A),
+    ("syntax", "tag", 0x08),
+    ("syntax", "tag_delimiter", 0x0F),
+    ("syntax", "tag_attribute", 0x09),
+    ("syntax", "comments", 0x03),
+    ("editor", "cursor", 0x05),
+    ("editor", "cursor_text", 0x00),
+    ("editor", "match_paren", 0x03),
+    ("editor", "selection_bg", 0x02),
+    ("editor", "selection_fg", 0x05),
+    ("editor", "inlay_hint_bg", 0x01),
+    ("editor", "inlay_hint_fg", 0x03),
+    ("editor", "search_bg", 0x0A),
+    ("editor", "search_fg", 0x00),
+    ("editor", "diagnostic_error", 0x08),
+    ("editor", "diagnostic_warn", 0x0A),
+    ("editor", "diagnostic_info", 0x0D),
+    ("editor", "diagnostic_hint", 0x0C),
+    ("editor", "diagnostic_underline_error", 0x08),
+    ("editor", "diagnostic_underline_warn", 0x0A),
+    ("editor", "diagnostic_underline_info", 0x0D),
+    ("editor", "diagnostic_underline_hint", 0x0C),
+    // ANSI order as in base16-shell
+    ("terminal", "black", 0x00),
+    ("terminal", "red", 0x08),
+    ("terminal", "green", 0x0B),
+    ("terminal", "yellow", 0x0A),
+    ("terminal", "blue", 0x0D),
+    ("terminal", "magenta", 0x0E),
+    ("terminal", "cyan", 0x0C),
+    ("terminal", "white", 0x05),
+    ("terminal", "bright_black", 0x03),
+    ("terminal", "bright_red", 0x12),
+    ("terminal", "bright_green", 0x14),
+    ("terminal", "bright_yellow", 0x13),
+    ("terminal", "bright_blue", 0x16),
+    ("terminal", "bright_magenta", 0x17),
+    ("terminal", "bright_cyan", 0x15),
+    ("terminal", "bright_white", 0x07),
+];
+
+/// Diff backgrounds: the accent blended over `base00` at this opacity.
+const DIFF_BG_ALPHA: f64 = 0.2;
+
+/// Palette slots tried in order for each exported scheme slot; the first set
+/// one wins. `base06` and `base07` are handled separately.
+const EXPORT_SLOTS: [&[SlotRef]; 24] = [
+    &[("base", "background")],
+    &[("surface", "statusline"), ("base", "background_highlight"), ("surface", "highlight")],
+    &[("editor", "selection_bg"), ("surface", "selection"), ("base", "background_highlight")],
+    &[("syntax", "comments"), ("typography", "comment"), ("terminal", "bright_black")],
+    &[("base", "foreground_dark"), ("typography", "line_number"), ("base", "foreground")],
+    &[("base", "foreground")],
+    &[],
+    &[],
+    &[("syntax", "variables_builtin"), ("terminal", "red"), ("semantic", "error")],
+    &[("syntax", "constants"), ("syntax", "numbers"), ("terminal", "bright_red")],
+    &[("syntax", "types"), ("terminal", "yellow"), ("semantic", "warning")],
+    &[("syntax", "strings"), ("terminal", "green"), ("semantic", "success")],
+    &[("syntax", "strings_escape"), ("terminal", "cyan"), ("semantic", "hint")],
+    &[("syntax", "functions"), ("terminal", "blue"), ("semantic", "info")],
+    &[("syntax", "keywords"), ("terminal", "magenta")],
+    &[("syntax", "tag_delimiter"), ("syntax", "punctuation_bracket"), ("semantic", "error")],
+    &[("base", "background_dark"), ("surface", "sidebar")],
+    &[("base", "border"), ("base", "background_dark")],
+    &[("terminal", "bright_red")],
+    &[("terminal", "bright_yellow")],
+    &[("terminal", "bright_green")],
+    &[("terminal", "bright_cyan")],
+    &[("terminal", "bright_blue")],
+    &[("terminal", "bright_magenta")],
+];
+
+/// A Base16 scheme, optionally extended to Base24.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Base16Scheme {
+    pub name: Arc<str>,
+    pub author: Option<Arc<str>>,
+    /// `dark` or `light`, when the scheme declares it.
+    pub variant: Option<Arc<str>>,
+    /// `base00` … `base0F`.
+    pub base16: [Color; 16],
+    /// `base10` … `base17`, for Base24 schemes.
+    pub base24: Option<[Color; 8]>,
+}
+
+/// `key: value` pairs from a flat or one-level-nested YAML document, with
+/// quotes and trailing comments removed. Enough for scheme files.
+fn yaml_pairs(s: &str) -> impl Iterator<Item = (&str, &str)> {
+    s.lines().filter_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        let key = key.trim();
+        let value = value.trim();
+        let value = match value.chars().next() {
+            Some(q @ ('"' | '\'')) => value[1..].split(q).next().unwrap_or_default(),
+            _ => value.split(" #").next().unwrap_or_default().trim(),
+        };
+        (!key.starts_with('#')).then_some((key, value))
+    })
+}
+
+fn parse_slot(key: &str, value: &str) -> Result<Color, PaletteError> {
+    let hex = match value.starts_with('#') {
+        true => value.to_owned(),
+        false => format!("#{value}"),
+    };
+    Color::from_hex(&hex).map_err(|_| PaletteError::InvalidHex {
+        section: Arc::from("base16"),
+        field: Arc::from(key),
+        value: Arc::from(value),
+    })
+}
+
+/// Slot index for `base00` … `base17`, accepting either hex-digit case.
+fn slot_index(key: &str) -> Option<usize> {
+    let digits = key.strip_prefix("base")?;
+    match digits.len() {
+        2 => usize::from_str_radix(digits, 16).ok().filter(|i| *i < 0x18),
+        _ => None,
+    }
+}
+
+fn slot_name(index: usize) -> String {
+    format!("base{index:02X}")
+}
+
+impl Base16Scheme {
+    /// Parse a scheme in either the classic (`scheme:`, bare hex) or the
+    /// tinted-theming (`name:`, `palette:` map, `#` hex) layout.
+    ///
+    /// All of `base00` … `base0F` are required. The scheme is Base24 when all
+    /// of `base10` … `base17` are present too.
+    pub fn from_yaml(s: &str) -> Result<Self, PaletteError> {
+        let mut slots: [Option<Color>; 24] = [None; 24];
+        let mut name = None;
+        let mut author = None;
+        let mut variant = None;
+        for (key, value) in yaml_pairs(s) {
+            match key {
+                "scheme" | "name" => name = Some(Arc::from(value)),
+                "author" => author = Some(Arc::from(value)),
+                "variant" => variant = Some(Arc::from(value)),
+                _ => {
+                    if let Some(index) = slot_index(key) {
+                        slots[index] = Some(parse_slot(key, value)?);
+                    }
+                }
+            }
+        }
+
+        let required = |index: usize| {
+            slots[index].ok_or_else(|| PaletteError::MissingSlot {
+                section: Arc::from("base16"),
+                field: Arc::from(slot_name(index)),
+            })
+        };
+        let mut base16 = [Color { r: 0, g: 0, b: 0 }; 16];
+        for (index, out) in base16.iter_mut().enumerate() {
+            *out = required(index)?;
+        }
+        let base24 = match slots[16..].iter().all(Option::is_some) {
+            true => Some(std::array::from_fn(|i| slots[16 + i].unwrap_or(base16[0]))),
+            false => None,
+        };
+
+        Ok(Self {
+            name: name.unwrap_or_else(|| Arc::from("Base16")),
+            author: author.filter(|a: &Arc<str>| !a.is_empty()),
+            variant,
+            base16,
+            base24,
+        })
+    }
+
+    /// `base00` … `base17` by index. Base24 slots fall back to their
+    /// nearest Base16 equivalent on plain Base16 schemes.
+    pub fn color(&self, index: usize) -> Color {
+        match (index, &self.base24) {
+            (0..=15, _) => self.base16[index],
+            (_, Some(extra)) => extra[index - 16],
+            (_, None) => self.base16[BASE24_FALLBACK[index - 16]],
+        }
+    }
+
+    pub fn is_base24(&self) -> bool {
+        self.base24.is_some()
+    }
+
+    /// `dark` or `light`: the declared variant, else judged from `base00`.
+    pub fn style(&self) -> &str {
+        match &self.variant {
+            Some(v) => v,
+            None => match self.base16[0].is_light() {
+                true => "light",
+                false => "dark",
+            },
+        }
+    }
+
+    /// Lowercase, underscore-separated ID from the scheme name.
+    pub fn slug(&self) -> String {
+        let words: Vec<String> = self
+            .name
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect();
+        words.join("_")
+    }
+
+    /// A full manifest following the Base16 styling guidelines, with diff
+    /// backgrounds tinted from the diff accents.
+    pub fn to_manifest(&self, id: &str) -> PaletteManifest {
+        let mut manifest = PaletteManifest::imported(Arc::clone(&self.name), id, self.style());
+        for (section, field, index) in IMPORT_SLOTS {
+            if let Some(slots) = manifest.section_mut(section) {
+                slots.insert(Arc::from(*field), Arc::from(self.color(*index).to_hex()));
+            }
+        }
+        let background = self.color(0x00);
+        for (field, index) in [("added_bg", 0x0B), ("modified_bg", 0x0E), ("removed_bg", 0x08), ("text_bg", 0x02)] {
+            let tinted = self.color(index).blend(background, DIFF_BG_ALPHA);
+            manifest.diff.insert(Arc::from(field), Arc::from(tinted.to_hex()));
+        }
+        manifest
+    }
+
+    pub fn to_palette(&self) -> Result<Palette, PaletteError> {
+        Palette::from_manifest(&self.to_manifest(&self.slug()))
+    }
+
+    /// Tinted-theming YAML: `system`, `name`, `author`, `variant`, and a
+    /// `palette` map of `#rrggbb` values.
+    pub fn to_yaml(&self) -> String {
+        let system = match self.is_base24() {
+            true => "base24",
+            false => "base16",
+        };
+        let count = match self.is_base24() {
+            true => 24,
+            false => 16,
+        };
+        let quote = |s: &str| s.replace('"', "'");
+        let mut out = format!("system: \"{system}\"\nname: \"{}\"\n", quote(&self.name));
+        // String::write_fmt is infallible
+        if let Some(author) = &self.author {
+            let _ = writeln!(out, "author: \"{}\"", quote(author));
+        }
+        let _ = writeln!(out, "variant: \"{}\"\npalette:", self.style());
+        for index in 0..count {
+            let hex = self.color(index).to_hex().to_lowercase();
+            let _ = writeln!(out, "  {}: \"{hex}\"", slot_name(index));
+        }
+        out
+    }
+
+    /// Pick each scheme slot from the palette slot that plays the same role.
+    ///
+    /// Slots the palette leaves unset fall back to `base.foreground` or
+    /// `base.background`. `base07` is the terminal's brightest (dark
+    /// variants) or darkest (light variants) neutral, and `base06` sits
+    /// halfway between `base05` and `base07`.
+    pub fn from_palette(palette: &Palette, base24: bool) -> Self {
+        let pick = |candidates: &[SlotRef]| palette.pick_slot(candidates).map(|(c, _)| c);
+        let foreground = palette.base.foreground.unwrap_or(Color { r: 255, g: 255, b: 255 });
+        let background = palette.base.background.unwrap_or(Color { r: 0, g: 0, b: 0 });
+        let light = background.is_light();
+
+        let picked: [Option<Color>; 24] = std::array::from_fn(|i| pick(EXPORT_SLOTS[i]));
+        let mut colors: [Color; 24] = std::array::from_fn(|i| {
+            let fallback = match i {
+                0x00..=0x02 | 0x10 | 0x11 => background,
+                0x12..=0x17 => picked[BASE24_FALLBACK[i - 16]].unwrap_or(foreground),
+                _ => foreground,
+            };
+            picked[i].unwrap_or(fallback)
+        });
+        let extreme: &[SlotRef] = match light {
+            true => &[("terminal", "black")],
+            false => &[("terminal", "bright_white")],
+        };
+        colors[0x07] = pick(extreme).unwrap_or(colors[0x05]);
+        colors[0x06] = colors[0x05].interpolate(colors[0x07], 0.5, BlendSpace::Oklab);
+
+        Self {
+            name: palette.meta.as_ref().map_or_else(|| Arc::from("palette"), |m| Arc::clone(&m.name)),
+            author: None,
+            variant: Some(Arc::from(match light {
+                true => "light",
+                false => "dark",
+            })),
+            base16: std::array::from_fn(|i| colors[i]),
+            base24: base24.then(|| std::array::from_fn(|i| colors[16 + i])),
+        }
+    }
+}
+
+impl Palette {
+    /// See [`Base16Scheme::from_palette`].
+    pub fn to_base16(&self) -> Base16Scheme {
+        Base16Scheme::from_palette(self, false)
+    }
+
+    /// [`to_base16`](Self::to_base16) plus the Base24 `base10` … `base17` slots.
+    pub fn to_base24(&self) -> Base16Scheme {
+        Base16Scheme::from_palette(self, true)
+    }
+}
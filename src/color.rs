@@ -54,6 +54,12 @@ impl Color {
         };
         0.2126 * linearize(self.r) + 0.7152 * linearize(self.g) + 0.0722 * linearize(self.b)
     }
+
+    /// Whether this color reads as a light background: black text contrasts
+    /// with it more than white text does (relative luminance above 0.18).
+    pub fn is_light(&self) -> bool {
+        self.relative_luminance() > 0.18
+    }
 }
 
 impl From<Color> for String {
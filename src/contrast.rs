@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
 use crate::color::Color;
-use crate::palette::Palette;
+use crate::manipulation::{oklab_to_rgb, rgb_to_oklab, Oklab};
+use crate::pairs::STYLE_PAIRS;
+use crate::palette::{Palette, PaletteMeta};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ContrastLevel {
@@ -58,11 +62,58 @@ impl Color {
     }
 }
 
+/// A slot reference as `(section, field)`, using manifest section names.
+pub type SlotRef = (&'static str, &'static str);
+
+const BACKGROUND: SlotRef = ("base", "background");
+
+/// Foreground/background slot pairs checked by [`validate_palette`].
+///
+/// Fixed pairs come first, followed by every populated semantic and syntax
+/// slot over `base.background`. Editor and diff pairs come from
+/// [`STYLE_PAIRS`], the same table behind [`Palette::pairs`], and name the
+/// slots each half resolves to after fallbacks. A pair that comes up twice,
+/// such as an inlay hint falling back to `typography.comment`, is listed once.
+pub fn contrast_pairs(palette: &Palette) -> Vec<(SlotRef, SlotRef)> {
+    let mut candidates = vec![
+        // Core readability
+        (("base", "foreground"), BACKGROUND),
+        (("base", "foreground_dark"), BACKGROUND),
+        (("base", "foreground"), ("base", "background_dark")),
+        (("base", "foreground"), ("base", "background_highlight")),
+    ];
+
+    // Semantic over background
+    candidates.extend(palette.semantic.populated_slots().map(|(name, _)| (("semantic", name), BACKGROUND)));
+
+    // Editor and diff style pairs, resolved like renderers resolve them
+    for spec in &STYLE_PAIRS {
+        if let (Some(fg), Some(bg)) = spec.resolve_slots(palette) {
+            candidates.push((fg, bg));
+        }
+    }
+
+    candidates.extend([
+        // Typography over background
+        (("typography", "comment"), BACKGROUND),
+        (("typography", "line_number"), BACKGROUND),
+    ]);
+
+    // Syntax over background
+    candidates.extend(palette.syntax.populated_slots().map(|(name, _)| (("syntax", name), BACKGROUND)));
+
+    let mut pairs = Vec::with_capacity(candidates.len());
+    for pair in candidates {
+        if !pairs.contains(&pair) {
+            pairs.push(pair);
+        }
+    }
+    pairs
+}
+
 fn check_pair(
-    fg_prefix: &str,
-    fg_name: &str,
-    bg_prefix: &str,
-    bg_name: &str,
+    (fg_section, fg_name): SlotRef,
+    (bg_section, bg_name): SlotRef,
     fg: Option<&Color>,
     bg: Option<&Color>,
     level: ContrastLevel,
@@ -75,8 +126,8 @@ fn check_pair(
     match level.passes(ratio) {
         true => None,
         false => Some(ContrastViolation {
-            foreground_label: format!("{fg_prefix}.{fg_name}").into_boxed_str(),
-            background_label: format!("{bg_prefix}.{bg_name}").into_boxed_str(),
+            foreground_label: format!("{fg_section}.{fg_name}").into_boxed_str(),
+            background_label: format!("{bg_section}.{bg_name}").into_boxed_str(),
             foreground: fg_color,
             background: bg_color,
             ratio,
@@ -86,43 +137,142 @@ fn check_pair(
 }
 
 pub fn validate_palette(palette: &Palette, level: ContrastLevel) -> Vec<ContrastViolation> {
-    let mut violations = Vec::new();
-    let mut push = |v: Option<ContrastViolation>| {
-        if let Some(v) = v {
-            violations.push(v);
-        }
+    contrast_pairs(palette)
+        .into_iter()
+        .filter_map(|(fg, bg)| {
+            check_pair(fg, bg, palette.slot(fg.0, fg.1), palette.slot(bg.0, bg.1), level)
+        })
+        .collect()
+}
+
+/// Adjust `fg` until it meets `level` against `bg`.
+///
+/// Moves only OKLab lightness, away from `bg`, by the smallest amount that
+/// passes — hue and chroma are kept where the sRGB gamut allows. Colors that
+/// already pass are returned unchanged. If no lightness passes, returns
+/// whichever of black or white contrasts more.
+pub fn ensure_contrast(fg: &Color, bg: &Color, level: ContrastLevel) -> Color {
+    if meets_level(fg, bg, level) {
+        return *fg;
+    }
+
+    let lab = rgb_to_oklab(*fg);
+    let target = match bg.is_light() {
+        true => 0.0,
+        false => 1.0,
+    };
+    let at = |t: f64| {
+        oklab_to_rgb(Oklab {
+            l: lab.l + (target - lab.l) * t,
+            ..lab
+        })
     };
 
-    // Core readability
-    push(check_pair("base", "foreground", "base", "background", palette.base.foreground.as_ref(), palette.base.background.as_ref(), level));
-    push(check_pair("base", "foreground_dark", "base", "background", palette.base.foreground_dark.as_ref(), palette.base.background.as_ref(), level));
-    push(check_pair("base", "foreground", "base", "background_dark", palette.base.foreground.as_ref(), palette.base.background_dark.as_ref(), level));
-    push(check_pair("base", "foreground", "base", "background_highlight", palette.base.foreground.as_ref(), palette.base.background_highlight.as_ref(), level));
+    if !meets_level(&at(1.0), bg, level) {
+        let (black, white) = (Color { r: 0, g: 0, b: 0 }, Color { r: 255, g: 255, b: 255 });
+        return match contrast_ratio(&black, bg) >= contrast_ratio(&white, bg) {
+            true => black,
+            false => white,
+        };
+    }
 
-    // Semantic over background
-    for (name, color) in palette.semantic.populated_slots() {
-        push(check_pair("semantic", name, "base", "background", Some(color), palette.base.background.as_ref(), level));
+    // Bisect for the smallest lightness shift that passes
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..24 {
+        let mid = (lo + hi) / 2.0;
+        match meets_level(&at(mid), bg, level) {
+            true => hi = mid,
+            false => lo = mid,
+        }
     }
+    at(hi)
+}
 
-    // Editor pairs
-    push(check_pair("editor", "selection_fg", "editor", "selection_bg", palette.editor.selection_fg.as_ref(), palette.editor.selection_bg.as_ref(), level));
-    push(check_pair("editor", "inlay_hint_fg", "editor", "inlay_hint_bg", palette.editor.inlay_hint_fg.as_ref(), palette.editor.inlay_hint_bg.as_ref(), level));
-    push(check_pair("editor", "search_fg", "editor", "search_bg", palette.editor.search_fg.as_ref(), palette.editor.search_bg.as_ref(), level));
-    push(check_pair("editor", "cursor_text", "editor", "cursor", palette.editor.cursor_text.as_ref(), palette.editor.cursor.as_ref(), level));
+impl Color {
+    /// See [`ensure_contrast`].
+    pub fn ensure_contrast(&self, bg: &Color, level: ContrastLevel) -> Color {
+        ensure_contrast(self, bg, level)
+    }
+}
 
-    // Diff pairs
-    push(check_pair("diff", "added_fg", "diff", "added_bg", palette.diff.added_fg.as_ref(), palette.diff.added_bg.as_ref(), level));
-    push(check_pair("diff", "modified_fg", "diff", "modified_bg", palette.diff.modified_fg.as_ref(), palette.diff.modified_bg.as_ref(), level));
-    push(check_pair("diff", "removed_fg", "diff", "removed_bg", palette.diff.removed_fg.as_ref(), palette.diff.removed_bg.as_ref(), level));
+/// Diff background tints, dropped by [`Palette::high_contrast`] when they
+/// barely differ from `base.background`.
+const DIFF_TINTS: [&str; 4] = ["added_bg", "modified_bg", "removed_bg", "text_bg"];
 
-    // Typography over background
-    push(check_pair("typography", "comment", "base", "background", palette.typography.comment.as_ref(), palette.base.background.as_ref(), level));
-    push(check_pair("typography", "line_number", "base", "background", palette.typography.line_number.as_ref(), palette.base.background.as_ref(), level));
+/// Diff foregrounds that render on `base.background` once tints are gone.
+const DIFF_FOREGROUNDS: [&str; 7] = [
+    "added", "added_fg", "modified", "modified_fg", "removed", "removed_fg", "ignored",
+];
 
-    // Syntax over background
-    for (name, color) in palette.syntax.populated_slots() {
-        push(check_pair("syntax", name, "base", "background", Some(color), palette.base.background.as_ref(), level));
+/// Non-text UI slots that must stand out from `base.background`.
+const UI_ACCENTS: [(SlotRef, ContrastLevel); 3] = [
+    (("base", "border"), ContrastLevel::AaLarge),
+    (("base", "border_highlight"), ContrastLevel::AaNormal),
+    (("surface", "focus"), ContrastLevel::AaNormal),
+];
+
+impl Palette {
+    /// Accessibility variant of this palette.
+    ///
+    /// - Every pair from [`contrast_pairs`] has its foreground pushed to meet
+    ///   `level` (see [`ensure_contrast`]).
+    /// - Borders reach 3:1 against the background; `border_highlight` and
+    ///   `surface.focus` reach 4.5:1.
+    /// - Diff `_bg` tints under 3:1 against the background are removed, and
+    ///   diff foregrounds are adjusted against the background instead.
+    /// - Paired backgrounds outside `base` (e.g. `editor.search_bg`) are moved
+    ///   when no foreground can reach `level` on them.
+    ///
+    /// `meta` gains a `+hc` id suffix and a "(High Contrast)" name suffix.
+    pub fn high_contrast(&self, level: ContrastLevel) -> Palette {
+        let mut out = self.clone();
+        let background = out.base.background;
+
+        if let Some(bg) = background {
+            for field in DIFF_TINTS {
+                if let Some(slot) = out.diff.get_mut(field) {
+                    if slot.is_some_and(|c| contrast_ratio(&c, &bg) < 3.0) {
+                        *slot = None;
+                    }
+                }
+            }
+            for ((section, field), min) in UI_ACCENTS {
+                push_slot(&mut out, (section, field), &bg, min);
+            }
+            for field in DIFF_FOREGROUNDS {
+                let tint = field.strip_suffix("_fg").map(|f| format!("{f}_bg"));
+                let on_tint = tint.is_some_and(|t| out.diff.get(&t).is_some());
+                if !on_tint {
+                    push_slot(&mut out, ("diff", field), &bg, level);
+                }
+            }
+        }
+
+        for (fg, bg) in contrast_pairs(&out) {
+            if let Some(bg_color) = out.slot(bg.0, bg.1).copied() {
+                push_slot(&mut out, fg, &bg_color, level);
+            }
+            // Mid-luminance paired backgrounds (search, cursor) may defeat
+            // even black or white text; move the background instead.
+            if let (Some(fg_color), false) = (out.slot(fg.0, fg.1).copied(), bg.0 == "base") {
+                push_slot(&mut out, bg, &fg_color, level);
+            }
+        }
+
+        out.meta = self.meta.as_ref().map(|m| PaletteMeta {
+            name: Arc::from(format!("{} (High Contrast)", m.name)),
+            preset_id: Arc::from(format!("{}{HIGH_CONTRAST_SUFFIX}", m.preset_id)),
+            style: Arc::clone(&m.style),
+        });
+        out
     }
+}
 
-    violations
+/// Theme id suffix for derived high-contrast variants (e.g. `nord+hc`).
+pub const HIGH_CONTRAST_SUFFIX: &str = "+hc";
+
+fn push_slot(palette: &mut Palette, (section, field): SlotRef, bg: &Color, level: ContrastLevel) {
+    if let Some(Some(color)) = palette.slot_mut(section, field) {
+        *color = ensure_contrast(color, bg, level);
+    }
 }
@@ -0,0 +1,124 @@
+use ::crossterm::style::{Attribute, Attributes, Color as CrosstermColor, ContentStyle};
+
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::style::TextStyle;
+
+pub fn to_crossterm_color(color: &Color) -> CrosstermColor {
+    CrosstermColor::Rgb {
+        r: color.r,
+        g: color.g,
+        b: color.b,
+    }
+}
+
+/// Map text style flags to crossterm attributes.
+pub fn to_crossterm_attributes(style: &TextStyle) -> Attributes {
+    let flags = [
+        (style.bold, Attribute::Bold),
+        (style.italic, Attribute::Italic),
+        (style.underline, Attribute::Underlined),
+        (style.undercurl, Attribute::Undercurled),
+        (style.strikethrough, Attribute::CrossedOut),
+    ];
+    flags
+        .into_iter()
+        .filter(|(set, _)| *set)
+        .fold(Attributes::none(), |acc, (_, a)| acc | a)
+}
+
+macro_rules! crossterm_group {
+    ($color_type:ident, $style_type:ident { $($field:ident),+ $(,)? }) => {
+        paste::paste! {
+            #[derive(Debug, Clone)]
+            pub struct [<Crossterm $color_type>] {
+                $(pub $field: Option<CrosstermColor>,)+
+            }
+
+            impl [<Crossterm $color_type>] {
+                fn from_palette(group: &crate::palette::$color_type) -> Self {
+                    Self {
+                        $($field: group.$field.as_ref().map(to_crossterm_color),)+
+                    }
+                }
+            }
+
+            /// Per-slot attributes; `Attributes::none()` where no style is set.
+            #[derive(Debug, Clone)]
+            pub struct [<Crossterm $style_type>] {
+                $(pub $field: Attributes,)+
+            }
+
+            impl [<Crossterm $style_type>] {
+                fn from_palette(group: &crate::palette::$style_type) -> Self {
+                    Self {
+                        $($field: group.$field.map_or(Attributes::none(), |s| to_crossterm_attributes(&s)),)+
+                    }
+                }
+            }
+        }
+    };
+}
+
+crate::palette::color_fields!(crossterm_group);
+
+#[derive(Debug, Clone)]
+pub struct CrosstermTheme {
+    pub base: CrosstermBaseColors,
+    pub semantic: CrosstermSemanticColors,
+    pub diff: CrosstermDiffColors,
+    pub surface: CrosstermSurfaceColors,
+    pub typography: CrosstermTypographyColors,
+    pub syntax: CrosstermSyntaxColors,
+    pub editor: CrosstermEditorColors,
+    pub terminal_ansi: CrosstermTerminalAnsiColors,
+    pub attributes: CrosstermAttributes,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrosstermAttributes {
+    pub base: CrosstermBaseStyles,
+    pub semantic: CrosstermSemanticStyles,
+    pub diff: CrosstermDiffStyles,
+    pub surface: CrosstermSurfaceStyles,
+    pub typography: CrosstermTypographyStyles,
+    pub syntax: CrosstermSyntaxStyles,
+    pub editor: CrosstermEditorStyles,
+    pub terminal_ansi: CrosstermTerminalAnsiStyles,
+}
+
+pub fn to_crossterm_theme(palette: &Palette) -> CrosstermTheme {
+    CrosstermTheme {
+        base: CrosstermBaseColors::from_palette(&palette.base),
+        semantic: CrosstermSemanticColors::from_palette(&palette.semantic),
+        diff: CrosstermDiffColors::from_palette(&palette.diff),
+        surface: CrosstermSurfaceColors::from_palette(&palette.surface),
+        typography: CrosstermTypographyColors::from_palette(&palette.typography),
+        syntax: CrosstermSyntaxColors::from_palette(&palette.syntax),
+        editor: CrosstermEditorColors::from_palette(&palette.editor),
+        terminal_ansi: CrosstermTerminalAnsiColors::from_palette(&palette.terminal_ansi),
+        attributes: CrosstermAttributes {
+            base: CrosstermBaseStyles::from_palette(&palette.styles.base),
+            semantic: CrosstermSemanticStyles::from_palette(&palette.styles.semantic),
+            diff: CrosstermDiffStyles::from_palette(&palette.styles.diff),
+            surface: CrosstermSurfaceStyles::from_palette(&palette.styles.surface),
+            typography: CrosstermTypographyStyles::from_palette(&palette.styles.typography),
+            syntax: CrosstermSyntaxStyles::from_palette(&palette.styles.syntax),
+            editor: CrosstermEditorStyles::from_palette(&palette.styles.editor),
+            terminal_ansi: CrosstermTerminalAnsiStyles::from_palette(&palette.styles.terminal_ansi),
+        },
+    }
+}
+
+/// Foreground and attributes for one slot, e.g. `("semantic", "error")`,
+/// ready for `style.apply(text)`.
+pub fn slot_style(palette: &Palette, section: &str, field: &str) -> ContentStyle {
+    ContentStyle {
+        foreground_color: palette.slot(section, field).map(to_crossterm_color),
+        attributes: palette
+            .styles
+            .get(section, field)
+            .map_or(Attributes::none(), to_crossterm_attributes),
+        ..ContentStyle::default()
+    }
+}
@@ -1,8 +1,9 @@
 use std::borrow::Cow;
-use std::fmt::Write;
+use std::fmt::{Display, Write};
 
 use crate::color::Color;
 use crate::palette::Palette;
+use crate::style::TextStyle;
 
 fn css_name(section: &str, field: &str) -> Option<&'static str> {
     match (section, field) {
@@ -128,22 +129,98 @@ fn fallback_slot(section: &str, field: &str) -> String {
     format!("{section}-{}", field.replace('_', "-"))
 }
 
-fn write_section<'a>(
+/// Custom sections get their own namespace so `[custom.syn] keyword` can
+/// never overwrite `--syn-keyword`.
+fn custom_slot(section: &str, field: &str) -> Cow<'static, str> {
+    Cow::Owned(format!("custom-{}", fallback_slot(section, field)))
+}
+
+pub(crate) fn slot_name(section: &str, field: &str) -> Cow<'static, str> {
+    match css_name(section, field) {
+        Some(name) => Cow::Borrowed(name),
+        None => Cow::Owned(fallback_slot(section, field)),
+    }
+}
+
+/// Custom property name, `--{prefix}-{name}` or `--{name}`.
+pub(crate) fn var_name(prefix: Option<&str>, name: &str) -> String {
+    match prefix {
+        Some(p) => format!("--{p}-{name}"),
+        None => format!("--{name}"),
+    }
+}
+
+fn write_declaration(out: &mut String, prefix: Option<&str>, name: &str, value: impl Display) {
+    // String::write_fmt is infallible
+    let _ = match prefix {
+        Some(p) => writeln!(out, "  --{p}-{name}: {value};"),
+        None => writeln!(out, "  --{name}: {value};"),
+    };
+}
+
+/// Every color property [`to_css_custom_properties`] writes, in order, as
+/// names without the `--` or prefix. Custom sections come last, under their
+/// own namespace: `custom-chart-series-1`.
+fn color_properties(palette: &Palette) -> Vec<(Cow<'static, str>, Color)> {
+    let sections: [(&str, Vec<(&'static str, &Color)>); 8] = [
+        ("base", palette.base.populated_slots().collect()),
+        ("semantic", palette.semantic.populated_slots().collect()),
+        ("diff", palette.diff.populated_slots().collect()),
+        ("surface", palette.surface.populated_slots().collect()),
+        ("typography", palette.typography.populated_slots().collect()),
+        ("syntax", palette.syntax.populated_slots().collect()),
+        ("editor", palette.editor.populated_slots().collect()),
+        ("terminal", palette.terminal_ansi.populated_slots().collect()),
+    ];
+    let builtin = sections.into_iter().flat_map(|(section, slots)| {
+        slots.into_iter().map(move |(field, color)| (slot_name(section, field), *color))
+    });
+    let custom = palette.custom.iter().flat_map(|(section, slots)| {
+        slots.iter().map(move |(field, color)| (custom_slot(section, field), *color))
+    });
+    builtin.chain(custom).collect()
+}
+
+pub(crate) fn text_decoration(style: &TextStyle) -> String {
+    let lines: Vec<&str> = [
+        (style.underline || style.undercurl, "underline"),
+        (style.strikethrough, "line-through"),
+        (style.undercurl, "wavy"),
+    ]
+    .into_iter()
+    .filter_map(|(set, value)| set.then_some(value))
+    .collect();
+
+    match lines.is_empty() {
+        true => "none".to_owned(),
+        false => lines.join(" "),
+    }
+}
+
+/// Style variables for each styled slot, named after the CSS property they
+/// feed: `--syn-keyword-font-weight`, `-font-style`, `-text-decoration`.
+///
+/// All three are written for every styled slot so switching themes never
+/// leaves a stale value behind.
+fn write_style_section<'f, 's>(
     out: &mut String,
     prefix: Option<&str>,
-    section: &str,
-    slots: impl Iterator<Item = (&'static str, &'a Color)>,
+    name: impl Fn(&str) -> Cow<'static, str>,
+    styles: impl Iterator<Item = (&'f str, &'s TextStyle)>,
 ) {
-    for (field, color) in slots {
-        let slot: Cow<'static, str> = match css_name(section, field) {
-            Some(name) => Cow::Borrowed(name),
-            None => Cow::Owned(fallback_slot(section, field)),
+    for (field, style) in styles {
+        let slot = name(field);
+        let weight = match style.bold {
+            true => "bold",
+            false => "normal",
         };
-        // String::write_fmt is infallible
-        let _ = match prefix {
-            Some(p) => writeln!(out, "  --{p}-{slot}: {color};"),
-            None => writeln!(out, "  --{slot}: {color};"),
+        let font_style = match style.italic {
+            true => "italic",
+            false => "normal",
         };
+        write_declaration(out, prefix, &format!("{slot}-font-weight"), weight);
+        write_declaration(out, prefix, &format!("{slot}-font-style"), font_style);
+        write_declaration(out, prefix, &format!("{slot}-text-decoration"), text_decoration(style));
     }
 }
 
@@ -165,13 +242,27 @@ impl Palette {
 
 pub fn to_css_custom_properties(palette: &Palette, prefix: Option<&str>) -> String {
     let mut out = String::with_capacity(3072);
-    write_section(&mut out, prefix, "base", palette.base.populated_slots());
-    write_section(&mut out, prefix, "semantic", palette.semantic.populated_slots());
-    write_section(&mut out, prefix, "diff", palette.diff.populated_slots());
-    write_section(&mut out, prefix, "surface", palette.surface.populated_slots());
-    write_section(&mut out, prefix, "typography", palette.typography.populated_slots());
-    write_section(&mut out, prefix, "syntax", palette.syntax.populated_slots());
-    write_section(&mut out, prefix, "editor", palette.editor.populated_slots());
-    write_section(&mut out, prefix, "terminal", palette.terminal_ansi.populated_slots());
+    for (name, color) in color_properties(palette) {
+        write_declaration(&mut out, prefix, &name, color);
+    }
+
+    let styles = &palette.styles;
+    write_style_section(&mut out, prefix, |f| slot_name("base", f), styles.base.populated_slots());
+    write_style_section(&mut out, prefix, |f| slot_name("semantic", f), styles.semantic.populated_slots());
+    write_style_section(&mut out, prefix, |f| slot_name("diff", f), styles.diff.populated_slots());
+    write_style_section(&mut out, prefix, |f| slot_name("surface", f), styles.surface.populated_slots());
+    write_style_section(&mut out, prefix, |f| slot_name("typography", f), styles.typography.populated_slots());
+    write_style_section(&mut out, prefix, |f| slot_name("syntax", f), styles.syntax.populated_slots());
+    write_style_section(&mut out, prefix, |f| slot_name("editor", f), styles.editor.populated_slots());
+    write_style_section(&mut out, prefix, |f| slot_name("terminal", f), styles.terminal_ansi.populated_slots());
+    for (section, slots) in &styles.custom {
+        write_style_section(&mut out, prefix, |f| custom_slot(section, f), slots.iter().map(|(f, s)| (&**f, s)));
+    }
     out
 }
+
+/// Names of the color properties [`to_css_custom_properties`] writes, in the
+/// same order and without the `--` or prefix.
+pub(crate) fn color_names(palette: &Palette) -> Vec<Cow<'static, str>> {
+    color_properties(palette).into_iter().map(|(name, _)| name).collect()
+}
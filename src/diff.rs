@@ -0,0 +1,315 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::manipulation::delta_e;
+use crate::palette::{Palette, PaletteMeta, PaletteStyles};
+use crate::style::TextStyle;
+
+/// How a single color slot differs between two palettes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+#[cfg_attr(feature = "snapshot", serde(tag = "kind", rename_all = "snake_case"))]
+pub enum SlotChange {
+    Added { new: Color },
+    Removed { old: Color },
+    Changed { old: Color, new: Color, delta_e: f64 },
+}
+
+impl SlotChange {
+    fn between(old: Option<&Color>, new: Option<&Color>) -> Option<Self> {
+        match (old, new) {
+            (None, None) => None,
+            (None, Some(&new)) => Some(Self::Added { new }),
+            (Some(&old), None) => Some(Self::Removed { old }),
+            (Some(&old), Some(&new)) if old == new => None,
+            (Some(&old), Some(&new)) => Some(Self::Changed {
+                old,
+                new,
+                delta_e: delta_e(old, new),
+            }),
+        }
+    }
+
+    pub fn old_color(&self) -> Option<Color> {
+        match *self {
+            Self::Added { .. } => None,
+            Self::Removed { old } | Self::Changed { old, .. } => Some(old),
+        }
+    }
+
+    pub fn new_color(&self) -> Option<Color> {
+        match *self {
+            Self::Removed { .. } => None,
+            Self::Added { new } | Self::Changed { new, .. } => Some(new),
+        }
+    }
+}
+
+/// A changed slot, labelled by section and field (e.g. `syntax.keywords`).
+///
+/// Custom sections use `custom.<name>` as the section, and platform
+/// overrides `platform.<name>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub struct SlotDiff {
+    pub section: Box<str>,
+    pub field: Box<str>,
+    pub change: SlotChange,
+}
+
+/// A slot whose text style changed, labelled like [`SlotDiff`]. `None`
+/// means the slot has no style.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub struct StyleDiff {
+    pub section: Box<str>,
+    pub field: Box<str>,
+    pub old: Option<TextStyle>,
+    pub new: Option<TextStyle>,
+}
+
+/// A changed `meta` field. `None` means the palette has no meta.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub struct MetaChange {
+    pub field: &'static str,
+    pub old: Option<Arc<str>>,
+    pub new: Option<Arc<str>>,
+}
+
+/// Every difference between two palettes, in `color_fields!` order.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub struct PaletteDiff {
+    pub meta: Vec<MetaChange>,
+    pub slots: Vec<SlotDiff>,
+    pub styles: Vec<StyleDiff>,
+}
+
+impl PaletteDiff {
+    pub fn is_empty(&self) -> bool {
+        self.meta.is_empty() && self.slots.is_empty() && self.styles.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.meta.len() + self.slots.len() + self.styles.len()
+    }
+
+    pub fn added(&self) -> impl Iterator<Item = &SlotDiff> {
+        self.slots.iter().filter(|d| matches!(d.change, SlotChange::Added { .. }))
+    }
+
+    pub fn removed(&self) -> impl Iterator<Item = &SlotDiff> {
+        self.slots.iter().filter(|d| matches!(d.change, SlotChange::Removed { .. }))
+    }
+
+    pub fn changed(&self) -> impl Iterator<Item = &SlotDiff> {
+        self.slots.iter().filter(|d| matches!(d.change, SlotChange::Changed { .. }))
+    }
+
+    fn push_group<'a>(
+        &mut self,
+        section: &str,
+        old: impl Iterator<Item = (&'static str, Option<&'a Color>)>,
+        new: impl Iterator<Item = (&'static str, Option<&'a Color>)>,
+        styles: (&PaletteStyles, &PaletteStyles),
+    ) {
+        for ((field, old), (_, new)) in old.zip(new) {
+            self.push_slot(section, field, old, new);
+            self.push_style(section, field, styles.0.get(section, field), styles.1.get(section, field));
+        }
+    }
+
+    fn push_style(&mut self, section: &str, field: &str, old: Option<&TextStyle>, new: Option<&TextStyle>) {
+        if old != new {
+            self.styles.push(StyleDiff {
+                section: Box::from(section),
+                field: Box::from(field),
+                old: old.copied(),
+                new: new.copied(),
+            });
+        }
+    }
+
+    fn push_slot(&mut self, section: &str, field: &str, old: Option<&Color>, new: Option<&Color>) {
+        if let Some(change) = SlotChange::between(old, new) {
+            self.slots.push(SlotDiff {
+                section: Box::from(section),
+                field: Box::from(field),
+                change,
+            });
+        }
+    }
+
+    fn push_meta(&mut self, old: Option<&PaletteMeta>, new: Option<&PaletteMeta>) {
+        let fields = [
+            ("name", old.map(|m| &m.name), new.map(|m| &m.name)),
+            ("preset_id", old.map(|m| &m.preset_id), new.map(|m| &m.preset_id)),
+            ("style", old.map(|m| &m.style), new.map(|m| &m.style)),
+        ];
+        for (field, old, new) in fields {
+            if old != new {
+                self.meta.push(MetaChange {
+                    field,
+                    old: old.cloned(),
+                    new: new.cloned(),
+                });
+            }
+        }
+    }
+
+    fn push_custom(&mut self, old: &Palette, new: &Palette) {
+        let mut names: BTreeSet<&Arc<str>> = BTreeSet::new();
+        for palette in [old, new] {
+            names.extend(palette.custom.keys().chain(palette.styles.custom.keys()));
+        }
+        for name in names {
+            let section = format!("custom.{name}");
+            let mut fields: BTreeSet<&Arc<str>> = BTreeSet::new();
+            for palette in [old, new] {
+                fields.extend(palette.custom.get(name).into_iter().flat_map(BTreeMap::keys));
+                fields.extend(palette.styles.custom.get(name).into_iter().flat_map(BTreeMap::keys));
+            }
+            for field in fields {
+                self.push_slot(&section, field, old.custom(name, field), new.custom(name, field));
+                self.push_style(&section, field, old.styles.get(name, field), new.styles.get(name, field));
+            }
+        }
+    }
+
+    #[cfg(feature = "platform")]
+    fn push_platform(
+        &mut self,
+        old: &crate::platform::PlatformOverrides,
+        new: &crate::platform::PlatformOverrides,
+    ) {
+        let names: BTreeSet<&Arc<str>> = old.keys().chain(new.keys()).collect();
+        for name in names {
+            let section = format!("platform.{name}");
+            let (old, new) = (old.get(name), new.get(name));
+            self.push_slot(
+                &section,
+                "background",
+                old.and_then(|o| o.background.as_ref()),
+                new.and_then(|o| o.background.as_ref()),
+            );
+            self.push_slot(
+                &section,
+                "foreground",
+                old.and_then(|o| o.foreground.as_ref()),
+                new.and_then(|o| o.foreground.as_ref()),
+            );
+        }
+    }
+}
+
+impl Palette {
+    /// Compare against `other`, treating `self` as the old palette.
+    pub fn diff(&self, other: &Palette) -> PaletteDiff {
+        diff(self, other)
+    }
+}
+
+pub fn diff(old: &Palette, new: &Palette) -> PaletteDiff {
+    let mut out = PaletteDiff::default();
+    out.push_meta(old.meta.as_ref(), new.meta.as_ref());
+    let styles = (&old.styles, &new.styles);
+    out.push_group("base", old.base.slots(), new.base.slots(), styles);
+    out.push_group("semantic", old.semantic.slots(), new.semantic.slots(), styles);
+    out.push_group("diff", old.diff.slots(), new.diff.slots(), styles);
+    out.push_group("surface", old.surface.slots(), new.surface.slots(), styles);
+    out.push_group("typography", old.typography.slots(), new.typography.slots(), styles);
+    out.push_group("syntax", old.syntax.slots(), new.syntax.slots(), styles);
+    out.push_group("editor", old.editor.slots(), new.editor.slots(), styles);
+    out.push_group("terminal", old.terminal_ansi.slots(), new.terminal_ansi.slots(), styles);
+    out.push_custom(old, new);
+    #[cfg(feature = "platform")]
+    out.push_platform(&old.platform, &new.platform);
+    out
+}
+
+const MISSING: &str = "-";
+
+fn color_cell(color: Option<Color>) -> String {
+    color.map_or_else(|| MISSING.to_owned(), |c| c.to_hex())
+}
+
+/// Set flags joined with `+`, e.g. `bold+italic`, or `plain`.
+fn style_cell(style: Option<TextStyle>) -> String {
+    let Some(style) = style else {
+        return MISSING.to_owned();
+    };
+    let flags: Vec<&str> = [
+        (style.bold, "bold"),
+        (style.italic, "italic"),
+        (style.underline, "underline"),
+        (style.undercurl, "undercurl"),
+        (style.strikethrough, "strikethrough"),
+    ]
+    .into_iter()
+    .filter_map(|(set, name)| set.then_some(name))
+    .collect();
+    match flags.is_empty() {
+        true => "plain".to_owned(),
+        false => flags.join("+"),
+    }
+}
+
+impl fmt::Display for PaletteDiff {
+    /// Compact aligned table: `slot  old  new  ΔE`, one row per change.
+    /// Style changes follow the color rows, with flags as the old and new
+    /// values.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "no changes");
+        }
+
+        let rows: Vec<[String; 4]> = self
+            .meta
+            .iter()
+            .map(|m| {
+                let text = |v: &Option<Arc<str>>| v.as_deref().unwrap_or(MISSING).to_owned();
+                [format!("meta.{}", m.field), text(&m.old), text(&m.new), String::new()]
+            })
+            .chain(self.slots.iter().map(|d| {
+                let delta = match d.change {
+                    SlotChange::Changed { delta_e, .. } => format!("{delta_e:.1}"),
+                    _ => String::new(),
+                };
+                [
+                    format!("{}.{}", d.section, d.field),
+                    color_cell(d.change.old_color()),
+                    color_cell(d.change.new_color()),
+                    delta,
+                ]
+            }))
+            .chain(self.styles.iter().map(|d| {
+                [
+                    format!("{}.{}", d.section, d.field),
+                    style_cell(d.old),
+                    style_cell(d.new),
+                    String::new(),
+                ]
+            }))
+            .collect();
+
+        let header = ["slot", "old", "new", "ΔE"];
+        let width = |col: usize| {
+            rows.iter()
+                .map(|r| r[col].chars().count())
+                .chain(std::iter::once(header[col].chars().count()))
+                .max()
+                .unwrap_or(0)
+        };
+        let (w0, w1, w2, w3) = (width(0), width(1), width(2), width(3).max(4));
+
+        writeln!(f, "{:<w0$}  {:<w1$}  {:<w2$}  {:>w3$}", header[0], header[1], header[2], header[3])?;
+        for [slot, old, new, delta] in &rows {
+            let line = format!("{slot:<w0$}  {old:<w1$}  {new:<w2$}  {delta:>w3$}");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
@@ -90,3 +90,11 @@ pub fn to_egui_visuals(palette: &Palette) -> ::egui::Visuals {
 
     v
 }
+
+/// Visuals for a point `t` in a transition from palette `a` to `b`.
+///
+/// Call once per frame with an eased `t` for a smooth theme crossfade.
+/// Blends in OKLab; see [`Palette::interpolate`].
+pub fn interpolate_visuals(a: &Palette, b: &Palette, t: f64) -> ::egui::Visuals {
+    to_egui_visuals(&Palette::interpolate(a, b, t))
+}
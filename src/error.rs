@@ -24,6 +24,34 @@ pub enum PaletteError {
         value: Arc<str>,
     },
 
+    #[error("missing required slot [{section}].{field}")]
+    MissingSlot {
+        section: Arc<str>,
+        field: Arc<str>,
+    },
+
+    #[error("unknown syntax slot `{value}` in [{section}].{field}")]
+    UnknownSlot {
+        section: Arc<str>,
+        field: Arc<str>,
+        value: Arc<str>,
+    },
+
+    #[error("invalid semantic token selector `{0}` in [semantic_tokens]")]
+    InvalidSelector(Arc<str>),
+
+    #[error("invalid {format} theme: {reason}")]
+    InvalidTheme {
+        format: Arc<str>,
+        reason: Arc<str>,
+    },
+
     #[error("unknown preset: {0}")]
     UnknownPreset(Arc<str>),
+
+    #[error("cannot resolve include `{0}` without a file path")]
+    UnresolvedInclude(Arc<str>),
+
+    #[error("include cycle detected at {0}")]
+    IncludeCycle(Arc<str>),
 }
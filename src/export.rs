@@ -0,0 +1,358 @@
+use std::fmt::Write;
+
+use crate::color::Color;
+use crate::json::quote;
+use crate::palette::Palette;
+
+/// Terminal emulator config formats [`to_terminal_config`] can render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TerminalFormat {
+    /// `[colors.*]` tables for `alacritty.toml`.
+    Alacritty,
+    /// `kitty.conf` color options.
+    Kitty,
+    /// WezTerm TOML color scheme (`colors/*.toml`).
+    WezTerm,
+    /// `[colors]` section of `foot.ini`.
+    Foot,
+    /// Ghostty theme file.
+    Ghostty,
+    /// Windows Terminal `schemes` entry.
+    WindowsTerminal,
+    /// `~/.Xresources` color resources.
+    Xresources,
+}
+
+impl TerminalFormat {
+    pub const ALL: [Self; 7] = [
+        Self::Alacritty,
+        Self::Kitty,
+        Self::WezTerm,
+        Self::Foot,
+        Self::Ghostty,
+        Self::WindowsTerminal,
+        Self::Xresources,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Alacritty => "alacritty",
+            Self::Kitty => "kitty",
+            Self::WezTerm => "wezterm",
+            Self::Foot => "foot",
+            Self::Ghostty => "ghostty",
+            Self::WindowsTerminal => "windows_terminal",
+            Self::Xresources => "xresources",
+        }
+    }
+
+    /// Conventional file extension, without the dot. Ghostty themes have none.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Alacritty | Self::WezTerm => "toml",
+            Self::Kitty => "conf",
+            Self::Foot => "ini",
+            Self::Ghostty => "",
+            Self::WindowsTerminal => "json",
+            Self::Xresources => "Xresources",
+        }
+    }
+}
+
+/// The colors a terminal emulator config carries, resolved from a palette.
+///
+/// Cursor and selection come from [`Palette::cursor_pair`] and
+/// [`Palette::selection_pair`], so they fall back like the pairs do.
+/// `ansi` is ordered black = 0 … bright_white = 15.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TerminalColors {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub cursor: Option<Color>,
+    pub cursor_text: Option<Color>,
+    pub selection_fg: Option<Color>,
+    pub selection_bg: Option<Color>,
+    pub ansi: [Option<Color>; 16],
+}
+
+impl TerminalColors {
+    pub fn from_palette(palette: &Palette) -> Self {
+        let cursor = palette.cursor_pair();
+        let selection = palette.selection_pair();
+        let mut ansi = [None; 16];
+        for (index, _, color) in palette.terminal_ansi.indexed() {
+            if let Some(slot) = ansi.get_mut(index) {
+                *slot = color.copied();
+            }
+        }
+        Self {
+            foreground: palette.base.foreground,
+            background: palette.base.background,
+            cursor: cursor.bg,
+            cursor_text: cursor.fg,
+            selection_fg: selection.fg,
+            selection_bg: selection.bg,
+            ansi,
+        }
+    }
+
+    /// The eight normal (`bright == false`) or bright ANSI entries, if all set.
+    fn ansi_row(&self, bright: bool) -> Option<Vec<Color>> {
+        self.ansi_half(bright).iter().copied().collect()
+    }
+
+    fn ansi_half(&self, bright: bool) -> &[Option<Color>] {
+        match bright {
+            true => &self.ansi[8..],
+            false => &self.ansi[..8],
+        }
+    }
+}
+
+const ANSI_NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+fn theme_name(palette: &Palette) -> &str {
+    palette.meta.as_ref().map_or("palette", |m| &m.name)
+}
+
+/// `text` with every control character replaced by a space, so it stays on
+/// one comment line.
+pub(crate) fn comment_text(text: &str) -> String {
+    text.chars()
+        .map(|c| match c.is_control() {
+            true => ' ',
+            false => c,
+        })
+        .collect()
+}
+
+/// Lowercase hex without `#`, as foot expects.
+fn bare_hex(color: &Color) -> String {
+    format!("{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+/// `key = "#RRGGBB"` TOML entries, skipping unset colors.
+fn write_toml_entries(out: &mut String, entries: &[(&str, Option<Color>)]) {
+    for (key, color) in entries {
+        if let Some(c) = color {
+            // String::write_fmt is infallible
+            let _ = writeln!(out, "{key} = \"{c}\"");
+        }
+    }
+}
+
+/// A `[header]` table of [`write_toml_entries`], omitted when every color is unset.
+fn write_toml_table(out: &mut String, header: &str, entries: &[(&str, Option<Color>)]) {
+    if entries.iter().any(|(_, c)| c.is_some()) {
+        let _ = writeln!(out, "\n[{header}]");
+        write_toml_entries(out, entries);
+    }
+}
+
+fn ansi_entries(colors: &TerminalColors, bright: bool) -> Vec<(&'static str, Option<Color>)> {
+    ANSI_NAMES.iter().copied().zip(colors.ansi_half(bright).iter().copied()).collect()
+}
+
+pub fn to_alacritty(palette: &Palette) -> String {
+    let colors = TerminalColors::from_palette(palette);
+    let mut out = format!("# {}\n", comment_text(theme_name(palette)));
+    write_toml_table(
+        &mut out,
+        "colors.primary",
+        &[("background", colors.background), ("foreground", colors.foreground)],
+    );
+    write_toml_table(&mut out, "colors.cursor", &[("text", colors.cursor_text), ("cursor", colors.cursor)]);
+    write_toml_table(
+        &mut out,
+        "colors.selection",
+        &[("text", colors.selection_fg), ("background", colors.selection_bg)],
+    );
+    write_toml_table(&mut out, "colors.normal", &ansi_entries(&colors, false));
+    write_toml_table(&mut out, "colors.bright", &ansi_entries(&colors, true));
+    out
+}
+
+pub fn to_kitty(palette: &Palette) -> String {
+    let colors = TerminalColors::from_palette(palette);
+    let named = [
+        ("foreground", colors.foreground),
+        ("background", colors.background),
+        ("cursor", colors.cursor),
+        ("cursor_text_color", colors.cursor_text),
+        ("selection_foreground", colors.selection_fg),
+        ("selection_background", colors.selection_bg),
+    ];
+    let mut out = format!("# {}\n\n", comment_text(theme_name(palette)));
+    for (key, color) in named {
+        if let Some(c) = color {
+            let _ = writeln!(out, "{key} {c}");
+        }
+    }
+    for (index, color) in colors.ansi.iter().enumerate() {
+        if let Some(c) = color {
+            let _ = writeln!(out, "color{index} {c}");
+        }
+    }
+    out
+}
+
+/// WezTerm requires all eight entries in `ansi` and `brights`, so a row with
+/// any unset slot is left out and WezTerm keeps its defaults for it.
+pub fn to_wezterm(palette: &Palette) -> String {
+    let colors = TerminalColors::from_palette(palette);
+    let mut out = String::from("[colors]\n");
+    write_toml_entries(
+        &mut out,
+        &[
+            ("foreground", colors.foreground),
+            ("background", colors.background),
+            ("cursor_bg", colors.cursor),
+            ("cursor_border", colors.cursor),
+            ("cursor_fg", colors.cursor_text),
+            ("selection_bg", colors.selection_bg),
+            ("selection_fg", colors.selection_fg),
+        ],
+    );
+    for (key, bright) in [("ansi", false), ("brights", true)] {
+        if let Some(row) = colors.ansi_row(bright) {
+            let quoted: Vec<String> = row.iter().map(|c| format!("\"{c}\"")).collect();
+            let _ = writeln!(out, "{key} = [{}]", quoted.join(", "));
+        }
+    }
+    let _ = writeln!(out, "\n[metadata]\nname = {}", quote(theme_name(palette)));
+    out
+}
+
+/// Foot wants bare hex, and sets the cursor as `<text> <cursor>` in
+/// `[cursor]`, so that line needs both halves.
+pub fn to_foot(palette: &Palette) -> String {
+    let colors = TerminalColors::from_palette(palette);
+    let named = [
+        ("foreground", colors.foreground),
+        ("background", colors.background),
+        ("selection-foreground", colors.selection_fg),
+        ("selection-background", colors.selection_bg),
+    ];
+    let mut out = format!("# {}\n\n[colors]\n", comment_text(theme_name(palette)));
+    for (key, color) in named {
+        if let Some(c) = color {
+            let _ = writeln!(out, "{key}={}", bare_hex(&c));
+        }
+    }
+    for (index, color) in colors.ansi.iter().enumerate() {
+        let (prefix, n) = match index < 8 {
+            true => ("regular", index),
+            false => ("bright", index - 8),
+        };
+        if let Some(c) = color {
+            let _ = writeln!(out, "{prefix}{n}={}", bare_hex(c));
+        }
+    }
+    if let (Some(text), Some(cursor)) = (colors.cursor_text, colors.cursor) {
+        let _ = writeln!(out, "\n[cursor]\ncolor={} {}", bare_hex(&text), bare_hex(&cursor));
+    }
+    out
+}
+
+pub fn to_ghostty(palette: &Palette) -> String {
+    let colors = TerminalColors::from_palette(palette);
+    let named = [
+        ("background", colors.background),
+        ("foreground", colors.foreground),
+        ("cursor-color", colors.cursor),
+        ("cursor-text", colors.cursor_text),
+        ("selection-background", colors.selection_bg),
+        ("selection-foreground", colors.selection_fg),
+    ];
+    let mut out = format!("# {}\n\n", comment_text(theme_name(palette)));
+    for (index, color) in colors.ansi.iter().enumerate() {
+        if let Some(c) = color {
+            let _ = writeln!(out, "palette = {index}={c}");
+        }
+    }
+    for (key, color) in named {
+        if let Some(c) = color {
+            let _ = writeln!(out, "{key} = {c}");
+        }
+    }
+    out
+}
+
+/// A single object for the `schemes` array of Windows Terminal's
+/// `settings.json`. Its ANSI keys say `purple` where the palette says
+/// `magenta`.
+pub fn to_windows_terminal(palette: &Palette) -> String {
+    let colors = TerminalColors::from_palette(palette);
+    let ansi_keys = [
+        "black",
+        "red",
+        "green",
+        "yellow",
+        "blue",
+        "purple",
+        "cyan",
+        "white",
+        "brightBlack",
+        "brightRed",
+        "brightGreen",
+        "brightYellow",
+        "brightBlue",
+        "brightPurple",
+        "brightCyan",
+        "brightWhite",
+    ];
+    let named = [
+        ("background", colors.background),
+        ("foreground", colors.foreground),
+        ("cursorColor", colors.cursor),
+        ("selectionBackground", colors.selection_bg),
+    ];
+    let mut entries = vec![format!("  \"name\": {}", quote(theme_name(palette)))];
+    let ansi = ansi_keys.into_iter().zip(colors.ansi);
+    for (key, color) in named.into_iter().chain(ansi) {
+        if let Some(c) = color {
+            entries.push(format!("  \"{key}\": \"{c}\""));
+        }
+    }
+    format!("{{\n{}\n}}\n", entries.join(",\n"))
+}
+
+pub fn to_xresources(palette: &Palette) -> String {
+    let colors = TerminalColors::from_palette(palette);
+    let named = [
+        ("foreground", colors.foreground),
+        ("background", colors.background),
+        ("cursorColor", colors.cursor),
+    ];
+    let mut out = format!("! {}\n\n", comment_text(theme_name(palette)));
+    for (key, color) in named {
+        if let Some(c) = color {
+            let _ = writeln!(out, "*.{key}: {c}");
+        }
+    }
+    for (index, color) in colors.ansi.iter().enumerate() {
+        if let Some(c) = color {
+            let _ = writeln!(out, "*.color{index}: {c}");
+        }
+    }
+    out
+}
+
+pub fn to_terminal_config(palette: &Palette, format: TerminalFormat) -> String {
+    match format {
+        TerminalFormat::Alacritty => to_alacritty(palette),
+        TerminalFormat::Kitty => to_kitty(palette),
+        TerminalFormat::WezTerm => to_wezterm(palette),
+        TerminalFormat::Foot => to_foot(palette),
+        TerminalFormat::Ghostty => to_ghostty(palette),
+        TerminalFormat::WindowsTerminal => to_windows_terminal(palette),
+        TerminalFormat::Xresources => to_xresources(palette),
+    }
+}
+
+impl Palette {
+    /// See [`to_terminal_config`].
+    pub fn to_terminal_config(&self, format: TerminalFormat) -> String {
+        to_terminal_config(self, format)
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt::Write;
+
+use crate::color::Color;
+use crate::contrast::SlotRef;
+use crate::export::comment_text;
+use crate::palette::Palette;
+use crate::style::TextStyle;
+
+/// Helix theme scopes with the slots tried for `fg` and `bg`, in order.
+///
+/// Later slots are fallbacks for sparse palettes; a scope whose slots are
+/// all unset is left out so Helix falls back to its parent scope.
+pub const SCOPES: &[(&str, &[SlotRef], &[SlotRef])] = &[
+    // Interface
+    ("ui.background", &[], &[("base", "background")]),
+    ("ui.background.separator", &[("base", "border")], &[]),
+    ("ui.text", &[("base", "foreground")], &[]),
+    ("ui.text.focus", &[("base", "foreground")], &[("surface", "selection")]),
+    ("ui.text.inactive", &[("typography", "comment")], &[]),
+    ("ui.cursor", &[("editor", "cursor_text")], &[("editor", "cursor"), ("base", "foreground")]),
+    ("ui.cursor.primary", &[("editor", "cursor_text")], &[("editor", "cursor"), ("base", "foreground")]),
+    ("ui.cursor.match", &[("editor", "match_paren")], &[]),
+    ("ui.cursorline.primary", &[], &[("surface", "highlight"), ("base", "background_highlight")]),
+    ("ui.selection", &[("editor", "selection_fg")], &[("editor", "selection_bg"), ("surface", "selection")]),
+    ("ui.selection.primary", &[("editor", "selection_fg")], &[("editor", "selection_bg"), ("surface", "selection")]),
+    ("ui.highlight", &[], &[("surface", "highlight"), ("base", "background_highlight")]),
+    ("ui.linenr", &[("typography", "line_number"), ("typography", "gutter")], &[]),
+    ("ui.linenr.selected", &[("base", "foreground")], &[]),
+    ("ui.statusline", &[("base", "foreground_dark"), ("base", "foreground")], &[("surface", "statusline"), ("base", "background_dark")]),
+    ("ui.statusline.inactive", &[("typography", "comment")], &[("base", "background_dark"), ("surface", "statusline")]),
+    ("ui.popup", &[("base", "foreground")], &[("surface", "popup"), ("surface", "float")]),
+    ("ui.help", &[("base", "foreground")], &[("surface", "float"), ("surface", "popup")]),
+    ("ui.menu", &[("base", "foreground")], &[("surface", "menu"), ("surface", "popup")]),
+    ("ui.menu.selected", &[("editor", "selection_fg")], &[("surface", "selection")]),
+    ("ui.window", &[("base", "border")], &[]),
+    ("ui.virtual.whitespace", &[("typography", "gutter")], &[]),
+    ("ui.virtual.ruler", &[], &[("base", "background_highlight")]),
+    ("ui.virtual.inlay-hint", &[("editor", "inlay_hint_fg"), ("typography", "comment")], &[("editor", "inlay_hint_bg")]),
+    // Diagnostics and diff gutter
+    ("error", &[("editor", "diagnostic_error"), ("semantic", "error")], &[]),
+    ("warning", &[("editor", "diagnostic_warn"), ("semantic", "warning")], &[]),
+    ("info", &[("editor", "diagnostic_info"), ("semantic", "info")], &[]),
+    ("hint", &[("editor", "diagnostic_hint"), ("semantic", "hint")], &[]),
+    ("diff.plus", &[("diff", "added")], &[]),
+    ("diff.delta", &[("diff", "modified")], &[]),
+    ("diff.minus", &[("diff", "removed")], &[]),
+    // Syntax
+    ("keyword", &[("syntax", "keywords")], &[]),
+    ("keyword.function", &[("syntax", "keywords_fn"), ("syntax", "keywords")], &[]),
+    ("keyword.operator", &[("syntax", "operators")], &[]),
+    ("operator", &[("syntax", "operators")], &[]),
+    ("function", &[("syntax", "functions")], &[]),
+    ("function.macro", &[("syntax", "attributes"), ("syntax", "functions")], &[]),
+    ("constructor", &[("syntax", "constructor")], &[]),
+    ("variable", &[("syntax", "variables")], &[]),
+    ("variable.builtin", &[("syntax", "variables_builtin")], &[]),
+    ("variable.parameter", &[("syntax", "parameters")], &[]),
+    ("variable.other.member", &[("syntax", "properties")], &[]),
+    ("type", &[("syntax", "types")], &[]),
+    ("type.builtin", &[("syntax", "types_builtin")], &[]),
+    ("constant", &[("syntax", "constants")], &[]),
+    ("constant.numeric", &[("syntax", "numbers")], &[]),
+    ("constant.builtin.boolean", &[("syntax", "booleans")], &[]),
+    ("constant.character.escape", &[("syntax", "strings_escape")], &[]),
+    ("string", &[("syntax", "strings")], &[]),
+    ("string.regexp", &[("syntax", "strings_regex")], &[]),
+    ("comment", &[("syntax", "comments"), ("typography", "comment")], &[]),
+    ("comment.block.documentation", &[("syntax", "strings_doc")], &[]),
+    ("punctuation", &[("syntax", "punctuation")], &[]),
+    ("punctuation.bracket", &[("syntax", "punctuation_bracket")], &[]),
+    ("punctuation.delimiter", &[("syntax", "punctuation")], &[]),
+    ("attribute", &[("syntax", "attributes"), ("syntax", "annotations")], &[]),
+    ("tag", &[("syntax", "tag")], &[]),
+    // Markup
+    ("markup.heading", &[("typography", "title")], &[]),
+    ("markup.link.url", &[("typography", "link")], &[]),
+    ("markup.link.text", &[("typography", "link")], &[]),
+    ("markup.quote", &[("typography", "comment")], &[]),
+    ("markup.raw", &[("syntax", "strings")], &[]),
+];
+
+/// `diagnostic.*` scopes: curly underlines in the first set slot's color.
+pub const DIAGNOSTICS: [(&str, &[SlotRef]); 4] = [
+    (
+        "diagnostic.error",
+        &[("editor", "diagnostic_underline_error"), ("editor", "diagnostic_error"), ("semantic", "error")],
+    ),
+    (
+        "diagnostic.warning",
+        &[("editor", "diagnostic_underline_warn"), ("editor", "diagnostic_warn"), ("semantic", "warning")],
+    ),
+    (
+        "diagnostic.info",
+        &[("editor", "diagnostic_underline_info"), ("editor", "diagnostic_info"), ("semantic", "info")],
+    ),
+    (
+        "diagnostic.hint",
+        &[("editor", "diagnostic_underline_hint"), ("editor", "diagnostic_hint"), ("semantic", "hint")],
+    ),
+];
+
+/// A scope value: a bare color when only `fg` is set, otherwise an inline
+/// table. Underline and undercurl become an `underline` table; the other
+/// flags become `modifiers`.
+fn scope_value(fg: Option<Color>, bg: Option<Color>, style: Option<TextStyle>) -> Option<String> {
+    let style = style.filter(|s| !s.is_plain());
+    if let (Some(fg), None, None) = (fg, bg, style) {
+        return Some(format!("\"{fg}\""));
+    }
+    let mut fields: Vec<String> = [("fg", fg), ("bg", bg)]
+        .iter()
+        .filter_map(|(key, color)| color.map(|c| format!("{key} = \"{c}\"")))
+        .collect();
+    if fields.is_empty() {
+        return None;
+    }
+    if let Some(style) = style {
+        let flags = [(style.bold, "\"bold\""), (style.italic, "\"italic\""), (style.strikethrough, "\"crossed_out\"")];
+        let modifiers: Vec<&str> = flags.iter().filter_map(|(set, name)| set.then_some(*name)).collect();
+        if !modifiers.is_empty() {
+            fields.push(format!("modifiers = [{}]", modifiers.join(", ")));
+        }
+        let underline = match (style.undercurl, style.underline) {
+            (true, _) => Some("curl"),
+            (false, true) => Some("line"),
+            (false, false) => None,
+        };
+        if let Some(kind) = underline {
+            fields.push(format!("underline = {{ style = \"{kind}\" }}"));
+        }
+    }
+    Some(format!("{{ {} }}", fields.join(", ")))
+}
+
+/// A Helix `theme.toml` for `~/.config/helix/themes/`.
+///
+/// Colors are written inline rather than through a `[palette]` table, so
+/// the file reads the same as the palette it came from.
+pub fn to_helix_theme(palette: &Palette) -> String {
+    let name = palette.meta.as_ref().map_or("palette", |m| &m.name);
+    let mut out = format!("# {}\n\n", comment_text(name));
+    for (scope, fg, bg) in SCOPES {
+        let fg = palette.pick_slot(fg);
+        let bg = palette.pick_slot(bg).map(|(c, _)| c);
+        if let Some(value) = scope_value(fg.map(|(c, _)| c), bg, fg.and_then(|(_, s)| s)) {
+            // String::write_fmt is infallible
+            let _ = writeln!(out, "\"{scope}\" = {value}");
+        }
+    }
+    for (scope, slots) in DIAGNOSTICS {
+        if let Some((color, _)) = palette.pick_slot(slots) {
+            let _ = writeln!(out, "\"{scope}\" = {{ underline = {{ color = \"{color}\", style = \"curl\" }} }}");
+        }
+    }
+    out
+}
+
+impl Palette {
+    /// See [`to_helix_theme`].
+    pub fn to_helix_theme(&self) -> String {
+        to_helix_theme(self)
+    }
+}
@@ -0,0 +1,145 @@
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::style::TextStyle;
+
+/// Tree-sitter capture names (without `@`) and the `syntax` slot each one
+/// maps to. Captures not listed here resolve through their dotted prefix.
+const CAPTURES: [(&str, &str); 45] = [
+    ("annotation", "annotations"),
+    ("attribute", "attributes"),
+    ("boolean", "booleans"),
+    ("comment", "comments"),
+    ("comment.documentation", "comments"),
+    ("constant", "constants"),
+    ("constant.builtin", "constants"),
+    ("constant.numeric", "numbers"),
+    ("constructor", "constructor"),
+    ("decorator", "annotations"),
+    ("escape", "strings_escape"),
+    ("field", "properties"),
+    ("float", "numbers"),
+    ("function", "functions"),
+    ("function.builtin", "functions"),
+    ("function.call", "functions"),
+    ("function.macro", "functions"),
+    ("function.method", "functions"),
+    ("keyword", "keywords"),
+    ("keyword.function", "keywords_fn"),
+    ("method", "functions"),
+    ("number", "numbers"),
+    ("number.float", "numbers"),
+    ("operator", "operators"),
+    ("parameter", "parameters"),
+    ("property", "properties"),
+    ("punctuation", "punctuation"),
+    ("punctuation.bracket", "punctuation_bracket"),
+    ("punctuation.delimiter", "punctuation"),
+    ("punctuation.special", "punctuation"),
+    ("string", "strings"),
+    ("string.doc", "strings_doc"),
+    ("string.documentation", "strings_doc"),
+    ("string.escape", "strings_escape"),
+    ("string.regex", "strings_regex"),
+    ("string.regexp", "strings_regex"),
+    ("string.special.regex", "strings_regex"),
+    ("tag", "tag"),
+    ("tag.attribute", "tag_attribute"),
+    ("tag.delimiter", "tag_delimiter"),
+    ("type", "types"),
+    ("type.builtin", "types_builtin"),
+    ("variable", "variables"),
+    ("variable.builtin", "variables_builtin"),
+    ("variable.parameter", "parameters"),
+];
+
+/// Every capture name with a direct mapping, suitable for
+/// `HighlightConfiguration::configure`.
+pub fn capture_names() -> impl Iterator<Item = &'static str> {
+    CAPTURES.iter().map(|(name, _)| *name)
+}
+
+fn direct_slot(capture: &str) -> Option<&'static str> {
+    CAPTURES
+        .binary_search_by(|(name, _)| (*name).cmp(capture))
+        .ok()
+        .map(|i| CAPTURES[i].1)
+}
+
+/// The capture followed by each shorter dotted prefix:
+/// `keyword.control.return`, `keyword.control`, `keyword`.
+fn prefixes(capture: &str) -> impl Iterator<Item = &str> {
+    let capture = capture.strip_prefix('@').unwrap_or(capture);
+    std::iter::successors(Some(capture), |c| c.rsplit_once('.').map(|(head, _)| head))
+}
+
+/// Map a tree-sitter capture name to a `syntax` slot.
+///
+/// A leading `@` is ignored. Unknown names fall back to their longest known
+/// dotted prefix, so `@keyword.control.return` maps to `keywords`.
+pub fn syntax_slot(capture: &str) -> Option<&'static str> {
+    prefixes(capture).find_map(direct_slot)
+}
+
+impl Palette {
+    /// Color for a tree-sitter capture.
+    ///
+    /// Like [`syntax_slot`], but also falls back along the prefix when the
+    /// mapped slot is unset: `@keyword.function` uses `keywords` if the
+    /// palette has no `keywords_fn`.
+    pub fn capture_color(&self, capture: &str) -> Option<Color> {
+        prefixes(capture)
+            .filter_map(direct_slot)
+            .find_map(|field| self.syntax.get(field))
+            .copied()
+    }
+
+    /// Text style for a tree-sitter capture, resolved like [`capture_color`](Self::capture_color).
+    pub fn capture_style(&self, capture: &str) -> Option<TextStyle> {
+        prefixes(capture)
+            .filter_map(direct_slot)
+            .find_map(|field| self.styles.syntax.get(field))
+            .copied()
+    }
+}
+
+/// Colors and styles precomputed for a fixed list of capture names.
+///
+/// Index `i` corresponds to `names[i]`, matching the `Highlight(i)` values
+/// tree-sitter-highlight emits after `configure(names)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighlightTable {
+    colors: Box<[Option<Color>]>,
+    styles: Box<[Option<TextStyle>]>,
+}
+
+impl HighlightTable {
+    pub fn new<S: AsRef<str>>(palette: &Palette, names: &[S]) -> Self {
+        Self {
+            colors: names.iter().map(|n| palette.capture_color(n.as_ref())).collect(),
+            styles: names.iter().map(|n| palette.capture_style(n.as_ref())).collect(),
+        }
+    }
+
+    pub fn color(&self, index: usize) -> Option<Color> {
+        self.colors.get(index).copied().flatten()
+    }
+
+    pub fn style(&self, index: usize) -> Option<TextStyle> {
+        self.styles.get(index).copied().flatten()
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+}
+
+impl Palette {
+    /// See [`HighlightTable::new`].
+    pub fn highlight_table<S: AsRef<str>>(&self, names: &[S]) -> HighlightTable {
+        HighlightTable::new(self, names)
+    }
+}
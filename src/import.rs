@@ -0,0 +1,409 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::error::PaletteError;
+use crate::export::TerminalColors;
+use crate::json::{self, Value};
+use crate::manifest::{ManifestSection, PaletteManifest};
+use crate::palette::TerminalAnsiColors;
+use crate::plist;
+use crate::textmate::invalid_theme;
+
+/// Terminal emulator config formats [`import`] can read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImportFormat {
+    /// `alacritty.toml` `[colors.*]` tables.
+    Alacritty,
+    /// `kitty.conf` color options.
+    Kitty,
+    /// WezTerm TOML color scheme.
+    WezTerm,
+    /// A single Windows Terminal `schemes` entry.
+    WindowsTerminal,
+    /// iTerm2 `.itermcolors` XML property list.
+    ITerm2,
+    /// `~/.Xresources` color resources, with simple `#define` substitution.
+    Xresources,
+}
+
+impl ImportFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Alacritty => "alacritty",
+            Self::Kitty => "kitty",
+            Self::WezTerm => "wezterm",
+            Self::WindowsTerminal => "windows_terminal",
+            Self::ITerm2 => "iterm2",
+            Self::Xresources => "xresources",
+        }
+    }
+
+    /// Pick a format from a file's extension, sniffing the contents of
+    /// `.toml` files to tell Alacritty and WezTerm apart and of `.json` files
+    /// to recognize a Windows Terminal scheme.
+    ///
+    /// Returns `None` for anything else, including palette manifests.
+    pub fn detect(path: &Path, contents: &str) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("conf") => Some(Self::Kitty),
+            Some("json") => detect_json(contents),
+            Some("itermcolors") => Some(Self::ITerm2),
+            Some("toml") => detect_toml(contents),
+            _ if file_name.contains("Xresources") || file_name.contains("Xdefaults") => Some(Self::Xresources),
+            _ => None,
+        }
+    }
+}
+
+fn detect_toml(contents: &str) -> Option<ImportFormat> {
+    let doc: toml::Table = toml::from_str(contents).ok()?;
+    if doc.contains_key("base") || doc.contains_key("meta") {
+        return None;
+    }
+    let colors = doc.get("colors")?.as_table()?;
+    match colors.contains_key("primary") || colors.contains_key("normal") {
+        true => Some(ImportFormat::Alacritty),
+        false => Some(ImportFormat::WezTerm),
+    }
+}
+
+fn detect_json(contents: &str) -> Option<ImportFormat> {
+    let doc = json::parse(contents).ok()?;
+    is_windows_terminal_scheme(&doc).then_some(ImportFormat::WindowsTerminal)
+}
+
+/// Colors read from a terminal emulator config, plus the theme name when the
+/// format carries one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportedTheme {
+    pub name: Option<Arc<str>>,
+    pub colors: TerminalColors,
+}
+
+impl ImportedTheme {
+    /// Build a manifest populating `[base]`, `[terminal]`, and the `editor`
+    /// cursor and selection slots.
+    ///
+    /// `id` becomes the preset ID, and the name when the file had none.
+    /// `style` is `dark` or `light` from the background's luminance.
+    pub fn to_manifest(&self, id: &str) -> Result<PaletteManifest, PaletteError> {
+        let colors = &self.colors;
+        let background = colors.background.ok_or_else(|| PaletteError::MissingSlot {
+            section: Arc::from("base"),
+            field: Arc::from("background"),
+        })?;
+        let style = match background.is_light() {
+            true => "light",
+            false => "dark",
+        };
+
+        let ansi_names = TerminalAnsiColors::default().indexed().map(|(_, name, _)| name).collect::<Vec<_>>();
+        let name = self.name.clone().unwrap_or_else(|| Arc::from(id));
+        Ok(PaletteManifest {
+            base: section([("background", colors.background), ("foreground", colors.foreground)]),
+            editor: section([
+                ("cursor", colors.cursor),
+                ("cursor_text", colors.cursor_text),
+                ("selection_bg", colors.selection_bg),
+                ("selection_fg", colors.selection_fg),
+            ]),
+            terminal: section(ansi_names.into_iter().zip(colors.ansi)),
+            ..PaletteManifest::imported(name, id, style)
+        })
+    }
+}
+
+fn section<'a>(entries: impl IntoIterator<Item = (&'a str, Option<Color>)>) -> ManifestSection {
+    entries
+        .into_iter()
+        .filter_map(|(field, color)| color.map(|c| (Arc::from(field), Arc::from(c.to_hex()))))
+        .collect()
+}
+
+/// Parse `#rrggbb`, `#rgb`, or Alacritty's `0xrrggbb`.
+fn parse_color(format: ImportFormat, field: &str, value: &str) -> Result<Color, PaletteError> {
+    let value = value.trim();
+    let digits = value.strip_prefix('#').or_else(|| value.strip_prefix("0x"));
+    let hex = match digits {
+        Some(d) if d.len() == 3 && d.is_ascii() => d.chars().flat_map(|c| [c, c]).collect(),
+        Some(d) => d.to_owned(),
+        None => value.to_owned(),
+    };
+    Color::from_hex(&format!("#{hex}")).map_err(|_| PaletteError::InvalidHex {
+        section: Arc::from(format.name()),
+        field: Arc::from(field),
+        value: Arc::from(value),
+    })
+}
+
+const ANSI_NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+/// Where a format-specific key lands in [`TerminalColors`].
+#[derive(Clone, Copy)]
+enum Slot {
+    Foreground,
+    Background,
+    Cursor,
+    CursorText,
+    SelectionFg,
+    SelectionBg,
+    Ansi(usize),
+}
+
+fn slot_mut(colors: &mut TerminalColors, slot: Slot) -> &mut Option<Color> {
+    match slot {
+        Slot::Foreground => &mut colors.foreground,
+        Slot::Background => &mut colors.background,
+        Slot::Cursor => &mut colors.cursor,
+        Slot::CursorText => &mut colors.cursor_text,
+        Slot::SelectionFg => &mut colors.selection_fg,
+        Slot::SelectionBg => &mut colors.selection_bg,
+        Slot::Ansi(i) => &mut colors.ansi[i],
+    }
+}
+
+/// `color0` … `color15`.
+fn numbered_color(key: &str) -> Option<Slot> {
+    let index: usize = key.strip_prefix("color")?.parse().ok()?;
+    (index < 16).then_some(Slot::Ansi(index))
+}
+
+fn toml_color(format: ImportFormat, table: &toml::Table, key: &str) -> Result<Option<Color>, PaletteError> {
+    match table.get(key).and_then(toml::Value::as_str) {
+        // Alacritty's `CellForeground` / `CellBackground` follow the cell, not a fixed color
+        Some(v) if v.starts_with("Cell") => Ok(None),
+        Some(v) => parse_color(format, key, v).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn subtable<'a>(table: &'a toml::Table, key: &str) -> Option<&'a toml::Table> {
+    table.get(key).and_then(toml::Value::as_table)
+}
+
+pub fn from_alacritty(s: &str) -> Result<ImportedTheme, PaletteError> {
+    let format = ImportFormat::Alacritty;
+    let doc: toml::Table = toml::from_str(s)?;
+    let empty = toml::Table::new();
+    let colors = subtable(&doc, "colors").unwrap_or(&empty);
+    let group = |name| subtable(colors, name).unwrap_or(&empty);
+
+    let mut out = TerminalColors {
+        foreground: toml_color(format, group("primary"), "foreground")?,
+        background: toml_color(format, group("primary"), "background")?,
+        cursor: toml_color(format, group("cursor"), "cursor")?,
+        cursor_text: toml_color(format, group("cursor"), "text")?,
+        selection_fg: toml_color(format, group("selection"), "text")?,
+        selection_bg: toml_color(format, group("selection"), "background")?,
+        ..TerminalColors::default()
+    };
+    for (offset, name) in [(0, "normal"), (8, "bright")] {
+        for (i, key) in ANSI_NAMES.iter().enumerate() {
+            out.ansi[offset + i] = toml_color(format, group(name), key)?;
+        }
+    }
+    Ok(ImportedTheme { name: None, colors: out })
+}
+
+pub fn from_wezterm(s: &str) -> Result<ImportedTheme, PaletteError> {
+    let format = ImportFormat::WezTerm;
+    let doc: toml::Table = toml::from_str(s)?;
+    let empty = toml::Table::new();
+    let colors = subtable(&doc, "colors").unwrap_or(&empty);
+
+    let mut out = TerminalColors {
+        foreground: toml_color(format, colors, "foreground")?,
+        background: toml_color(format, colors, "background")?,
+        cursor: toml_color(format, colors, "cursor_bg")?,
+        cursor_text: toml_color(format, colors, "cursor_fg")?,
+        selection_fg: toml_color(format, colors, "selection_fg")?,
+        selection_bg: toml_color(format, colors, "selection_bg")?,
+        ..TerminalColors::default()
+    };
+    for (offset, key) in [(0, "ansi"), (8, "brights")] {
+        let values = colors.get(key).and_then(toml::Value::as_array).map_or(&[][..], Vec::as_slice);
+        for (i, value) in values.iter().take(8).enumerate() {
+            if let Some(v) = value.as_str() {
+                out.ansi[offset + i] = Some(parse_color(format, key, v)?);
+            }
+        }
+    }
+    let name = subtable(&doc, "metadata")
+        .and_then(|m| m.get("name"))
+        .and_then(toml::Value::as_str)
+        .map(Arc::from);
+    Ok(ImportedTheme { name, colors: out })
+}
+
+pub fn from_kitty(s: &str) -> Result<ImportedTheme, PaletteError> {
+    let mut colors = TerminalColors::default();
+    for line in s.lines().map(str::trim) {
+        let Some((key, value)) = line.split_once(char::is_whitespace) else {
+            continue;
+        };
+        let slot = match key {
+            "foreground" => Slot::Foreground,
+            "background" => Slot::Background,
+            "cursor" => Slot::Cursor,
+            "cursor_text_color" => Slot::CursorText,
+            "selection_foreground" => Slot::SelectionFg,
+            "selection_background" => Slot::SelectionBg,
+            other => match numbered_color(other) {
+                Some(slot) => slot,
+                None => continue,
+            },
+        };
+        // `none` and `background` defer to the cell colors
+        let value = value.trim();
+        if value == "none" || value == "background" {
+            continue;
+        }
+        *slot_mut(&mut colors, slot) = Some(parse_color(ImportFormat::Kitty, key, value)?);
+    }
+    Ok(ImportedTheme { name: None, colors })
+}
+
+/// `//` comments and trailing commas, allowed in `settings.json`, are
+/// accepted. Values that aren't strings are skipped.
+pub fn from_windows_terminal(s: &str) -> Result<ImportedTheme, PaletteError> {
+    let doc = json::parse(s).map_err(|reason| invalid_theme(ImportFormat::WindowsTerminal.name(), reason))?;
+    windows_terminal_scheme(&doc)
+}
+
+const WINDOWS_TERMINAL_ANSI: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "purple",
+    "cyan",
+    "white",
+    "brightBlack",
+    "brightRed",
+    "brightGreen",
+    "brightYellow",
+    "brightBlue",
+    "brightPurple",
+    "brightCyan",
+    "brightWhite",
+];
+
+/// A Windows Terminal scheme is a JSON object with its own ANSI key names.
+pub(crate) fn is_windows_terminal_scheme(doc: &Value) -> bool {
+    doc.get("purple").is_some() || doc.get("brightBlack").is_some()
+}
+
+/// [`from_windows_terminal`] for an already parsed document.
+pub(crate) fn windows_terminal_scheme(doc: &Value) -> Result<ImportedTheme, PaletteError> {
+    let mut colors = TerminalColors::default();
+    let mut name = None;
+    for (key, value) in doc.entries() {
+        let Some(value) = value.as_str() else {
+            continue;
+        };
+        let slot = match key.as_str() {
+            "name" => {
+                name = Some(Arc::from(value));
+                continue;
+            }
+            "foreground" => Slot::Foreground,
+            "background" => Slot::Background,
+            "cursorColor" => Slot::Cursor,
+            "selectionBackground" => Slot::SelectionBg,
+            other => match WINDOWS_TERMINAL_ANSI.iter().position(|k| *k == other) {
+                Some(i) => Slot::Ansi(i),
+                None => continue,
+            },
+        };
+        *slot_mut(&mut colors, slot) = Some(parse_color(ImportFormat::WindowsTerminal, key, value)?);
+    }
+    Ok(ImportedTheme { name, colors })
+}
+
+/// One `<dict>` of `Red/Green/Blue Component` numbers (0.0–1.0).
+fn iterm_color(dict: &Value) -> Option<Color> {
+    let component = |name: &str| -> Option<u8> {
+        let value = dict.get(&format!("{name} Component"))?.as_f64()?;
+        Some((value.clamp(0.0, 1.0) * 255.0).round() as u8)
+    };
+    Some(Color {
+        r: component("Red")?,
+        g: component("Green")?,
+        b: component("Blue")?,
+    })
+}
+
+/// Entries without all three components are skipped.
+pub fn from_iterm2(s: &str) -> Result<ImportedTheme, PaletteError> {
+    let doc = plist::parse(s).map_err(|reason| invalid_theme(ImportFormat::ITerm2.name(), reason))?;
+    let mut colors = TerminalColors::default();
+    for (key, dict) in doc.entries() {
+        let slot = match key.as_str() {
+            "Foreground Color" => Slot::Foreground,
+            "Background Color" => Slot::Background,
+            "Cursor Color" => Slot::Cursor,
+            "Cursor Text Color" => Slot::CursorText,
+            "Selected Text Color" => Slot::SelectionFg,
+            "Selection Color" => Slot::SelectionBg,
+            other => {
+                let index = other.strip_prefix("Ansi ").and_then(|k| k.strip_suffix(" Color"));
+                match index.and_then(|i| i.parse::<usize>().ok()).filter(|i| *i < 16) {
+                    Some(i) => Slot::Ansi(i),
+                    None => continue,
+                }
+            }
+        };
+        *slot_mut(&mut colors, slot) = iterm_color(dict);
+    }
+    Ok(ImportedTheme { name: None, colors })
+}
+
+pub fn from_xresources(s: &str) -> Result<ImportedTheme, PaletteError> {
+    let mut defines: BTreeMap<&str, &str> = BTreeMap::new();
+    let mut colors = TerminalColors::default();
+    for line in s.lines().map(str::trim) {
+        if let Some(define) = line.strip_prefix("#define") {
+            let mut parts = define.split_whitespace();
+            if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+                defines.insert(name, value);
+            }
+            continue;
+        }
+        if line.starts_with('!') {
+            continue;
+        }
+        let Some((resource, value)) = line.split_once(':') else {
+            continue;
+        };
+        // `*.color0`, `*color0`, `URxvt*color0`, `XTerm.vt100.foreground`
+        let key = resource.trim().rsplit(['.', '*']).next().unwrap_or_default();
+        let slot = match key {
+            "foreground" => Slot::Foreground,
+            "background" => Slot::Background,
+            "cursorColor" => Slot::Cursor,
+            other => match numbered_color(other) {
+                Some(slot) => slot,
+                None => continue,
+            },
+        };
+        let value = value.trim();
+        let value = defines.get(value).copied().unwrap_or(value);
+        *slot_mut(&mut colors, slot) = Some(parse_color(ImportFormat::Xresources, key, value)?);
+    }
+    Ok(ImportedTheme { name: None, colors })
+}
+
+pub fn import(s: &str, format: ImportFormat) -> Result<ImportedTheme, PaletteError> {
+    match format {
+        ImportFormat::Alacritty => from_alacritty(s),
+        ImportFormat::Kitty => from_kitty(s),
+        ImportFormat::WezTerm => from_wezterm(s),
+        ImportFormat::WindowsTerminal => from_windows_terminal(s),
+        ImportFormat::ITerm2 => from_iterm2(s),
+        ImportFormat::Xresources => from_xresources(s),
+    }
+}
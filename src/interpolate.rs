@@ -0,0 +1,113 @@
+use crate::color::Color;
+use crate::manipulation::{interpolate, BlendSpace};
+use crate::palette::{CustomColors, Palette};
+
+/// Blend one slot. A slot set on only one side keeps that side's color for
+/// the whole transition and appears or disappears only at the endpoint where
+/// the other palette takes over, so `t = 0` and `t = 1` reproduce the inputs
+/// exactly.
+fn interpolate_slot(a: Option<Color>, b: Option<Color>, t: f64, space: BlendSpace) -> Option<Color> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(interpolate(a, b, t, space)),
+        (Some(a), None) => (t < 1.0).then_some(a),
+        (None, Some(b)) => (t > 0.0).then_some(b),
+        (None, None) => None,
+    }
+}
+
+fn interpolate_custom(a: &CustomColors, b: &CustomColors, t: f64, space: BlendSpace) -> CustomColors {
+    let empty = Default::default();
+    let names: std::collections::BTreeSet<_> = a.keys().chain(b.keys()).collect();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let (sa, sb) = (a.get(name).unwrap_or(&empty), b.get(name).unwrap_or(&empty));
+            let fields: std::collections::BTreeSet<_> = sa.keys().chain(sb.keys()).collect();
+            let blended: std::collections::BTreeMap<_, _> = fields
+                .into_iter()
+                .filter_map(|field| {
+                    let color = interpolate_slot(sa.get(field).copied(), sb.get(field).copied(), t, space);
+                    color.map(|c| (field.clone(), c))
+                })
+                .collect();
+            match blended.is_empty() {
+                true => None,
+                false => Some((name.clone(), blended)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "platform")]
+fn interpolate_platform(
+    a: &crate::platform::PlatformOverrides,
+    b: &crate::platform::PlatformOverrides,
+    t: f64,
+    space: BlendSpace,
+) -> crate::platform::PlatformOverrides {
+    use crate::platform::PlatformOverride;
+
+    let empty = PlatformOverride {
+        background: None,
+        foreground: None,
+    };
+    let names: std::collections::BTreeSet<_> = a.keys().chain(b.keys()).collect();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let (pa, pb) = (a.get(name).unwrap_or(&empty), b.get(name).unwrap_or(&empty));
+            let blended = PlatformOverride {
+                background: interpolate_slot(pa.background, pb.background, t, space),
+                foreground: interpolate_slot(pa.foreground, pb.foreground, t, space),
+            };
+            match blended == empty {
+                true => None,
+                false => Some((name.clone(), blended)),
+            }
+        })
+        .collect()
+}
+
+impl Palette {
+    /// Blend every slot from `a` (at `t = 0`) to `b` (at `t = 1`) in OKLab.
+    ///
+    /// For sRGB mixing, use [`interpolate_in`](Self::interpolate_in).
+    pub fn interpolate(a: &Palette, b: &Palette, t: f64) -> Palette {
+        Self::interpolate_in(a, b, t, BlendSpace::Oklab)
+    }
+
+    /// Blend every slot from `a` to `b` in the given color space.
+    ///
+    /// `t` is clamped to `[0, 1]`; non-finite `t` returns `a`. Slots set on
+    /// only one side hold their color until the opposite endpoint. `meta`
+    /// and text styles come from whichever palette `t` is closer to.
+    pub fn interpolate_in(a: &Palette, b: &Palette, t: f64, space: BlendSpace) -> Palette {
+        let t = match t.is_finite() {
+            true => t.clamp(0.0, 1.0),
+            false => 0.0,
+        };
+        let slot = |x, y| interpolate_slot(x, y, t, space);
+
+        let nearer = match t < 0.5 {
+            true => a,
+            false => b,
+        };
+
+        Palette {
+            meta: nearer.meta.clone(),
+            base: a.base.zip_with(&b.base, slot),
+            semantic: a.semantic.zip_with(&b.semantic, slot),
+            diff: a.diff.zip_with(&b.diff, slot),
+            surface: a.surface.zip_with(&b.surface, slot),
+            typography: a.typography.zip_with(&b.typography, slot),
+            syntax: a.syntax.zip_with(&b.syntax, slot),
+            editor: a.editor.zip_with(&b.editor, slot),
+            terminal_ansi: a.terminal_ansi.zip_with(&b.terminal_ansi, slot),
+            styles: nearer.styles.clone(),
+            semantic_tokens: nearer.semantic_tokens.clone(),
+            custom: interpolate_custom(&a.custom, &b.custom, t, space),
+            #[cfg(feature = "platform")]
+            platform: interpolate_platform(&a.platform, &b.platform, t, space),
+        }
+    }
+}
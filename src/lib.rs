@@ -6,20 +6,52 @@ pub mod manifest;
 pub mod merge;
 pub mod palette;
 pub mod registry;
+pub mod style;
 
+pub mod base16;
 pub mod contrast;
 pub mod css;
+pub mod diff;
+pub mod export;
+pub mod helix;
+pub mod highlight;
+pub mod import;
+pub mod interpolate;
 pub mod manipulation;
+pub mod neovim;
+pub mod pairs;
+pub mod semantic_tokens;
+pub mod stylesheet;
+pub mod tailwind;
+pub mod textmate;
+pub mod transform;
+pub mod vscode;
+pub mod zed;
+
+mod json;
+mod plist;
 
 pub use color::Color;
 pub use contrast::ContrastLevel;
+pub use diff::PaletteDiff;
 pub use error::PaletteError;
+pub use pairs::StylePair;
 pub use palette::{Palette, PaletteMeta};
 pub use registry::{load_preset, load_preset_file, preset, preset_ids, Registry, ThemeInfo};
+pub use style::TextStyle;
 
-#[cfg(feature = "terminal")]
+#[cfg(any(feature = "terminal", feature = "osc"))]
 pub mod terminal;
 
+#[cfg(feature = "crossterm")]
+pub mod crossterm;
+
+#[cfg(feature = "anstyle")]
+pub mod anstyle;
+
+#[cfg(feature = "syntect")]
+pub mod syntect;
+
 #[cfg(feature = "platform")]
 pub mod platform;
 
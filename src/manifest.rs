@@ -1,12 +1,20 @@
 use std::collections::BTreeMap;
+use std::fmt;
 use std::sync::Arc;
 
-use serde::Deserialize;
+use serde::de::{self, Error as _, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
 
 use crate::error::PaletteError;
+use crate::style::TextStyle;
 
 pub type ManifestSection = BTreeMap<Arc<str>, Arc<str>>;
 pub type PlatformSections = BTreeMap<Arc<str>, ManifestSection>;
+pub type StyleSection = BTreeMap<Arc<str>, TextStyle>;
+/// Application-defined sections (`[custom.app]`, `[custom.chart]`, ...) keyed by section name.
+pub type CustomSections = BTreeMap<Arc<str>, ManifestSection>;
+/// Text styles keyed by section name (`syntax`, `editor`, ...), then field.
+pub type StyleSections = BTreeMap<Arc<str>, StyleSection>;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ManifestMeta {
@@ -24,6 +32,8 @@ pub struct ManifestMeta {
 #[derive(Debug, Clone)]
 pub struct PaletteManifest {
     pub meta: Option<ManifestMeta>,
+    /// Fragment paths from the top-level `include` key, relative to this file.
+    pub include: Vec<Arc<str>>,
     pub base: ManifestSection,
     pub semantic: ManifestSection,
     pub diff: ManifestSection,
@@ -32,6 +42,12 @@ pub struct PaletteManifest {
     pub syntax: ManifestSection,
     pub editor: ManifestSection,
     pub terminal: ManifestSection,
+    /// Styles from inline-table entries; their `fg` lands in the color sections.
+    pub styles: StyleSections,
+    /// `[semantic_tokens]`: LSP token selector to `syntax` slot name.
+    pub semantic_tokens: ManifestSection,
+    /// `[custom.*]` tables, parsed as color slots like the built-in groups.
+    pub custom: CustomSections,
     #[cfg(feature = "platform")]
     pub platform: PlatformSections,
 }
@@ -42,25 +58,194 @@ impl PaletteManifest {
 
         match raw.base {
             None => Err(PaletteError::MissingBase),
-            Some(base) => Ok(Self {
-                meta: raw.meta,
-                base,
-                semantic: raw.semantic,
-                diff: raw.diff,
-                surface: raw.surface,
-                typography: raw.typography,
-                syntax: raw.syntax,
-                editor: raw.editor,
-                terminal: raw.terminal,
-                #[cfg(feature = "platform")]
-                platform: raw.platform,
-            }),
+            Some(_) => Ok(raw.into_manifest()),
         }
     }
 
+    /// Parse a partial manifest referenced by an `include` directive.
+    ///
+    /// Fragments may omit `[meta]` and `[base]`. A `[meta]` section in a
+    /// fragment is ignored — identity always comes from the including file.
+    pub fn from_fragment_toml(s: &str) -> Result<Self, PaletteError> {
+        let raw: RawManifest = toml::from_str(s)?;
+        Ok(Self {
+            meta: None,
+            ..raw.into_manifest()
+        })
+    }
+
     pub fn inherits_from(&self) -> Option<&str> {
         self.meta.as_ref().and_then(|m| m.inherits.as_deref())
     }
+
+    /// An empty manifest with only `[meta]` set, for importers to fill in.
+    pub(crate) fn imported(name: Arc<str>, id: &str, style: &str) -> Self {
+        Self {
+            meta: Some(ManifestMeta {
+                name,
+                preset_id: Arc::from(id),
+                schema_version: Arc::from("1"),
+                style: Arc::from(style),
+                kind: Arc::from("imported"),
+                inherits: None,
+                upstream_repo: None,
+            }),
+            include: Vec::new(),
+            base: ManifestSection::new(),
+            semantic: ManifestSection::new(),
+            diff: ManifestSection::new(),
+            surface: ManifestSection::new(),
+            typography: ManifestSection::new(),
+            syntax: ManifestSection::new(),
+            editor: ManifestSection::new(),
+            terminal: ManifestSection::new(),
+            styles: StyleSections::new(),
+            semantic_tokens: ManifestSection::new(),
+            custom: CustomSections::new(),
+            #[cfg(feature = "platform")]
+            platform: PlatformSections::new(),
+        }
+    }
+
+    /// A built-in color section by its manifest name (`terminal` for ANSI).
+    pub(crate) fn section_mut(&mut self, name: &str) -> Option<&mut ManifestSection> {
+        match name {
+            "base" => Some(&mut self.base),
+            "semantic" => Some(&mut self.semantic),
+            "diff" => Some(&mut self.diff),
+            "surface" => Some(&mut self.surface),
+            "typography" => Some(&mut self.typography),
+            "syntax" => Some(&mut self.syntax),
+            "editor" => Some(&mut self.editor),
+            "terminal" => Some(&mut self.terminal),
+            _ => None,
+        }
+    }
+}
+
+/// A slot value: plain hex, or an inline table with `fg` and style flags.
+enum RawSlot {
+    Hex(Arc<str>),
+    Styled { fg: Option<Arc<str>>, style: TextStyle },
+}
+
+const STYLED_KEYS: &[&str] = &["fg", "bold", "italic", "underline", "undercurl", "strikethrough"];
+
+impl<'de> Deserialize<'de> for RawSlot {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(RawSlotVisitor)
+    }
+}
+
+struct RawSlotVisitor;
+
+impl<'de> Visitor<'de> for RawSlotVisitor {
+    type Value = RawSlot;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a hex color or an inline table of `fg` and style flags")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<RawSlot, E> {
+        Ok(RawSlot::Hex(Arc::from(v)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<RawSlot, A::Error> {
+        let mut fg = None;
+        let mut style = TextStyle::default();
+        while let Some(key) = map.next_key::<String>()? {
+            let flag = match key.as_str() {
+                "fg" => {
+                    fg = Some(map.next_value()?);
+                    continue;
+                }
+                "bold" => &mut style.bold,
+                "italic" => &mut style.italic,
+                "underline" => &mut style.underline,
+                "undercurl" => &mut style.undercurl,
+                "strikethrough" => &mut style.strikethrough,
+                other => return Err(A::Error::unknown_field(other, STYLED_KEYS)),
+            };
+            *flag = map.next_value()?;
+        }
+        Ok(RawSlot::Styled { fg, style })
+    }
+}
+
+type RawSection = BTreeMap<Arc<str>, RawSlot>;
+
+/// Names a `[custom.*]` table may not take: their styles would land in the
+/// built-in section's entry of [`StyleSections`].
+const BUILTIN_SECTIONS: [&str; 8] = ["base", "semantic", "diff", "surface", "typography", "syntax", "editor", "terminal"];
+
+/// Whether `name` can go into a CSS custom property or Tailwind key as is.
+fn is_slot_name(name: &str) -> bool {
+    !name.is_empty() && name.bytes().all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-'))
+}
+
+/// Parse `[custom.*]`: tables of hex strings and inline style tables.
+///
+/// Other values (numbers, arrays, booleans, and non-table entries directly
+/// under `[custom]`) are skipped, so applications can keep settings beside
+/// their colors. Section and slot names are limited to `[a-z0-9_-]`, since
+/// they become CSS variable names.
+fn custom_sections<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BTreeMap<Arc<str>, RawSection>, D::Error> {
+    let tables = BTreeMap::<Arc<str>, toml::Value>::deserialize(deserializer)?;
+    let mut sections = BTreeMap::new();
+    for (name, value) in tables {
+        let toml::Value::Table(table) = value else {
+            continue;
+        };
+        if BUILTIN_SECTIONS.contains(&&*name) {
+            return Err(D::Error::custom(format!("[custom.{name}] shadows the built-in [{name}] section")));
+        }
+        if !is_slot_name(&name) {
+            return Err(D::Error::custom(format!("[custom] section name {name:?} must use only a-z, 0-9, `_` and `-`")));
+        }
+        let mut section = RawSection::new();
+        for (field, value) in table {
+            let slot = match value {
+                toml::Value::String(hex) => RawSlot::Hex(Arc::from(hex)),
+                value @ toml::Value::Table(_) => RawSlot::deserialize(value)
+                    .map_err(|e| D::Error::custom(format!("[custom.{name}] slot `{field}`: {e}")))?,
+                _ => continue,
+            };
+            if !is_slot_name(&field) {
+                return Err(D::Error::custom(format!(
+                    "[custom.{name}] slot name {field:?} must use only a-z, 0-9, `_` and `-`"
+                )));
+            }
+            section.insert(Arc::from(field), slot);
+        }
+        sections.insert(name, section);
+    }
+    Ok(sections)
+}
+
+fn split_section(
+    name: &str,
+    raw: RawSection,
+    styles: &mut StyleSections,
+) -> ManifestSection {
+    let mut colors = ManifestSection::new();
+    let mut section_styles = StyleSection::new();
+    for (field, slot) in raw {
+        match slot {
+            RawSlot::Hex(hex) => {
+                colors.insert(field, hex);
+            }
+            RawSlot::Styled { fg, style } => {
+                if let Some(hex) = fg {
+                    colors.insert(field.clone(), hex);
+                }
+                section_styles.insert(field, style);
+            }
+        }
+    }
+    if !section_styles.is_empty() {
+        styles.insert(Arc::from(name), section_styles);
+    }
+    colors
 }
 
 #[derive(Deserialize)]
@@ -68,22 +253,58 @@ struct RawManifest {
     #[serde(default)]
     meta: Option<ManifestMeta>,
     #[serde(default)]
-    base: Option<ManifestSection>,
+    include: Vec<Arc<str>>,
     #[serde(default)]
-    semantic: ManifestSection,
+    base: Option<RawSection>,
     #[serde(default)]
-    diff: ManifestSection,
+    semantic: RawSection,
     #[serde(default)]
-    surface: ManifestSection,
+    diff: RawSection,
     #[serde(default)]
-    typography: ManifestSection,
+    surface: RawSection,
     #[serde(default)]
-    syntax: ManifestSection,
+    typography: RawSection,
     #[serde(default)]
-    editor: ManifestSection,
+    syntax: RawSection,
     #[serde(default)]
-    terminal: ManifestSection,
+    editor: RawSection,
+    #[serde(default)]
+    terminal: RawSection,
+    #[serde(default)]
+    semantic_tokens: ManifestSection,
     #[cfg(feature = "platform")]
     #[serde(default)]
     platform: PlatformSections,
+    #[serde(default, deserialize_with = "custom_sections")]
+    custom: BTreeMap<Arc<str>, RawSection>,
+}
+
+impl RawManifest {
+    fn into_manifest(self) -> PaletteManifest {
+        let mut styles = StyleSections::new();
+        PaletteManifest {
+            meta: self.meta,
+            include: self.include,
+            base: split_section("base", self.base.unwrap_or_default(), &mut styles),
+            semantic: split_section("semantic", self.semantic, &mut styles),
+            diff: split_section("diff", self.diff, &mut styles),
+            surface: split_section("surface", self.surface, &mut styles),
+            typography: split_section("typography", self.typography, &mut styles),
+            syntax: split_section("syntax", self.syntax, &mut styles),
+            editor: split_section("editor", self.editor, &mut styles),
+            terminal: split_section("terminal", self.terminal, &mut styles),
+            semantic_tokens: self.semantic_tokens,
+            custom: self
+                .custom
+                .into_iter()
+                .map(|(name, raw)| {
+                    let section = split_section(&name, raw, &mut styles);
+                    (name, section)
+                })
+                .collect(),
+            styles,
+            #[cfg(feature = "platform")]
+            platform: self.platform,
+        }
+    }
 }
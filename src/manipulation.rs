@@ -99,6 +99,12 @@ impl Color {
     pub fn rotate_hue(self, degrees: f64) -> Self {
         adjust_hsl(self, degrees, |hsl, d| hsl.h = (hsl.h + d).rem_euclid(360.0))
     }
+
+    /// Neutral gray with the same perceived lightness (OKLab `L`).
+    pub fn grayscale(self) -> Self {
+        let lab = rgb_to_oklab(self);
+        oklab_to_rgb(Oklab { a: 0.0, b: 0.0, ..lab })
+    }
 }
 
 impl Color {
@@ -127,3 +133,106 @@ pub fn blend(fg: Color, bg: Color, alpha: f64) -> Color {
         false => bg,
     }
 }
+
+/// Color in the OKLab perceptual space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Oklab {
+    pub(crate) l: f64,
+    pub(crate) a: f64,
+    pub(crate) b: f64,
+}
+
+fn srgb_to_linear(channel: u8) -> f64 {
+    let s = f64::from(channel) / 255.0;
+    match s <= 0.04045 {
+        true => s / 12.92,
+        false => ((s + 0.055) / 1.055).powf(2.4),
+    }
+}
+
+fn linear_to_srgb(v: f64) -> u8 {
+    let s = match v <= 0.0031308 {
+        true => v * 12.92,
+        false => 1.055 * v.powf(1.0 / 2.4) - 0.055,
+    };
+    clamp_channel(s)
+}
+
+pub(crate) fn rgb_to_oklab(color: Color) -> Oklab {
+    let r = srgb_to_linear(color.r);
+    let g = srgb_to_linear(color.g);
+    let b = srgb_to_linear(color.b);
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    Oklab {
+        l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    }
+}
+
+pub(crate) fn oklab_to_rgb(lab: Oklab) -> Color {
+    let l = (lab.l + 0.3963377774 * lab.a + 0.2158037573 * lab.b).powi(3);
+    let m = (lab.l - 0.1055613458 * lab.a - 0.0638541728 * lab.b).powi(3);
+    let s = (lab.l - 0.0894841775 * lab.a - 1.2914855480 * lab.b).powi(3);
+
+    Color {
+        r: linear_to_srgb(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+        g: linear_to_srgb(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+        b: linear_to_srgb(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+    }
+}
+
+/// Perceptual distance between two colors.
+///
+/// Euclidean distance in OKLab, scaled by 100. Identical colors return `0.0`;
+/// differences below ~2 are hard to see side by side.
+pub fn delta_e(a: Color, b: Color) -> f64 {
+    let (x, y) = (rgb_to_oklab(a), rgb_to_oklab(b));
+    let (dl, da, db) = (x.l - y.l, x.a - y.a, x.b - y.b);
+    (dl * dl + da * da + db * db).sqrt() * 100.0
+}
+
+impl Color {
+    pub fn delta_e(self, other: Color) -> f64 {
+        delta_e(self, other)
+    }
+}
+
+/// Color space used when mixing two colors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BlendSpace {
+    /// Channel-wise mix of gamma-encoded sRGB, as [`blend`] does.
+    Srgb,
+    /// Perceptually uniform mix; avoids the muddy midpoints of sRGB.
+    #[default]
+    Oklab,
+}
+
+/// Mix from `from` (at `t = 0`) to `to` (at `t = 1`) in the given space.
+///
+/// `t` is clamped to `[0, 1]`. Non-finite `t` returns `from`.
+pub fn interpolate(from: Color, to: Color, t: f64, space: BlendSpace) -> Color {
+    match (t.is_finite(), space) {
+        (false, _) => from,
+        (true, BlendSpace::Srgb) => blend(to, from, t),
+        (true, BlendSpace::Oklab) => {
+            let t = t.clamp(0.0, 1.0);
+            let (a, b) = (rgb_to_oklab(from), rgb_to_oklab(to));
+            oklab_to_rgb(Oklab {
+                l: a.l + (b.l - a.l) * t,
+                a: a.a + (b.a - a.a) * t,
+                b: a.b + (b.b - a.b) * t,
+            })
+        }
+    }
+}
+
+impl Color {
+    pub fn interpolate(self, to: Color, t: f64, space: BlendSpace) -> Color {
+        interpolate(self, to, t, space)
+    }
+}
@@ -1,6 +1,12 @@
-use crate::manifest::{ManifestSection, PaletteManifest};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
-fn merge_sections(primary: &ManifestSection, fallback: &ManifestSection) -> ManifestSection {
+use crate::manifest::PaletteManifest;
+
+fn merge_sections<V: Clone>(
+    primary: &BTreeMap<Arc<str>, V>,
+    fallback: &BTreeMap<Arc<str>, V>,
+) -> BTreeMap<Arc<str>, V> {
     let mut merged = fallback.clone();
     for (key, value) in primary {
         merged.insert(key.clone(), value.clone());
@@ -8,14 +14,14 @@ fn merge_sections(primary: &ManifestSection, fallback: &ManifestSection) -> Mani
     merged
 }
 
-#[cfg(feature = "platform")]
-fn merge_platform_sections(
-    primary: &crate::manifest::PlatformSections,
-    fallback: &crate::manifest::PlatformSections,
-) -> crate::manifest::PlatformSections {
+/// Merge two-level maps (platform, style, and custom sections) key by key.
+fn merge_nested_sections<V: Clone>(
+    primary: &BTreeMap<Arc<str>, BTreeMap<Arc<str>, V>>,
+    fallback: &BTreeMap<Arc<str>, BTreeMap<Arc<str>, V>>,
+) -> BTreeMap<Arc<str>, BTreeMap<Arc<str>, V>> {
     let mut merged = primary.clone();
-    for (platform, section) in fallback {
-        let existing = merged.entry(platform.clone()).or_default();
+    for (name, section) in fallback {
+        let existing = merged.entry(name.clone()).or_default();
         *existing = merge_sections(existing, section);
     }
     merged
@@ -24,6 +30,7 @@ fn merge_platform_sections(
 pub fn merge_manifests(variant: &PaletteManifest, base: &PaletteManifest) -> PaletteManifest {
     PaletteManifest {
         meta: variant.meta.clone(),
+        include: variant.include.clone(),
         base: merge_sections(&variant.base, &base.base),
         semantic: merge_sections(&variant.semantic, &base.semantic),
         diff: merge_sections(&variant.diff, &base.diff),
@@ -32,7 +39,10 @@ pub fn merge_manifests(variant: &PaletteManifest, base: &PaletteManifest) -> Pal
         syntax: merge_sections(&variant.syntax, &base.syntax),
         editor: merge_sections(&variant.editor, &base.editor),
         terminal: merge_sections(&variant.terminal, &base.terminal),
+        styles: merge_nested_sections(&variant.styles, &base.styles),
+        semantic_tokens: merge_sections(&variant.semantic_tokens, &base.semantic_tokens),
+        custom: merge_nested_sections(&variant.custom, &base.custom),
         #[cfg(feature = "platform")]
-        platform: merge_platform_sections(&variant.platform, &base.platform),
+        platform: merge_nested_sections(&variant.platform, &base.platform),
     }
 }
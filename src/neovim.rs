@@ -0,0 +1,248 @@
+use std::fmt::Write;
+
+use crate::color::Color;
+use crate::contrast::SlotRef;
+use crate::export::comment_text;
+use crate::highlight::capture_names;
+use crate::palette::Palette;
+use crate::style::TextStyle;
+
+/// Built-in highlight groups with the slots tried for `fg` and `bg`, in order.
+///
+/// Like [`WORKBENCH_COLORS`](crate::vscode::WORKBENCH_COLORS), later slots are
+/// fallbacks for sparse palettes. A group whose slots are all unset is left
+/// out so Neovim keeps its default.
+pub const UI_GROUPS: &[(&str, &[SlotRef], &[SlotRef])] = &[
+    ("Normal", &[("base", "foreground")], &[("base", "background")]),
+    ("NormalNC", &[("base", "foreground")], &[("base", "background")]),
+    ("NormalFloat", &[("base", "foreground")], &[("surface", "float"), ("surface", "popup")]),
+    ("FloatBorder", &[("base", "border_highlight"), ("base", "border")], &[("surface", "float"), ("surface", "popup")]),
+    ("Pmenu", &[("base", "foreground")], &[("surface", "menu"), ("surface", "popup")]),
+    ("PmenuSel", &[("editor", "selection_fg")], &[("surface", "selection"), ("editor", "selection_bg")]),
+    ("PmenuSbar", &[], &[("surface", "menu"), ("surface", "popup")]),
+    ("PmenuThumb", &[], &[("base", "border_highlight"), ("base", "border")]),
+    ("StatusLine", &[("base", "foreground_dark"), ("base", "foreground")], &[("surface", "statusline"), ("base", "background_dark")]),
+    ("StatusLineNC", &[("typography", "comment")], &[("base", "background_dark"), ("surface", "statusline")]),
+    ("WinSeparator", &[("base", "border")], &[]),
+    ("CursorLine", &[], &[("surface", "highlight"), ("base", "background_highlight")]),
+    ("CursorColumn", &[], &[("surface", "highlight"), ("base", "background_highlight")]),
+    ("ColorColumn", &[], &[("base", "background_highlight"), ("surface", "highlight")]),
+    ("Cursor", &[("editor", "cursor_text")], &[("editor", "cursor")]),
+    ("Visual", &[("editor", "selection_fg")], &[("editor", "selection_bg"), ("surface", "selection")]),
+    ("Search", &[("editor", "search_fg")], &[("editor", "search_bg"), ("surface", "search")]),
+    ("IncSearch", &[("editor", "search_fg")], &[("editor", "search_bg"), ("surface", "search")]),
+    ("MatchParen", &[("editor", "match_paren")], &[]),
+    ("LineNr", &[("typography", "line_number"), ("typography", "gutter")], &[]),
+    ("CursorLineNr", &[("base", "foreground")], &[]),
+    ("SignColumn", &[], &[("base", "background")]),
+    ("NonText", &[("typography", "gutter")], &[]),
+    ("Whitespace", &[("typography", "gutter")], &[]),
+    ("Comment", &[("syntax", "comments"), ("typography", "comment")], &[]),
+    ("Title", &[("typography", "title")], &[]),
+    ("Underlined", &[("typography", "link")], &[]),
+    ("ErrorMsg", &[("semantic", "error")], &[]),
+    ("WarningMsg", &[("semantic", "warning")], &[]),
+    ("MoreMsg", &[("semantic", "info")], &[]),
+    ("Question", &[("semantic", "info")], &[]),
+    ("LspInlayHint", &[("editor", "inlay_hint_fg"), ("typography", "comment")], &[("editor", "inlay_hint_bg")]),
+    // Diff
+    ("DiffAdd", &[("diff", "added_fg")], &[("diff", "added_bg")]),
+    ("DiffChange", &[("diff", "modified_fg")], &[("diff", "modified_bg")]),
+    ("DiffDelete", &[("diff", "removed_fg")], &[("diff", "removed_bg")]),
+    ("DiffText", &[], &[("diff", "text_bg")]),
+    ("Added", &[("diff", "added")], &[]),
+    ("Changed", &[("diff", "modified")], &[]),
+    ("Removed", &[("diff", "removed")], &[]),
+    // Diagnostics
+    ("DiagnosticError", &[("editor", "diagnostic_error"), ("semantic", "error")], &[]),
+    ("DiagnosticWarn", &[("editor", "diagnostic_warn"), ("semantic", "warning")], &[]),
+    ("DiagnosticInfo", &[("editor", "diagnostic_info"), ("semantic", "info")], &[]),
+    ("DiagnosticHint", &[("editor", "diagnostic_hint"), ("semantic", "hint")], &[]),
+    ("DiagnosticOk", &[("semantic", "success")], &[]),
+];
+
+/// `DiagnosticUnderline*` groups: undercurled, colored through `sp`.
+pub const UNDERLINE_GROUPS: [(&str, &[SlotRef]); 4] = [
+    (
+        "DiagnosticUnderlineError",
+        &[("editor", "diagnostic_underline_error"), ("editor", "diagnostic_error"), ("semantic", "error")],
+    ),
+    (
+        "DiagnosticUnderlineWarn",
+        &[("editor", "diagnostic_underline_warn"), ("editor", "diagnostic_warn"), ("semantic", "warning")],
+    ),
+    (
+        "DiagnosticUnderlineInfo",
+        &[("editor", "diagnostic_underline_info"), ("editor", "diagnostic_info"), ("semantic", "info")],
+    ),
+    (
+        "DiagnosticUnderlineHint",
+        &[("editor", "diagnostic_underline_hint"), ("editor", "diagnostic_hint"), ("semantic", "hint")],
+    ),
+];
+
+/// Legacy Vim syntax groups, linked to the tree-sitter capture that covers
+/// them so filetypes without a parser still pick up the palette.
+const LEGACY_LINKS: [(&str, &str); 16] = [
+    ("Constant", "@constant"),
+    ("String", "@string"),
+    ("Character", "@string"),
+    ("Number", "@number"),
+    ("Boolean", "@boolean"),
+    ("Float", "@number.float"),
+    ("Identifier", "@variable"),
+    ("Function", "@function"),
+    ("Statement", "@keyword"),
+    ("Keyword", "@keyword"),
+    ("Operator", "@operator"),
+    ("PreProc", "@attribute"),
+    ("Type", "@type"),
+    ("Special", "@string.escape"),
+    ("Delimiter", "@punctuation.delimiter"),
+    ("Tag", "@tag"),
+];
+
+/// A Lua string literal. Control characters use decimal escapes, which
+/// LuaJIT understands.
+fn lua_quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_ascii_control() => {
+                // String::write_fmt is infallible
+                let _ = write!(out, "\\{}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// The `nvim_set_hl` attribute table, or `None` when it would be empty.
+fn attributes(fg: Option<Color>, bg: Option<Color>, sp: Option<Color>, style: Option<TextStyle>) -> Option<String> {
+    let colors = [("fg", fg), ("bg", bg), ("sp", sp)];
+    let mut fields: Vec<String> = colors
+        .iter()
+        .filter_map(|(key, color)| color.map(|c| format!("{key} = \"{c}\"")))
+        .collect();
+    if fields.is_empty() {
+        return None;
+    }
+    if let Some(style) = style {
+        let flags = [
+            ("bold", style.bold),
+            ("italic", style.italic),
+            ("underline", style.underline),
+            ("undercurl", style.undercurl),
+            ("strikethrough", style.strikethrough),
+        ];
+        fields.extend(flags.iter().filter(|(_, set)| *set).map(|(name, _)| format!("{name} = true")));
+    }
+    Some(format!("{{ {} }}", fields.join(", ")))
+}
+
+fn set_hl(out: &mut String, group: &str, attrs: Option<String>) {
+    if let Some(attrs) = attrs {
+        let _ = writeln!(out, "hl(0, {}, {attrs})", lua_quote(group));
+    }
+}
+
+/// Neovim highlight group for an LSP semantic token selector: `@lsp.type.*`
+/// for a bare type, `@lsp.typemod.*` for one modifier, `@lsp.mod.*` for
+/// `*.modifier`. Neovim has no group for several modifiers at once.
+fn lsp_group(selector: &str) -> Option<String> {
+    match selector.split('.').collect::<Vec<_>>().as_slice() {
+        ["*"] => None,
+        ["*", modifier] => Some(format!("@lsp.mod.{modifier}")),
+        [token_type] => Some(format!("@lsp.type.{token_type}")),
+        [token_type, modifier] => Some(format!("@lsp.typemod.{token_type}.{modifier}")),
+        _ => None,
+    }
+}
+
+/// A Lua colorscheme for `colors/<name>.lua`.
+///
+/// Sets the [`UI_GROUPS`] and [`UNDERLINE_GROUPS`], one `@capture` group per
+/// [`capture_names`] entry, `@lsp.*` groups from
+/// [`Palette::semantic_token_rules`], links for the legacy Vim syntax groups,
+/// and `vim.g.terminal_color_0` … `terminal_color_15`.
+pub fn to_neovim_lua(palette: &Palette) -> String {
+    let meta = palette.meta.as_ref();
+    let name = meta.map_or("palette", |m| &m.name);
+    let id = meta.map_or("palette", |m| &m.preset_id);
+    let background = match palette.base.background.is_some_and(|bg| bg.is_light()) {
+        true => "light",
+        false => "dark",
+    };
+
+    let mut out = format!("-- {}\n\n", comment_text(name));
+    out.push_str("vim.cmd(\"highlight clear\")\n");
+    out.push_str("if vim.fn.exists(\"syntax_on\") == 1 then\n  vim.cmd(\"syntax reset\")\nend\n");
+    let _ = writeln!(out, "vim.o.background = \"{background}\"");
+    let _ = writeln!(out, "vim.g.colors_name = {}\n", lua_quote(id));
+    out.push_str("local hl = vim.api.nvim_set_hl\n\n");
+
+    for (group, fg, bg) in UI_GROUPS {
+        let fg = palette.pick_slot(fg);
+        let bg = palette.pick_slot(bg).map(|(c, _)| c);
+        set_hl(&mut out, group, attributes(fg.map(|(c, _)| c), bg, None, fg.and_then(|(_, s)| s)));
+    }
+    for (group, slots) in UNDERLINE_GROUPS {
+        let sp = palette.pick_slot(slots).map(|(c, _)| c);
+        let undercurl = TextStyle {
+            undercurl: true,
+            ..TextStyle::default()
+        };
+        set_hl(&mut out, group, attributes(None, None, sp, Some(undercurl)));
+    }
+
+    out.push('\n');
+    let mut captures = Vec::new();
+    for capture in capture_names() {
+        let attrs = attributes(palette.capture_color(capture), None, None, palette.capture_style(capture));
+        if attrs.is_some() {
+            captures.push(capture);
+        }
+        set_hl(&mut out, &format!("@{capture}"), attrs);
+    }
+
+    out.push('\n');
+    for (selector, slot) in palette.semantic_token_rules() {
+        let Some(group) = lsp_group(selector) else {
+            continue;
+        };
+        let color = palette.syntax.get(slot).copied();
+        set_hl(&mut out, &group, attributes(color, None, None, palette.styles.syntax.get(slot).copied()));
+    }
+
+    out.push('\n');
+    for (group, target) in LEGACY_LINKS {
+        if captures.contains(&&target[1..]) {
+            let _ = writeln!(out, "hl(0, {}, {{ link = {} }})", lua_quote(group), lua_quote(target));
+        }
+    }
+
+    let ansi: Vec<(usize, Color)> = palette
+        .terminal_ansi
+        .indexed()
+        .filter_map(|(index, _, color)| Some((index, *color?)))
+        .collect();
+    if !ansi.is_empty() {
+        out.push('\n');
+    }
+    for (index, color) in ansi {
+        let _ = writeln!(out, "vim.g.terminal_color_{index} = \"{color}\"");
+    }
+    out
+}
+
+impl Palette {
+    /// See [`to_neovim_lua`].
+    pub fn to_neovim_lua(&self) -> String {
+        to_neovim_lua(self)
+    }
+}
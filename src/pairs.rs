@@ -0,0 +1,191 @@
+use crate::color::Color;
+use crate::contrast::SlotRef;
+use crate::palette::Palette;
+use crate::style::TextStyle;
+
+/// A foreground/background slot pair and where each half falls back to.
+///
+/// Fallbacks are tried in order when the paired slot is unset, ending with
+/// `base.foreground` / `base.background`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairSpec {
+    pub name: &'static str,
+    pub fg: SlotRef,
+    pub bg: SlotRef,
+    pub fg_fallbacks: &'static [SlotRef],
+    pub bg_fallbacks: &'static [SlotRef],
+}
+
+const FOREGROUND: SlotRef = ("base", "foreground");
+const BACKGROUND: SlotRef = ("base", "background");
+
+const SELECTION: PairSpec = PairSpec {
+    name: "selection",
+    fg: ("editor", "selection_fg"),
+    bg: ("editor", "selection_bg"),
+    fg_fallbacks: &[("typography", "selection_text"), FOREGROUND],
+    bg_fallbacks: &[("surface", "selection"), BACKGROUND],
+};
+
+const INLAY_HINT: PairSpec = PairSpec {
+    name: "inlay_hint",
+    fg: ("editor", "inlay_hint_fg"),
+    bg: ("editor", "inlay_hint_bg"),
+    fg_fallbacks: &[("typography", "comment"), FOREGROUND],
+    bg_fallbacks: &[BACKGROUND],
+};
+
+const SEARCH: PairSpec = PairSpec {
+    name: "search",
+    fg: ("editor", "search_fg"),
+    bg: ("editor", "search_bg"),
+    fg_fallbacks: &[FOREGROUND],
+    bg_fallbacks: &[("surface", "search"), BACKGROUND],
+};
+
+const CURSOR: PairSpec = PairSpec {
+    name: "cursor",
+    fg: ("editor", "cursor_text"),
+    bg: ("editor", "cursor"),
+    fg_fallbacks: &[BACKGROUND],
+    bg_fallbacks: &[FOREGROUND],
+};
+
+const DIFF_ADDED: PairSpec = PairSpec {
+    name: "diff_added",
+    fg: ("diff", "added_fg"),
+    bg: ("diff", "added_bg"),
+    fg_fallbacks: &[("diff", "added"), FOREGROUND],
+    bg_fallbacks: &[BACKGROUND],
+};
+
+const DIFF_MODIFIED: PairSpec = PairSpec {
+    name: "diff_modified",
+    fg: ("diff", "modified_fg"),
+    bg: ("diff", "modified_bg"),
+    fg_fallbacks: &[("diff", "modified"), FOREGROUND],
+    bg_fallbacks: &[BACKGROUND],
+};
+
+const DIFF_REMOVED: PairSpec = PairSpec {
+    name: "diff_removed",
+    fg: ("diff", "removed_fg"),
+    bg: ("diff", "removed_bg"),
+    fg_fallbacks: &[("diff", "removed"), FOREGROUND],
+    bg_fallbacks: &[BACKGROUND],
+};
+
+/// Every fg/bg pair in the palette. [`contrast_pairs`](crate::contrast::contrast_pairs)
+/// checks each entry as resolved, fallbacks included.
+pub const STYLE_PAIRS: [PairSpec; 7] = [
+    SELECTION,
+    INLAY_HINT,
+    SEARCH,
+    CURSOR,
+    DIFF_ADDED,
+    DIFF_MODIFIED,
+    DIFF_REMOVED,
+];
+
+/// Resolved foreground and background for one [`PairSpec`].
+///
+/// `fg` and `bg` are `None` only when neither the declared slot nor any
+/// fallback is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StylePair {
+    pub name: &'static str,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    /// Text style of the declared foreground slot.
+    pub style: Option<TextStyle>,
+    /// Whether `fg` came from a fallback slot.
+    pub fg_fallback: bool,
+    /// Whether `bg` came from a fallback slot.
+    pub bg_fallback: bool,
+}
+
+impl StylePair {
+    /// Both halves, if both resolved.
+    pub fn colors(&self) -> Option<(Color, Color)> {
+        self.fg.zip(self.bg)
+    }
+}
+
+/// The declared slot if set, else the first set fallback.
+fn resolve_slot(palette: &Palette, slot: SlotRef, fallbacks: &[SlotRef]) -> Option<SlotRef> {
+    std::iter::once(&slot)
+        .chain(fallbacks)
+        .find(|(section, field)| palette.slot(section, field).is_some())
+        .copied()
+}
+
+fn resolve(palette: &Palette, slot: SlotRef, fallbacks: &[SlotRef]) -> (Option<Color>, bool) {
+    let found = resolve_slot(palette, slot, fallbacks);
+    let color = found.and_then(|(section, field)| palette.slot(section, field));
+    (color.copied(), found.is_some_and(|found| found != slot))
+}
+
+impl PairSpec {
+    /// The slots [`resolve`](Self::resolve) takes `fg` and `bg` from.
+    pub fn resolve_slots(&self, palette: &Palette) -> (Option<SlotRef>, Option<SlotRef>) {
+        (
+            resolve_slot(palette, self.fg, self.fg_fallbacks),
+            resolve_slot(palette, self.bg, self.bg_fallbacks),
+        )
+    }
+
+    pub fn resolve(&self, palette: &Palette) -> StylePair {
+        let (fg, fg_fallback) = resolve(palette, self.fg, self.fg_fallbacks);
+        let (bg, bg_fallback) = resolve(palette, self.bg, self.bg_fallbacks);
+        StylePair {
+            name: self.name,
+            fg,
+            bg,
+            style: palette.styles.get(self.fg.0, self.fg.1).copied(),
+            fg_fallback,
+            bg_fallback,
+        }
+    }
+}
+
+macro_rules! pair_accessors {
+    ($($(#[$meta:meta])* $method:ident => $spec:ident),+ $(,)?) => {
+        impl Palette {
+            $(
+                $(#[$meta])*
+                pub fn $method(&self) -> StylePair {
+                    $spec.resolve(self)
+                }
+            )+
+        }
+    };
+}
+
+pair_accessors! {
+    /// `editor.selection_fg` on `editor.selection_bg`.
+    selection_pair => SELECTION,
+    /// `editor.inlay_hint_fg` on `editor.inlay_hint_bg`.
+    inlay_hint_pair => INLAY_HINT,
+    /// `editor.search_fg` on `editor.search_bg`.
+    search_pair => SEARCH,
+    /// `editor.cursor_text` on `editor.cursor`.
+    cursor_pair => CURSOR,
+    /// `diff.added_fg` on `diff.added_bg`.
+    diff_added_pair => DIFF_ADDED,
+    /// `diff.modified_fg` on `diff.modified_bg`.
+    diff_modified_pair => DIFF_MODIFIED,
+    /// `diff.removed_fg` on `diff.removed_bg`.
+    diff_removed_pair => DIFF_REMOVED,
+}
+
+impl Palette {
+    /// Every [`STYLE_PAIRS`] entry, resolved against this palette.
+    pub fn pairs(&self) -> Vec<StylePair> {
+        STYLE_PAIRS.iter().map(|spec| spec.resolve(self)).collect()
+    }
+
+    /// Look up a resolved pair by name, e.g. `"search"` or `"diff_added"`.
+    pub fn pair(&self, name: &str) -> Option<StylePair> {
+        STYLE_PAIRS.iter().find(|spec| spec.name == name).map(|spec| spec.resolve(self))
+    }
+}
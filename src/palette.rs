@@ -1,8 +1,11 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::color::{Color, InvalidHex};
+use crate::contrast::SlotRef;
 use crate::error::PaletteError;
-use crate::manifest::{ManifestSection, PaletteManifest};
+use crate::manifest::{CustomSections, ManifestSection, PaletteManifest, StyleSection, StyleSections};
+use crate::style::TextStyle;
 
 fn resolve_color(
     section: &ManifestSection,
@@ -22,7 +25,41 @@ fn resolve_color(
 }
 
 macro_rules! color_group {
-    ($(#[$meta:meta])* $name:ident { $($field:ident),+ $(,)? }) => {
+    ($(#[$meta:meta])* $name:ident, $style_name:ident { $($field:ident),+ $(,)? }) => {
+        /// Optional text styles for the slots of the matching color group.
+        #[derive(Debug, Clone, Default, PartialEq, Eq)]
+        #[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+        pub struct $style_name {
+            $(
+                #[cfg_attr(feature = "snapshot", serde(skip_serializing_if = "Option::is_none"))]
+                pub $field: Option<TextStyle>,
+            )+
+        }
+
+        impl $style_name {
+            fn from_section(section: Option<&StyleSection>) -> Self {
+                Self {
+                    $($field: section.and_then(|s| s.get(stringify!($field))).copied(),)+
+                }
+            }
+
+            pub fn populated_slots(&self) -> impl Iterator<Item = (&'static str, &TextStyle)> {
+                [$(
+                    (stringify!($field), self.$field.as_ref()),
+                )+]
+                .into_iter()
+                .filter_map(|(name, style)| style.map(|s| (name, s)))
+            }
+
+            /// Look up a slot's style by field name. Unknown names return `None`.
+            pub fn get(&self, field: &str) -> Option<&TextStyle> {
+                match field {
+                    $(stringify!($field) => self.$field.as_ref(),)+
+                    _ => None,
+                }
+            }
+        }
+
         $(#[$meta])*
         #[derive(Debug, Clone, Default, PartialEq, Eq)]
         #[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
@@ -47,18 +84,64 @@ macro_rules! color_group {
                 .into_iter()
                 .filter_map(|(name, color)| color.map(|c| (name, c)))
             }
+
+            /// Look up a slot by field name. Unknown names return `None`.
+            pub fn get(&self, field: &str) -> Option<&Color> {
+                match field {
+                    $(stringify!($field) => self.$field.as_ref(),)+
+                    _ => None,
+                }
+            }
+
+            pub(crate) fn get_mut(&mut self, field: &str) -> Option<&mut Option<Color>> {
+                match field {
+                    $(stringify!($field) => Some(&mut self.$field),)+
+                    _ => None,
+                }
+            }
+
+            /// Combine two groups slot by slot.
+            pub(crate) fn zip_with(
+                &self,
+                other: &Self,
+                mut f: impl FnMut(Option<Color>, Option<Color>) -> Option<Color>,
+            ) -> Self {
+                Self {
+                    $($field: f(self.$field, other.$field),)+
+                }
+            }
+
+            /// Apply `f(section, field, color)` to every populated slot.
+            pub(crate) fn map_slots(
+                &self,
+                section: &str,
+                f: &mut impl FnMut(&str, &str, Color) -> Color,
+            ) -> Self {
+                Self {
+                    $($field: self.$field.map(|c| f(section, stringify!($field), c)),)+
+                }
+            }
+
+            /// Every slot in declaration order, including unset ones.
+            pub fn slots(&self) -> impl Iterator<Item = (&'static str, Option<&Color>)> {
+                [$(
+                    (stringify!($field), self.$field.as_ref()),
+                )+]
+                .into_iter()
+            }
         }
     };
 }
 
 /// Single source of truth for color group field lists.
 ///
-/// Invokes `$macro_name!` once per group, passing the struct name and its
-/// fields. Both `color_group!` and `terminal_group!` consume this so
-/// additions stay in sync at compile time.
+/// Invokes `$macro_name!` once per group, passing the color struct name, the
+/// matching style struct name, and the fields. `color_group!`, `terminal_group!`,
+/// `crossterm_group!`, and `anstyle_group!` all consume this so additions stay
+/// in sync at compile time.
 macro_rules! color_fields {
     ($macro_name:ident) => {
-        $macro_name!(BaseColors {
+        $macro_name!(BaseColors, BaseStyles {
             background,
             background_dark,
             background_highlight,
@@ -68,7 +151,7 @@ macro_rules! color_fields {
             border_highlight,
         });
 
-        $macro_name!(SemanticColors {
+        $macro_name!(SemanticColors, SemanticStyles {
             success,
             warning,
             error,
@@ -76,7 +159,7 @@ macro_rules! color_fields {
             hint,
         });
 
-        $macro_name!(DiffColors {
+        $macro_name!(DiffColors, DiffStyles {
             added,
             added_bg,
             added_fg,
@@ -90,7 +173,7 @@ macro_rules! color_fields {
             ignored,
         });
 
-        $macro_name!(SurfaceColors {
+        $macro_name!(SurfaceColors, SurfaceStyles {
             menu,
             sidebar,
             statusline,
@@ -103,7 +186,7 @@ macro_rules! color_fields {
             search,
         });
 
-        $macro_name!(TypographyColors {
+        $macro_name!(TypographyColors, TypographyStyles {
             comment,
             gutter,
             line_number,
@@ -112,7 +195,7 @@ macro_rules! color_fields {
             title,
         });
 
-        $macro_name!(SyntaxColors {
+        $macro_name!(SyntaxColors, SyntaxStyles {
             keywords,
             keywords_fn,
             functions,
@@ -141,7 +224,7 @@ macro_rules! color_fields {
             comments,
         });
 
-        $macro_name!(EditorColors {
+        $macro_name!(EditorColors, EditorStyles {
             cursor,
             cursor_text,
             match_paren,
@@ -161,7 +244,7 @@ macro_rules! color_fields {
             diagnostic_underline_hint,
         });
 
-        $macro_name!(TerminalAnsiColors {
+        $macro_name!(TerminalAnsiColors, TerminalAnsiStyles {
             black,
             red,
             green,
@@ -183,9 +266,17 @@ macro_rules! color_fields {
 }
 
 color_fields!(color_group);
-#[cfg(feature = "terminal")]
+#[cfg(any(feature = "terminal", feature = "crossterm", feature = "anstyle"))]
 pub(crate) use color_fields;
 
+impl TerminalAnsiColors {
+    /// Every slot with its ANSI index, black = 0 … bright_white = 15. The
+    /// index is the field's position in `color_fields!`.
+    pub fn indexed(&self) -> impl Iterator<Item = (usize, &'static str, Option<&Color>)> {
+        self.slots().enumerate().map(|(index, (field, color))| (index, field, color))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
 pub struct PaletteMeta {
@@ -194,6 +285,83 @@ pub struct PaletteMeta {
     pub style: Arc<str>,
 }
 
+/// Text styles for every group, parallel to the color groups on [`Palette`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub struct PaletteStyles {
+    pub base: BaseStyles,
+    pub semantic: SemanticStyles,
+    pub diff: DiffStyles,
+    pub surface: SurfaceStyles,
+    pub typography: TypographyStyles,
+    pub syntax: SyntaxStyles,
+    pub editor: EditorStyles,
+    pub terminal_ansi: TerminalAnsiStyles,
+    /// Styles for slots in custom sections, keyed by section then field.
+    #[cfg_attr(feature = "snapshot", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    pub custom: StyleSections,
+}
+
+impl PaletteStyles {
+    fn from_sections(sections: &StyleSections, custom: &CustomSections) -> Self {
+        Self {
+            base: BaseStyles::from_section(sections.get("base")),
+            semantic: SemanticStyles::from_section(sections.get("semantic")),
+            diff: DiffStyles::from_section(sections.get("diff")),
+            surface: SurfaceStyles::from_section(sections.get("surface")),
+            typography: TypographyStyles::from_section(sections.get("typography")),
+            syntax: SyntaxStyles::from_section(sections.get("syntax")),
+            editor: EditorStyles::from_section(sections.get("editor")),
+            terminal_ansi: TerminalAnsiStyles::from_section(sections.get("terminal")),
+            custom: sections
+                .iter()
+                .filter(|(name, _)| custom.contains_key(*name))
+                .map(|(name, section)| (name.clone(), section.clone()))
+                .collect(),
+        }
+    }
+
+    /// Look up a slot's style by manifest section and field name.
+    pub fn get(&self, section: &str, field: &str) -> Option<&TextStyle> {
+        match section {
+            "base" => self.base.get(field),
+            "semantic" => self.semantic.get(field),
+            "diff" => self.diff.get(field),
+            "surface" => self.surface.get(field),
+            "typography" => self.typography.get(field),
+            "syntax" => self.syntax.get(field),
+            "editor" => self.editor.get(field),
+            "terminal" => self.terminal_ansi.get(field),
+            _ => self.custom.get(section).and_then(|s| s.get(field)),
+        }
+    }
+}
+
+/// Colors from custom manifest sections, keyed by section then field.
+pub type CustomColors = BTreeMap<Arc<str>, BTreeMap<Arc<str>, Color>>;
+
+fn custom_from_sections(sections: &CustomSections) -> Result<CustomColors, PaletteError> {
+    sections
+        .iter()
+        .map(|(name, section)| {
+            let colors = section
+                .iter()
+                .map(|(field, hex)| {
+                    let color = Color::from_hex(hex).map_err(|InvalidHex { value }| {
+                        PaletteError::InvalidHex {
+                            section: name.clone(),
+                            field: field.clone(),
+                            value,
+                        }
+                    })?;
+                    Ok((field.clone(), color))
+                })
+                .collect::<Result<_, PaletteError>>()?;
+            Ok((name.clone(), colors))
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
 pub struct Palette {
@@ -206,6 +374,13 @@ pub struct Palette {
     pub syntax: SyntaxColors,
     pub editor: EditorColors,
     pub terminal_ansi: TerminalAnsiColors,
+    pub styles: PaletteStyles,
+    /// Manifest `[semantic_tokens]` rules; see [`Palette::semantic_token_slot`].
+    #[cfg_attr(feature = "snapshot", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    pub semantic_tokens: crate::semantic_tokens::SemanticTokenOverrides,
+    /// Application-defined sections; see [`Palette::custom`].
+    #[cfg_attr(feature = "snapshot", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    pub custom: CustomColors,
     #[cfg(feature = "platform")]
     pub platform: crate::platform::PlatformOverrides,
 }
@@ -249,6 +424,9 @@ impl Default for Palette {
             syntax: SyntaxColors::default(),
             editor: EditorColors::default(),
             terminal_ansi: TerminalAnsiColors::default(),
+            styles: PaletteStyles::default(),
+            semantic_tokens: BTreeMap::new(),
+            custom: CustomColors::new(),
             #[cfg(feature = "platform")]
             platform: crate::platform::PlatformOverrides::default(),
         }
@@ -256,6 +434,52 @@ impl Default for Palette {
 }
 
 impl Palette {
+    /// Look up a slot by manifest section and field name, e.g.
+    /// `("syntax", "keywords")` or `("terminal", "red")`.
+    ///
+    /// Sections outside the built-in groups are looked up in [`custom`](Self::custom).
+    pub fn slot(&self, section: &str, field: &str) -> Option<&Color> {
+        match section {
+            "base" => self.base.get(field),
+            "semantic" => self.semantic.get(field),
+            "diff" => self.diff.get(field),
+            "surface" => self.surface.get(field),
+            "typography" => self.typography.get(field),
+            "syntax" => self.syntax.get(field),
+            "editor" => self.editor.get(field),
+            "terminal" => self.terminal_ansi.get(field),
+            _ => self.custom(section, field),
+        }
+    }
+
+    /// The first set slot in `slots`, with its style. Exporter tables list a
+    /// primary slot followed by fallbacks for sparse palettes.
+    pub(crate) fn pick_slot(&self, slots: &[SlotRef]) -> Option<(Color, Option<TextStyle>)> {
+        slots.iter().find_map(|&(section, field)| {
+            let color = self.slot(section, field)?;
+            Some((*color, self.styles.get(section, field).copied()))
+        })
+    }
+
+    /// Look up a slot in a custom section, e.g. `("chart", "series_1")`.
+    pub fn custom(&self, section: &str, field: &str) -> Option<&Color> {
+        self.custom.get(section).and_then(|s| s.get(field))
+    }
+
+    pub(crate) fn slot_mut(&mut self, section: &str, field: &str) -> Option<&mut Option<Color>> {
+        match section {
+            "base" => self.base.get_mut(field),
+            "semantic" => self.semantic.get_mut(field),
+            "diff" => self.diff.get_mut(field),
+            "surface" => self.surface.get_mut(field),
+            "typography" => self.typography.get_mut(field),
+            "syntax" => self.syntax.get_mut(field),
+            "editor" => self.editor.get_mut(field),
+            "terminal" => self.terminal_ansi.get_mut(field),
+            _ => None,
+        }
+    }
+
     pub fn from_manifest(manifest: &PaletteManifest) -> Result<Self, PaletteError> {
         let meta = manifest.meta.as_ref().map(|m| PaletteMeta {
             name: Arc::clone(&m.name),
@@ -273,6 +497,9 @@ impl Palette {
             syntax: SyntaxColors::from_section(&manifest.syntax, "syntax")?,
             editor: EditorColors::from_section(&manifest.editor, "editor")?,
             terminal_ansi: TerminalAnsiColors::from_section(&manifest.terminal, "terminal")?,
+            styles: PaletteStyles::from_sections(&manifest.styles, &manifest.custom),
+            semantic_tokens: crate::semantic_tokens::from_section(&manifest.semantic_tokens)?,
+            custom: custom_from_sections(&manifest.custom)?,
             #[cfg(feature = "platform")]
             platform: crate::platform::from_sections(&manifest.platform)?,
         })
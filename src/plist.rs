@@ -0,0 +1,153 @@
+//! Minimal XML property list support for `.tmTheme` and `.itermcolors` files.
+
+use crate::json::{MAX_DEPTH, Value};
+
+/// Parse an XML plist into the same [`Value`] tree JSON themes use.
+///
+/// `<dict>` becomes an object, `<array>` an array, `<real>`/`<integer>` a
+/// number; `<string>`, `<date>`, and `<data>` stay strings.
+pub(crate) fn parse(s: &str) -> Result<Value, String> {
+    let mut parser = Parser { s, pos: 0, depth: 0 };
+    match parser.next_tag()? {
+        Some(Tag::Open("plist")) => {}
+        _ => return Err("missing <plist>".to_owned()),
+    }
+    parser.value()
+}
+
+#[derive(Debug, PartialEq)]
+enum Tag<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    Empty(&'a str),
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+/// Escape text for a `<string>` element.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+impl<'a> Parser<'a> {
+    /// Next element tag, skipping text, the XML declaration, doctype, and comments.
+    fn next_tag(&mut self) -> Result<Option<Tag<'a>>, String> {
+        loop {
+            let Some(start) = self.s[self.pos..].find('<') else {
+                return Ok(None);
+            };
+            let rest = &self.s[self.pos + start..];
+            let (skip_to, tag) = match rest {
+                r if r.starts_with("<!--") => (r.find("-->").map(|i| i + 3), None),
+                r if r.starts_with("<?") || r.starts_with("<!") => {
+                    (r.find('>').map(|i| i + 1), None)
+                }
+                r => {
+                    let end = r.find('>').ok_or("unterminated tag")?;
+                    let inner = &r[1..end];
+                    let tag = match (inner.strip_prefix('/'), inner.strip_suffix('/')) {
+                        (Some(name), _) => Tag::Close(name.trim()),
+                        (_, Some(name)) => Tag::Empty(name.trim()),
+                        _ => Tag::Open(inner.split_whitespace().next().unwrap_or_default()),
+                    };
+                    (Some(end + 1), Some(tag))
+                }
+            };
+            self.pos += start + skip_to.ok_or("unterminated markup")?;
+            if tag.is_some() {
+                return Ok(tag);
+            }
+        }
+    }
+
+    /// Text up to the closing `</name>`.
+    fn text(&mut self, name: &str) -> Result<String, String> {
+        let close = format!("</{name}>");
+        let end = self.s[self.pos..]
+            .find(&close)
+            .ok_or_else(|| format!("missing {close}"))?;
+        let text = unescape(&self.s[self.pos..self.pos + end]);
+        self.pos += end + close.len();
+        Ok(text)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.next_tag()? {
+            Some(tag) => self.value_from(tag),
+            None => Err("expected a value".to_owned()),
+        }
+    }
+
+    /// Count one more level of `<dict>`/`<array>` nesting, up to [`MAX_DEPTH`].
+    fn enter(&mut self) -> Result<(), String> {
+        match self.depth == MAX_DEPTH {
+            true => Err("nesting too deep".to_owned()),
+            false => {
+                self.depth += 1;
+                Ok(())
+            }
+        }
+    }
+
+    fn value_from(&mut self, tag: Tag<'a>) -> Result<Value, String> {
+        match tag {
+            Tag::Empty("true") => Ok(Value::Bool(true)),
+            Tag::Empty("false") => Ok(Value::Bool(false)),
+            Tag::Empty("dict") => Ok(Value::Object(Vec::new())),
+            Tag::Empty("array") => Ok(Value::Array(Vec::new())),
+            Tag::Empty("string") => Ok(Value::String(String::new())),
+            Tag::Open("dict") => {
+                self.enter()?;
+                let mut entries = Vec::new();
+                loop {
+                    match self.next_tag()? {
+                        Some(Tag::Close("dict")) => {
+                            self.depth -= 1;
+                            return Ok(Value::Object(entries));
+                        }
+                        Some(Tag::Open("key")) => {
+                            let key = self.text("key")?;
+                            entries.push((key, self.value()?));
+                        }
+                        other => return Err(format!("unexpected {other:?} in <dict>")),
+                    }
+                }
+            }
+            Tag::Open("array") => {
+                self.enter()?;
+                let mut items = Vec::new();
+                loop {
+                    match self.next_tag()? {
+                        Some(Tag::Close("array")) => {
+                            self.depth -= 1;
+                            return Ok(Value::Array(items));
+                        }
+                        Some(tag) => items.push(self.value_from(tag)?),
+                        None => return Err("missing </array>".to_owned()),
+                    }
+                }
+            }
+            Tag::Open(name @ ("real" | "integer")) => {
+                let text = self.text(name)?;
+                text.trim()
+                    .parse()
+                    .map(Value::Number)
+                    .map_err(|_| format!("invalid <{name}> `{text}`"))
+            }
+            Tag::Open(name @ ("string" | "date" | "data")) => self.text(name).map(Value::String),
+            other => Err(format!("unexpected {other:?}")),
+        }
+    }
+}
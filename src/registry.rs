@@ -1,10 +1,16 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use crate::base16::Base16Scheme;
+use crate::contrast::{ContrastLevel, HIGH_CONTRAST_SUFFIX};
 use crate::error::PaletteError;
+use crate::import::{import, is_windows_terminal_scheme, windows_terminal_scheme, ImportFormat};
+use crate::json;
 use crate::manifest::PaletteManifest;
 use crate::merge::merge_manifests;
 use crate::palette::Palette;
+use crate::textmate::{from_tmtheme, invalid_theme};
+use crate::vscode::{is_vscode_document, vscode_manifest};
 
 /// Display metadata for a theme, usable without parsing the full TOML.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -74,18 +80,20 @@ presets! {
     "tokyonight_storm"      => "../presets/tokyonight_storm.toml",      "TokyoNight Storm",      "storm",
 }
 
+/// Contrast level used for `+hc` theme IDs.
+const DERIVED_CONTRAST_LEVEL: ContrastLevel = ContrastLevel::AaaNormal;
+
 // ---------------------------------------------------------------------------
-// Shared inheritance resolution
+// Shared inheritance and include resolution
 // ---------------------------------------------------------------------------
 
 fn resolve_with_inheritance<F>(
-    toml_str: &str,
+    manifest: PaletteManifest,
     resolve_parent: F,
 ) -> Result<Palette, PaletteError>
 where
     F: FnOnce(&str) -> Result<PaletteManifest, PaletteError>,
 {
-    let manifest = PaletteManifest::from_toml(toml_str)?;
     let resolved = match manifest.inherits_from() {
         None => manifest,
         Some(parent_id) => {
@@ -96,17 +104,71 @@ where
     Palette::from_manifest(&resolved)
 }
 
+fn read_file(path: &Path) -> Result<String, PaletteError> {
+    std::fs::read_to_string(path).map_err(|source| PaletteError::Io {
+        path: Arc::from(path.to_string_lossy().as_ref()),
+        source,
+    })
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Parse a manifest and merge in its `include` fragments.
+///
+/// Fragment paths resolve relative to `path`'s directory. Keys in the
+/// including file win over fragments; later fragments win over earlier ones.
+/// Without a `path`, any `include` entry is an error.
+fn parse_with_includes(toml_str: &str, path: Option<&Path>) -> Result<PaletteManifest, PaletteError> {
+    let manifest = PaletteManifest::from_toml(toml_str)?;
+    let mut stack: Vec<PathBuf> = path.map(canonical).into_iter().collect();
+    apply_includes(manifest, path.and_then(Path::parent), &mut stack)
+}
+
+fn apply_includes(
+    manifest: PaletteManifest,
+    dir: Option<&Path>,
+    stack: &mut Vec<PathBuf>,
+) -> Result<PaletteManifest, PaletteError> {
+    let mut fragments: Option<PaletteManifest> = None;
+    for include in &manifest.include {
+        let dir = dir.ok_or_else(|| PaletteError::UnresolvedInclude(Arc::clone(include)))?;
+        let fragment = load_fragment(&dir.join(include.as_ref()), stack)?;
+        fragments = Some(match fragments {
+            None => fragment,
+            Some(earlier) => merge_manifests(&fragment, &earlier),
+        });
+    }
+
+    Ok(match fragments {
+        None => manifest,
+        Some(fragments) => merge_manifests(&manifest, &fragments),
+    })
+}
+
+fn load_fragment(path: &Path, stack: &mut Vec<PathBuf>) -> Result<PaletteManifest, PaletteError> {
+    let toml = read_file(path)?;
+    let key = canonical(path);
+    if stack.contains(&key) {
+        return Err(PaletteError::IncludeCycle(Arc::from(path.to_string_lossy().as_ref())));
+    }
+
+    let fragment = PaletteManifest::from_fragment_toml(&toml)?;
+    stack.push(key);
+    let resolved = apply_includes(fragment, path.parent(), stack);
+    stack.pop();
+    resolved
+}
+
 // ---------------------------------------------------------------------------
 // Standalone preset functions (existing API)
 // ---------------------------------------------------------------------------
 
 pub fn load_preset_file(path: &Path) -> Result<Palette, PaletteError> {
-    let path_str: Arc<str> = Arc::from(path.to_string_lossy().as_ref());
-    let toml = std::fs::read_to_string(path).map_err(|source| PaletteError::Io {
-        path: path_str,
-        source,
-    })?;
-    resolve_with_inheritance(&toml, |parent_id| resolve_parent(path, parent_id))
+    let toml = read_file(path)?;
+    let manifest = parse_with_includes(&toml, Some(path))?;
+    resolve_with_inheritance(manifest, |parent_id| resolve_parent(path, parent_id))
 }
 
 fn resolve_parent(child_path: &Path, parent_id: &str) -> Result<PaletteManifest, PaletteError> {
@@ -116,25 +178,25 @@ fn resolve_parent(child_path: &Path, parent_id: &str) -> Result<PaletteManifest,
         .filter(|p| p.is_file());
 
     match (sibling, preset_toml(parent_id)) {
-        (Some(path), _) => {
-            let path_str: Arc<str> = Arc::from(path.to_string_lossy().as_ref());
-            let toml = std::fs::read_to_string(&path).map_err(|source| PaletteError::Io {
-                path: path_str,
-                source,
-            })?;
-            PaletteManifest::from_toml(&toml)
-        }
-        (None, Some(embedded)) => PaletteManifest::from_toml(embedded),
+        (Some(path), _) => parse_with_includes(&read_file(&path)?, Some(&path)),
+        (None, Some(embedded)) => parse_with_includes(embedded, None),
         (None, None) => Err(PaletteError::UnknownPreset(Arc::from(parent_id))),
     }
 }
 
+/// Load a built-in preset by ID.
+///
+/// A `+hc` suffix (e.g. `nord+hc`) loads the derived high-contrast variant;
+/// see [`Palette::high_contrast`].
 pub fn load_preset(id: &str) -> Result<Palette, PaletteError> {
+    if let (None, Some(base_id)) = (preset_toml(id), id.strip_suffix(HIGH_CONTRAST_SUFFIX)) {
+        return load_preset(base_id).map(|p| p.high_contrast(DERIVED_CONTRAST_LEVEL));
+    }
     let toml = preset_toml(id).ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id)))?;
-    resolve_with_inheritance(toml, |parent_id| {
+    resolve_with_inheritance(parse_with_includes(toml, None)?, |parent_id| {
         let parent_toml = preset_toml(parent_id)
             .ok_or_else(|| PaletteError::UnknownPreset(Arc::from(parent_id)))?;
-        PaletteManifest::from_toml(parent_toml)
+        parse_with_includes(parent_toml, None)
     })
 }
 
@@ -153,7 +215,13 @@ pub fn preset(id: &str) -> Option<Palette> {
 
 enum Source {
     Builtin,
-    Custom(Box<str>),
+    /// Raw TOML plus the file it came from, used to resolve `include` paths.
+    Custom {
+        toml: Box<str>,
+        path: Option<Box<Path>>,
+    },
+    /// Terminal emulator config, converted once when the file is added.
+    Imported(Box<PaletteManifest>),
 }
 
 struct Entry {
@@ -192,9 +260,27 @@ impl Registry {
     }
 
     /// Load a palette by ID, resolving inheritance within the registry.
+    ///
+    /// Any registered ID with a `+hc` suffix (e.g. `nord+hc`) loads its
+    /// derived high-contrast variant.
     pub fn load(&self, id: &str) -> Result<Palette, PaletteError> {
-        let toml_str = self.toml_for(id)?;
-        resolve_with_inheritance(toml_str, |parent_id| self.resolve_manifest(parent_id))
+        if let (Err(_), Some(base_id)) = (self.find_entry(id), id.strip_suffix(HIGH_CONTRAST_SUFFIX)) {
+            return self.load(base_id).map(|p| p.high_contrast(DERIVED_CONTRAST_LEVEL));
+        }
+        let manifest = self.resolve_manifest(id)?;
+        resolve_with_inheritance(manifest, |parent_id| self.resolve_manifest(parent_id))
+    }
+
+    /// Derived high-contrast companions for every registered theme.
+    ///
+    /// These are not part of [`list`](Self::list); load them by ID like any
+    /// other theme.
+    pub fn high_contrast_variants(&self) -> impl Iterator<Item = ThemeInfo> + '_ {
+        self.entries.iter().map(|e| ThemeInfo {
+            id: Arc::from(format!("{}{HIGH_CONTRAST_SUFFIX}", e.info.id)),
+            name: Arc::from(format!("{} (High Contrast)", e.info.name)),
+            style: Arc::clone(&e.info.style),
+        })
     }
 
     /// Filter registered themes by style (e.g. "dark", "light").
@@ -205,38 +291,44 @@ impl Registry {
             .map(|e| &e.info)
     }
 
-    /// Register a custom theme from a TOML file on disk.
+    /// Register a custom theme from a file on disk.
+    ///
+    /// Palette manifests keep `include` paths resolving relative to the file's
+    /// directory. Terminal emulator configs recognized by
+    /// [`ImportFormat::detect`] (Kitty `.conf`, `.itermcolors`, Alacritty or
+    /// WezTerm `.toml`, ...), Base16/Base24 `.yaml` schemes, VS Code color
+    /// theme `.json`, and TextMate `.tmTheme` files are imported under the
+    /// file stem as their ID.
     pub fn add_file(&mut self, path: &Path) -> Result<(), PaletteError> {
-        let path_str: Arc<str> = Arc::from(path.to_string_lossy().as_ref());
-        let toml = std::fs::read_to_string(path).map_err(|source| PaletteError::Io {
-            path: path_str,
-            source,
-        })?;
+        let contents = read_file(path)?;
+        match import_file(path, &contents) {
+            Some(manifest) => self.insert_imported(manifest?),
+            None => self.insert(contents, Some(Box::from(path))),
+        }
+    }
 
-        self.add_toml(toml)
+    /// Register a Base16 or Base24 YAML scheme, using [`Base16Scheme::slug`]
+    /// of its name as the ID.
+    pub fn add_base16(&mut self, yaml: &str) -> Result<(), PaletteError> {
+        let scheme = Base16Scheme::from_yaml(yaml)?;
+        self.insert_imported(scheme.to_manifest(&scheme.slug()))
     }
 
     /// Register a custom theme from a TOML string.
     ///
     /// Useful for WASM targets (no filesystem), network-fetched themes, or
-    /// embedded resources.
+    /// embedded resources. Themes registered this way cannot use `include`.
     pub fn add_toml(&mut self, toml: String) -> Result<(), PaletteError> {
-        let info = extract_theme_info(&toml)?;
-        let source = Source::Custom(toml.into_boxed_str());
-
-        match self.entries.iter().position(|e| e.info.id == info.id) {
-            Some(idx) => {
-                self.entries[idx] = Entry { info, source };
-            }
-            None => {
-                self.entries.push(Entry { info, source });
-            }
-        }
-
-        Ok(())
+        self.insert(toml, None)
     }
 
-    /// Register all `.toml` files in a directory as custom themes.
+    /// Register every theme file in a directory: `.toml` files, plus the
+    /// other formats [`add_file`](Self::add_file) imports.
+    ///
+    /// A `.toml` file that fails to parse is an error, as with `add_file`.
+    /// Files with the other extensions are often unrelated (`package.json`,
+    /// a `kitty.conf` without colors), so those that are not recognized or
+    /// do not import are skipped.
     pub fn add_dir(&mut self, dir: &Path) -> Result<(), PaletteError> {
         let dir_str: Arc<str> = Arc::from(dir.to_string_lossy().as_ref());
         let read_dir = std::fs::read_dir(dir).map_err(|source| PaletteError::Io {
@@ -252,6 +344,11 @@ impl Registry {
             let path = entry.path();
             match path.extension().and_then(|e| e.to_str()) {
                 Some("toml") => self.add_file(&path)?,
+                Some("yaml" | "yml" | "conf" | "json" | "itermcolors" | "tmTheme" | "Xresources") => {
+                    if let Some(Ok(manifest)) = import_file(&path, &read_file(&path)?) {
+                        self.insert_imported(manifest)?;
+                    }
+                }
                 _ => continue,
             }
         }
@@ -260,6 +357,32 @@ impl Registry {
     }
 }
 
+/// The manifest for a file in an importable format, or `None` for a palette
+/// manifest. Imports use the file stem as their ID.
+fn import_file(path: &Path, contents: &str) -> Option<Result<PaletteManifest, PaletteError>> {
+    let stem = path.file_stem().map(|s| s.to_string_lossy()).unwrap_or_default();
+    let id = stem.trim_start_matches('.');
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("yaml" | "yml") => Some(Base16Scheme::from_yaml(contents).map(|scheme| scheme.to_manifest(id))),
+        Some("tmTheme") => Some(from_tmtheme(contents, id)),
+        Some("json") => Some(import_json(contents, id)),
+        _ => {
+            let format = ImportFormat::detect(path, contents)?;
+            Some(import(contents, format).and_then(|theme| theme.to_manifest(id)))
+        }
+    }
+}
+
+/// A VS Code color theme or Windows Terminal scheme, parsed once.
+fn import_json(contents: &str, id: &str) -> Result<PaletteManifest, PaletteError> {
+    let doc = json::parse(contents).map_err(|reason| invalid_theme("json", reason))?;
+    match (is_vscode_document(&doc), is_windows_terminal_scheme(&doc)) {
+        (true, _) => vscode_manifest(&doc, id),
+        (false, true) => windows_terminal_scheme(&doc)?.to_manifest(id),
+        (false, false) => Err(invalid_theme("json", "not a VS Code theme or Windows Terminal scheme".to_owned())),
+    }
+}
+
 impl Default for Registry {
     fn default() -> Self {
         Self::new()
@@ -274,22 +397,52 @@ impl Registry {
             .ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id)))
     }
 
-    fn toml_for(&self, id: &str) -> Result<&str, PaletteError> {
-        let entry = self.find_entry(id)?;
-        match &entry.source {
-            Source::Builtin => preset_toml(id)
-                .ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id))),
-            Source::Custom(toml) => Ok(toml),
+    fn insert(&mut self, toml: String, path: Option<Box<Path>>) -> Result<(), PaletteError> {
+        let info = extract_theme_info(PaletteManifest::from_toml(&toml)?)?;
+        let source = Source::Custom {
+            toml: toml.into_boxed_str(),
+            path,
+        };
+        self.upsert(Entry { info, source });
+        Ok(())
+    }
+
+    fn insert_imported(&mut self, manifest: PaletteManifest) -> Result<(), PaletteError> {
+        let info = extract_theme_info(manifest.clone())?;
+        self.upsert(Entry {
+            info,
+            source: Source::Imported(Box::new(manifest)),
+        });
+        Ok(())
+    }
+
+    fn upsert(&mut self, entry: Entry) {
+        let Entry { info, source } = entry;
+        match self.entries.iter().position(|e| e.info.id == info.id) {
+            Some(idx) => {
+                self.entries[idx] = Entry { info, source };
+            }
+            None => {
+                self.entries.push(Entry { info, source });
+            }
         }
     }
 
     fn resolve_manifest(&self, id: &str) -> Result<PaletteManifest, PaletteError> {
-        PaletteManifest::from_toml(self.toml_for(id)?)
+        let entry = self.find_entry(id)?;
+        match &entry.source {
+            Source::Builtin => {
+                let toml = preset_toml(id)
+                    .ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id)))?;
+                parse_with_includes(toml, None)
+            }
+            Source::Custom { toml, path } => parse_with_includes(toml, path.as_deref()),
+            Source::Imported(manifest) => Ok(PaletteManifest::clone(manifest)),
+        }
     }
 }
 
-fn extract_theme_info(toml_str: &str) -> Result<ThemeInfo, PaletteError> {
-    let manifest = PaletteManifest::from_toml(toml_str)?;
+fn extract_theme_info(manifest: PaletteManifest) -> Result<ThemeInfo, PaletteError> {
     let meta = manifest.meta.ok_or(PaletteError::MissingMeta)?;
     Ok(ThemeInfo {
         id: meta.preset_id,
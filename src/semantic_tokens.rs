@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::error::PaletteError;
+use crate::manifest::ManifestSection;
+use crate::palette::{Palette, SyntaxColors};
+use crate::style::TextStyle;
+
+/// `[semantic_tokens]` rules: selector (`type` or `type.modifier...`) to
+/// `syntax` slot name.
+pub type SemanticTokenOverrides = BTreeMap<Arc<str>, &'static str>;
+
+/// Built-in selectors, in the same `type.modifier` form as manifest rules.
+const DEFAULT_RULES: [(&str, &str); 33] = [
+    ("boolean", "booleans"),
+    ("builtinType", "types_builtin"),
+    ("class", "types"),
+    ("class.defaultLibrary", "types_builtin"),
+    ("comment", "comments"),
+    ("decorator", "annotations"),
+    ("enum", "types"),
+    ("enum.defaultLibrary", "types_builtin"),
+    ("enumMember", "constants"),
+    ("event", "properties"),
+    ("function", "functions"),
+    ("interface", "types"),
+    ("interface.defaultLibrary", "types_builtin"),
+    ("keyword", "keywords"),
+    ("macro", "functions"),
+    ("method", "functions"),
+    ("modifier", "keywords"),
+    ("namespace", "types"),
+    ("number", "numbers"),
+    ("operator", "operators"),
+    ("parameter", "parameters"),
+    ("property", "properties"),
+    ("regexp", "strings_regex"),
+    ("selfKeyword", "variables_builtin"),
+    ("string", "strings"),
+    ("struct", "types"),
+    ("struct.defaultLibrary", "types_builtin"),
+    ("type", "types"),
+    ("type.defaultLibrary", "types_builtin"),
+    ("typeParameter", "types"),
+    ("variable", "variables"),
+    ("variable.defaultLibrary", "variables_builtin"),
+    ("variable.readonly", "constants"),
+];
+
+/// Modifiers a selector requires, if it applies to `token_type` at all.
+fn selector_modifiers<'s>(selector: &'s str, token_type: &str) -> Option<std::str::Split<'s, char>> {
+    let mut parts = selector.split('.');
+    match parts.next() {
+        Some(t) if t == token_type || t == "*" => Some(parts),
+        _ => None,
+    }
+}
+
+/// Most specific matching rule: every selector modifier must be present on
+/// the token, and more modifiers beat fewer. Ties keep the first rule.
+fn best_match<'a>(
+    rules: impl Iterator<Item = (&'a str, &'static str)>,
+    token_type: &str,
+    modifiers: &[&str],
+) -> Option<&'static str> {
+    let mut best: Option<(usize, &'static str)> = None;
+    for (selector, slot) in rules {
+        let Some(required) = selector_modifiers(selector, token_type) else {
+            continue;
+        };
+        let required: Vec<&str> = required.collect();
+        let matched = required.iter().all(|m| modifiers.contains(m));
+        if matched && best.is_none_or(|(n, _)| required.len() > n) {
+            best = Some((required.len(), slot));
+        }
+    }
+    best.map(|(_, slot)| slot)
+}
+
+/// Built-in `syntax` slot for an LSP semantic token type and its modifiers,
+/// e.g. `("variable", ["defaultLibrary"])` maps to `variables_builtin`.
+pub fn semantic_token_slot(token_type: &str, modifiers: &[&str]) -> Option<&'static str> {
+    best_match(DEFAULT_RULES.iter().copied(), token_type, modifiers)
+}
+
+/// `type` or `*` followed by `.modifier` parts, each made of ASCII letters,
+/// digits and `_`. Exporters write selectors into Lua and JSON, so nothing
+/// else gets through.
+fn is_valid_selector(selector: &str) -> bool {
+    let word = |part: &str, star: bool| {
+        !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || (star && c == '*'))
+    };
+    let mut parts = selector.split('.');
+    parts.next().is_some_and(|token_type| word(token_type, true)) && parts.all(|modifier| word(modifier, false))
+}
+
+/// Validate `[semantic_tokens]` selectors and check values against the
+/// `syntax` slot names.
+pub fn from_section(section: &ManifestSection) -> Result<SemanticTokenOverrides, PaletteError> {
+    let slots = SyntaxColors::default();
+    section
+        .iter()
+        .map(|(selector, slot)| {
+            if !is_valid_selector(selector) {
+                return Err(PaletteError::InvalidSelector(selector.clone()));
+            }
+            let known = slots.slots().map(|(name, _)| name).find(|name| *name == &**slot);
+            match known {
+                Some(name) => Ok((selector.clone(), name)),
+                None => Err(PaletteError::UnknownSlot {
+                    section: Arc::from("semantic_tokens"),
+                    field: selector.clone(),
+                    value: slot.clone(),
+                }),
+            }
+        })
+        .collect()
+}
+
+impl Palette {
+    /// `syntax` slot for a semantic token, using manifest `[semantic_tokens]`
+    /// rules alongside the built-in mapping.
+    ///
+    /// The most specific selector wins; a manifest rule beats a built-in one
+    /// of equal specificity, so `variable = "properties"` leaves
+    /// `variable.defaultLibrary` on `variables_builtin`.
+    pub fn semantic_token_slot(&self, token_type: &str, modifiers: &[&str]) -> Option<&'static str> {
+        let overrides = self.semantic_tokens.iter().map(|(selector, slot)| (&**selector, *slot));
+        best_match(overrides.chain(DEFAULT_RULES.iter().copied()), token_type, modifiers)
+    }
+
+    /// Every selector in effect: manifest rules first, then the built-in
+    /// ones they don't replace.
+    pub fn semantic_token_rules(&self) -> Vec<(&str, &'static str)> {
+        let overrides = self.semantic_tokens.iter().map(|(selector, slot)| (&**selector, *slot));
+        let defaults = DEFAULT_RULES
+            .iter()
+            .copied()
+            .filter(|(selector, _)| !self.semantic_tokens.contains_key(*selector));
+        overrides.chain(defaults).collect()
+    }
+
+    /// Color for a semantic token, or `None` if the mapped slot is unset.
+    pub fn semantic_token_color(&self, token_type: &str, modifiers: &[&str]) -> Option<Color> {
+        self.semantic_token_slot(token_type, modifiers)
+            .and_then(|field| self.syntax.get(field))
+            .copied()
+    }
+
+    /// Text style for a semantic token. `deprecated` adds strikethrough on top
+    /// of the mapped slot's style.
+    pub fn semantic_token_style(&self, token_type: &str, modifiers: &[&str]) -> TextStyle {
+        let mut style = self
+            .semantic_token_slot(token_type, modifiers)
+            .and_then(|field| self.styles.syntax.get(field))
+            .copied()
+            .unwrap_or_default();
+        if modifiers.contains(&"deprecated") {
+            style.strikethrough = true;
+        }
+        style
+    }
+}
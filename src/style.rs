@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+/// Font styling attached to a color slot.
+///
+/// Written in a manifest as an inline table next to the color:
+/// `keywords = { fg = "#cba6f7", bold = true }`. All flags default to `false`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+#[serde(default, deny_unknown_fields)]
+pub struct TextStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    /// Wavy underline, as used for diagnostics. Renderers without curly
+    /// underlines fall back to a plain underline.
+    pub undercurl: bool,
+    pub strikethrough: bool,
+}
+
+impl TextStyle {
+    /// `true` when no attribute is set.
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+}
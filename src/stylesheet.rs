@@ -0,0 +1,255 @@
+use std::fmt::Write;
+
+use crate::contrast::SlotRef;
+use crate::css::{slot_name, text_decoration, var_name};
+use crate::palette::Palette;
+
+/// Class names a code highlighter puts on tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeClasses {
+    /// highlight.js: `.hljs-keyword`, `.hljs-title.function_`, ...
+    HighlightJs,
+    /// Prism: `.token.keyword`, `.token.class-name`, ...
+    Prism,
+    /// Pygments and Rouge short classes under `.highlight`: `.k`, `.s`, `.c`, ...
+    Pygments,
+}
+
+impl CodeClasses {
+    pub const ALL: [Self; 3] = [Self::HighlightJs, Self::Prism, Self::Pygments];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::HighlightJs => "highlight.js",
+            Self::Prism => "prism",
+            Self::Pygments => "pygments",
+        }
+    }
+
+    /// Selector for the code block itself, which gets `fg` / `bg`.
+    fn container(self) -> &'static str {
+        match self {
+            Self::HighlightJs => ".hljs",
+            Self::Prism => "code[class*=\"language-\"],\npre[class*=\"language-\"]",
+            Self::Pygments => ".highlight",
+        }
+    }
+
+    /// Token selectors and the slots tried for each, in order.
+    pub fn rules(self) -> &'static [(&'static [&'static str], &'static [SlotRef])] {
+        match self {
+            Self::HighlightJs => HLJS_RULES,
+            Self::Prism => PRISM_RULES,
+            Self::Pygments => PYGMENTS_RULES,
+        }
+    }
+
+    /// Rules that only set emphasis, independent of the palette.
+    fn emphasis(self) -> &'static str {
+        match self {
+            Self::HighlightJs => ".hljs-emphasis {\n  font-style: italic;\n}\n.hljs-strong {\n  font-weight: bold;\n}\n",
+            Self::Prism => ".token.italic {\n  font-style: italic;\n}\n.token.bold,\n.token.important {\n  font-weight: bold;\n}\n",
+            Self::Pygments => ".highlight .ge {\n  font-style: italic;\n}\n.highlight .gs {\n  font-weight: bold;\n}\n",
+        }
+    }
+}
+
+/// How generated rules refer to colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorValues<'a> {
+    /// `var(--syn-keyword)` and friends, with the same optional prefix as
+    /// [`Palette::to_css_scoped`]. Every rule is written, so the stylesheet
+    /// follows whichever palette's variables are active.
+    ///
+    /// Each rule starts at the slot this palette sets, and the style
+    /// variables come from that slot only, so a fallback slot's bold or
+    /// italic never lands on another slot's color.
+    Variables(Option<&'a str>),
+    /// Hex colors and styles from this palette; rules for unset slots are
+    /// left out.
+    Literal,
+}
+
+const HLJS_RULES: &[(&[&str], &[SlotRef])] = &[
+    (&[".hljs-comment", ".hljs-quote"], &[("syntax", "comments")]),
+    (&[".hljs-doctag"], &[("syntax", "strings_doc"), ("syntax", "comments")]),
+    (&[".hljs-keyword", ".hljs-selector-tag"], &[("syntax", "keywords")]),
+    (&[".hljs-operator"], &[("syntax", "operators")]),
+    (&[".hljs-punctuation"], &[("syntax", "punctuation")]),
+    (&[".hljs-title.function_", ".hljs-title", ".hljs-section"], &[("syntax", "functions")]),
+    (&[".hljs-title.class_", ".hljs-type", ".hljs-selector-class"], &[("syntax", "types")]),
+    (&[".hljs-built_in"], &[("syntax", "types_builtin"), ("syntax", "types")]),
+    (&[".hljs-variable", ".hljs-template-variable", ".hljs-subst"], &[("syntax", "variables")]),
+    (&[".hljs-variable.language_"], &[("syntax", "variables_builtin"), ("syntax", "variables")]),
+    (&[".hljs-params"], &[("syntax", "parameters"), ("syntax", "variables")]),
+    (&[".hljs-property", ".hljs-attr"], &[("syntax", "properties")]),
+    (&[".hljs-number"], &[("syntax", "numbers")]),
+    (&[".hljs-literal"], &[("syntax", "booleans"), ("syntax", "constants")]),
+    (&[".hljs-symbol"], &[("syntax", "constants")]),
+    (&[".hljs-string"], &[("syntax", "strings")]),
+    (&[".hljs-char.escape_"], &[("syntax", "strings_escape"), ("syntax", "strings")]),
+    (&[".hljs-regexp"], &[("syntax", "strings_regex"), ("syntax", "strings")]),
+    (&[".hljs-meta"], &[("syntax", "attributes"), ("syntax", "annotations")]),
+    (&[".hljs-name", ".hljs-tag"], &[("syntax", "tag")]),
+    (&[".hljs-attribute"], &[("syntax", "tag_attribute"), ("syntax", "properties")]),
+    (&[".hljs-link"], &[("typography", "link")]),
+    (&[".hljs-addition"], &[("diff", "added")]),
+    (&[".hljs-deletion"], &[("diff", "removed")]),
+];
+
+const PRISM_RULES: &[(&[&str], &[SlotRef])] = &[
+    (&[".token.comment", ".token.prolog", ".token.doctype", ".token.cdata"], &[("syntax", "comments")]),
+    (&[".token.doc-comment"], &[("syntax", "strings_doc"), ("syntax", "comments")]),
+    (&[".token.keyword", ".token.atrule"], &[("syntax", "keywords")]),
+    (&[".token.operator"], &[("syntax", "operators")]),
+    (&[".token.punctuation"], &[("syntax", "punctuation")]),
+    (&[".token.function", ".token.function-name"], &[("syntax", "functions")]),
+    (&[".token.class-name", ".token.selector"], &[("syntax", "types")]),
+    (&[".token.builtin"], &[("syntax", "types_builtin"), ("syntax", "types")]),
+    (&[".token.variable"], &[("syntax", "variables")]),
+    (&[".token.parameter"], &[("syntax", "parameters"), ("syntax", "variables")]),
+    (&[".token.property"], &[("syntax", "properties")]),
+    (&[".token.number"], &[("syntax", "numbers")]),
+    (&[".token.boolean"], &[("syntax", "booleans"), ("syntax", "constants")]),
+    (&[".token.constant", ".token.symbol"], &[("syntax", "constants")]),
+    (&[".token.string", ".token.char", ".token.attr-value"], &[("syntax", "strings")]),
+    (&[".token.escape"], &[("syntax", "strings_escape"), ("syntax", "strings")]),
+    (&[".token.regex"], &[("syntax", "strings_regex"), ("syntax", "strings")]),
+    (&[".token.annotation", ".token.decorator"], &[("syntax", "annotations")]),
+    (&[".token.tag"], &[("syntax", "tag")]),
+    (&[".token.attr-name"], &[("syntax", "tag_attribute"), ("syntax", "properties")]),
+    (&[".token.url"], &[("typography", "link")]),
+    (&[".token.inserted"], &[("diff", "added")]),
+    (&[".token.deleted"], &[("diff", "removed")]),
+];
+
+const PYGMENTS_RULES: &[(&[&str], &[SlotRef])] = &[
+    (&[".c", ".ch", ".cm", ".c1", ".cs"], &[("syntax", "comments")]),
+    (&[".cp", ".cpf"], &[("syntax", "attributes"), ("syntax", "comments")]),
+    (&[".k", ".kd", ".kn", ".kp", ".kr", ".ow"], &[("syntax", "keywords")]),
+    (&[".kc"], &[("syntax", "booleans"), ("syntax", "constants")]),
+    (&[".kt"], &[("syntax", "types_builtin"), ("syntax", "types")]),
+    (&[".o"], &[("syntax", "operators")]),
+    (&[".p"], &[("syntax", "punctuation")]),
+    (&[".nf", ".fm"], &[("syntax", "functions")]),
+    (&[".nc", ".ne", ".nn"], &[("syntax", "types")]),
+    (&[".nb", ".bp"], &[("syntax", "variables_builtin"), ("syntax", "variables")]),
+    (&[".nv", ".vc", ".vg", ".vi", ".vm"], &[("syntax", "variables")]),
+    (&[".py"], &[("syntax", "properties")]),
+    (&[".no", ".ss"], &[("syntax", "constants")]),
+    (&[".nd"], &[("syntax", "annotations")]),
+    (&[".nt"], &[("syntax", "tag")]),
+    (&[".na"], &[("syntax", "tag_attribute"), ("syntax", "properties")]),
+    (&[".m", ".mb", ".mf", ".mh", ".mi", ".mo", ".il"], &[("syntax", "numbers")]),
+    (&[".s", ".sa", ".sb", ".sc", ".dl", ".s2", ".sh", ".si", ".sx", ".s1"], &[("syntax", "strings")]),
+    (&[".sd"], &[("syntax", "strings_doc"), ("syntax", "strings")]),
+    (&[".se"], &[("syntax", "strings_escape"), ("syntax", "strings")]),
+    (&[".sr"], &[("syntax", "strings_regex"), ("syntax", "strings")]),
+    (&[".gh", ".gu"], &[("typography", "title")]),
+    (&[".gi"], &[("diff", "added")]),
+    (&[".gd"], &[("diff", "removed")]),
+    (&[".err"], &[("semantic", "error")]),
+];
+
+/// `var(--a, var(--b, ...))` over `slots`, each name followed by `suffix`,
+/// ending in `default` when one is given.
+fn var_chain(prefix: Option<&str>, slots: &[SlotRef], suffix: &str, default: Option<&str>) -> String {
+    let innermost = default.map(str::to_owned);
+    slots.iter().rev().fold(innermost, |inner, (section, field)| {
+        let name = var_name(prefix, &format!("{}{suffix}", slot_name(section, field)));
+        Some(match inner {
+            Some(fallback) => format!("var({name}, {fallback})"),
+            None => format!("var({name})"),
+        })
+    })
+    .unwrap_or_default()
+}
+
+/// Declarations for one rule, or `None` when a literal rule has no color.
+fn declarations(palette: &Palette, slots: &[SlotRef], values: ColorValues) -> Option<Vec<String>> {
+    match values {
+        ColorValues::Variables(prefix) => {
+            // Start where pick_slot would, or at the primary slot when none is set.
+            let start = slots.iter().position(|(section, field)| palette.slot(section, field).is_some());
+            let slots = &slots[start.unwrap_or(0)..];
+            let styled = &slots[..slots.len().min(1)];
+            Some(vec![
+                format!("color: {}", var_chain(prefix, slots, "", None)),
+                format!("font-weight: {}", var_chain(prefix, styled, "-font-weight", Some("normal"))),
+                format!("font-style: {}", var_chain(prefix, styled, "-font-style", Some("normal"))),
+                format!("text-decoration: {}", var_chain(prefix, styled, "-text-decoration", Some("none"))),
+            ])
+        }
+        ColorValues::Literal => {
+            let (color, style) = palette.pick_slot(slots)?;
+            let mut decls = vec![format!("color: {color}")];
+            if let Some(style) = style.filter(|s| !s.is_plain()) {
+                if style.bold {
+                    decls.push("font-weight: bold".to_owned());
+                }
+                if style.italic {
+                    decls.push("font-style: italic".to_owned());
+                }
+                if style.underline || style.undercurl || style.strikethrough {
+                    decls.push(format!("text-decoration: {}", text_decoration(&style)));
+                }
+            }
+            Some(decls)
+        }
+    }
+}
+
+fn write_rule(out: &mut String, selectors: &str, decls: &[String]) {
+    // String::write_fmt is infallible
+    let _ = writeln!(out, "{selectors} {{");
+    for decl in decls {
+        let _ = writeln!(out, "  {decl};");
+    }
+    out.push_str("}\n");
+}
+
+/// A complete stylesheet for a code highlighter's token classes.
+///
+/// The container rule (`.hljs`, Prism's `language-*` elements, or
+/// `.highlight`) sets `fg` and `bg`; token rules use the
+/// [`CodeClasses::rules`] table. With [`ColorValues::Variables`], pair it
+/// with [`Palette::to_css`] output using the same prefix.
+pub fn to_code_stylesheet(palette: &Palette, classes: CodeClasses, values: ColorValues) -> String {
+    let name = palette.meta.as_ref().map_or("palette", |m| &m.name);
+    let mut out = format!("/* {} — {} */\n", name.replace("*/", "* /"), classes.name());
+
+    let container: Vec<String> = match values {
+        ColorValues::Variables(prefix) => vec![
+            format!("color: var({})", var_name(prefix, "fg")),
+            format!("background: var({})", var_name(prefix, "bg")),
+        ],
+        ColorValues::Literal => [("color", palette.base.foreground), ("background", palette.base.background)]
+            .iter()
+            .filter_map(|(property, color)| color.map(|c| format!("{property}: {c}")))
+            .collect(),
+    };
+    if !container.is_empty() {
+        write_rule(&mut out, classes.container(), &container);
+    }
+
+    let scope = match classes {
+        CodeClasses::Pygments => ".highlight ",
+        CodeClasses::HighlightJs | CodeClasses::Prism => "",
+    };
+    for (selectors, slots) in classes.rules() {
+        let Some(decls) = declarations(palette, slots, values) else {
+            continue;
+        };
+        let selectors: Vec<String> = selectors.iter().map(|s| format!("{scope}{s}")).collect();
+        write_rule(&mut out, &selectors.join(",\n"), &decls);
+    }
+    out.push_str(classes.emphasis());
+    out
+}
+
+impl Palette {
+    /// See [`to_code_stylesheet`].
+    pub fn to_code_stylesheet(&self, classes: CodeClasses, values: ColorValues) -> String {
+        to_code_stylesheet(self, classes, values)
+    }
+}
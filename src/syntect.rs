@@ -0,0 +1,81 @@
+use ::syntect::highlighting::{
+    Color as SyntectColor, FontStyle, ScopeSelectors, StyleModifier, Theme, ThemeItem, ThemeSettings,
+};
+
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::style::TextStyle;
+use crate::textmate::{global_colors, scope_rules};
+
+pub fn to_syntect_color(color: &Color) -> SyntectColor {
+    SyntectColor {
+        r: color.r,
+        g: color.g,
+        b: color.b,
+        a: 0xFF,
+    }
+}
+
+/// Map text style flags to a syntect font style. Undercurl reads as
+/// underline; syntect has no strikethrough.
+pub fn to_syntect_font_style(style: &TextStyle) -> FontStyle {
+    let flags = [
+        (style.bold, FontStyle::BOLD),
+        (style.italic, FontStyle::ITALIC),
+        (style.underline || style.undercurl, FontStyle::UNDERLINE),
+    ];
+    flags
+        .into_iter()
+        .filter(|(set, _)| *set)
+        .fold(FontStyle::empty(), |acc, (_, f)| acc | f)
+}
+
+/// Editor settings from the [`GLOBALS`](crate::textmate::GLOBALS) table.
+fn theme_settings(palette: &Palette) -> ThemeSettings {
+    let mut settings = ThemeSettings::default();
+    for (key, color) in global_colors(palette) {
+        let field = match key {
+            "background" => &mut settings.background,
+            "foreground" => &mut settings.foreground,
+            "caret" => &mut settings.caret,
+            "selection" => &mut settings.selection,
+            "selectionForeground" => &mut settings.selection_foreground,
+            "lineHighlight" => &mut settings.line_highlight,
+            "findHighlight" => &mut settings.find_highlight,
+            "findHighlightForeground" => &mut settings.find_highlight_foreground,
+            "gutter" => &mut settings.gutter,
+            "gutterForeground" => &mut settings.gutter_foreground,
+            "guide" => &mut settings.guide,
+            "activeGuide" => &mut settings.active_guide,
+            "bracketsForeground" => &mut settings.brackets_foreground,
+            _ => continue,
+        };
+        *field = Some(to_syntect_color(&color));
+    }
+    settings
+}
+
+/// A syntect theme with the same settings and scope rules as
+/// [`to_tmtheme`](crate::textmate::to_tmtheme), without the plist round trip.
+pub fn to_syntect_theme(palette: &Palette) -> Theme {
+    let scopes = scope_rules(palette)
+        .into_iter()
+        .filter_map(|rule| {
+            let scope: ScopeSelectors = rule.scopes.join(", ").parse().ok()?;
+            Some(ThemeItem {
+                scope,
+                style: StyleModifier {
+                    foreground: Some(to_syntect_color(&rule.foreground)),
+                    background: None,
+                    font_style: rule.style.as_ref().map(to_syntect_font_style),
+                },
+            })
+        })
+        .collect();
+    Theme {
+        name: palette.meta.as_ref().map(|m| m.name.to_string()),
+        author: None,
+        settings: theme_settings(palette),
+        scopes,
+    }
+}
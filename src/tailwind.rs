@@ -0,0 +1,76 @@
+use std::fmt::Write;
+
+use crate::css::{color_names, var_name};
+use crate::json::quote;
+use crate::palette::Palette;
+
+/// A color that mixes the custom property with transparency, so opacity
+/// modifiers such as `bg-bg/50` work without channel-only variables.
+fn mixed(variable: &str, alpha: &str) -> String {
+    format!("color-mix(in srgb, var({variable}) calc({alpha} * 100%), transparent)")
+}
+
+/// A JSON object mapping utility keys to values, indented by `depth` levels.
+fn colors_object(palette: &Palette, prefix: Option<&str>, alpha: &str, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+    let entries: Vec<String> = color_names(palette)
+        .iter()
+        .map(|name| format!("{indent}  {}: {}", quote(name), quote(&mixed(&var_name(prefix, name), alpha))))
+        .collect();
+    match entries.is_empty() {
+        true => "{}".to_owned(),
+        false => format!("{{\n{}\n{indent}}}", entries.join(",\n")),
+    }
+}
+
+/// A Tailwind v3 config fragment: `{ "theme": { "extend": { "colors": ... } } }`.
+///
+/// Keys are the [`Palette::to_css`] variable names without `--` (`bg`, `fg`,
+/// `ui-sel`, `syn-keyword`), so `text-syn-keyword` or `bg-bg/80` resolve to
+/// the active palette's variables. Values use Tailwind's `<alpha-value>`
+/// placeholder. The output is JSON, which is also a valid JS object literal
+/// for spreading into `tailwind.config.js`.
+pub fn to_tailwind_config(palette: &Palette, prefix: Option<&str>) -> String {
+    let colors = colors_object(palette, prefix, "<alpha-value>", 3);
+    format!("{{\n  \"theme\": {{\n    \"extend\": {{\n      \"colors\": {colors}\n    }}\n  }}\n}}\n")
+}
+
+/// A Tailwind v4 `@theme inline` block defining `--color-{name}` for every
+/// variable [`Palette::to_css`] writes.
+///
+/// `inline` makes utilities reference `var(--syn-keyword)` directly, so they
+/// follow whichever palette is active; Tailwind v4 adds opacity modifiers
+/// through `color-mix` on its own.
+pub fn to_tailwind_theme(palette: &Palette, prefix: Option<&str>) -> String {
+    let mut out = String::from("@theme inline {\n");
+    for name in color_names(palette) {
+        // String::write_fmt is infallible
+        let _ = writeln!(out, "  --color-{name}: var({});", var_name(prefix, &name));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// An UnoCSS config fragment: `{ "theme": { "colors": ... } }`, with the same
+/// keys as [`to_tailwind_config`] and UnoCSS's `%alpha` placeholder.
+pub fn to_unocss_theme(palette: &Palette, prefix: Option<&str>) -> String {
+    let colors = colors_object(palette, prefix, "%alpha", 2);
+    format!("{{\n  \"theme\": {{\n    \"colors\": {colors}\n  }}\n}}\n")
+}
+
+impl Palette {
+    /// See [`to_tailwind_config`].
+    pub fn to_tailwind_config(&self, prefix: Option<&str>) -> String {
+        to_tailwind_config(self, prefix)
+    }
+
+    /// See [`to_tailwind_theme`].
+    pub fn to_tailwind_theme(&self, prefix: Option<&str>) -> String {
+        to_tailwind_theme(self, prefix)
+    }
+
+    /// See [`to_unocss_theme`].
+    pub fn to_unocss_theme(&self, prefix: Option<&str>) -> String {
+        to_unocss_theme(self, prefix)
+    }
+}
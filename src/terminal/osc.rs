@@ -0,0 +1,260 @@
+use crate::color::Color;
+use crate::palette::{BaseColors, EditorColors, Palette, SemanticColors, SurfaceColors, TerminalAnsiColors};
+
+const OSC: &str = "\x1b]";
+const ST: &str = "\x1b\\";
+const BEL: &str = "\x07";
+
+/// Wrapping needed to reach the outer terminal through a multiplexer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Passthrough {
+    #[default]
+    None,
+    /// tmux DCS passthrough (needs `allow-passthrough on`).
+    Tmux,
+    /// GNU screen DCS passthrough.
+    Screen,
+}
+
+/// Guess the passthrough from `TMUX` and `TERM` values.
+pub fn detect_passthrough(tmux: Option<&str>, term: Option<&str>) -> Passthrough {
+    match (tmux, term) {
+        (Some(t), _) if !t.is_empty() => Passthrough::Tmux,
+        (_, Some(t)) if t.starts_with("tmux") => Passthrough::Tmux,
+        (_, Some(t)) if t.starts_with("screen") => Passthrough::Screen,
+        _ => Passthrough::None,
+    }
+}
+
+/// Wrap one complete OSC sequence for `passthrough`.
+///
+/// tmux needs every ESC doubled inside its DCS. screen ends the DCS at the
+/// first ST, so the inner sequence is terminated with BEL instead.
+pub fn wrap(sequence: &str, passthrough: Passthrough) -> String {
+    match passthrough {
+        Passthrough::None => sequence.to_owned(),
+        Passthrough::Tmux => format!("\x1bPtmux;{}{ST}", sequence.replace('\x1b', "\x1b\x1b")),
+        Passthrough::Screen => {
+            let inner = sequence.strip_suffix(ST).map_or_else(|| sequence.to_owned(), |s| format!("{s}{BEL}"));
+            format!("\x1bP{inner}{ST}")
+        }
+    }
+}
+
+/// X11 color spec: `rgb:rr/gg/bb`.
+fn spec(color: &Color) -> String {
+    format!("rgb:{:02x}/{:02x}/{:02x}", color.r, color.g, color.b)
+}
+
+/// OSC 4: set ANSI palette entry `index` (0–255).
+pub fn set_ansi_color(index: u8, color: &Color) -> String {
+    format!("{OSC}4;{index};{}{ST}", spec(color))
+}
+
+/// OSC 10: set the default foreground.
+pub fn set_foreground(color: &Color) -> String {
+    format!("{OSC}10;{}{ST}", spec(color))
+}
+
+/// OSC 11: set the default background.
+pub fn set_background(color: &Color) -> String {
+    format!("{OSC}11;{}{ST}", spec(color))
+}
+
+/// OSC 12: set the cursor color.
+pub fn set_cursor(color: &Color) -> String {
+    format!("{OSC}12;{}{ST}", spec(color))
+}
+
+/// Every sequence needed to recolor the terminal to `palette`, unwrapped.
+///
+/// OSC 4 for each set `terminal_ansi` slot (black = 0 … bright_white = 15),
+/// then OSC 10/11/12 from `base.foreground`, `base.background`, and
+/// `editor.cursor`. Unset slots are skipped.
+pub fn apply_sequences(palette: &Palette) -> Vec<String> {
+    let ansi = palette
+        .terminal_ansi
+        .indexed()
+        .filter_map(|(index, _, color)| color.map(|c| set_ansi_color(index as u8, c)));
+    let dynamic = [
+        palette.base.foreground.as_ref().map(set_foreground),
+        palette.base.background.as_ref().map(set_background),
+        palette.editor.cursor.as_ref().map(set_cursor),
+    ];
+    ansi.chain(dynamic.into_iter().flatten()).collect()
+}
+
+/// [`apply_sequences`] joined and wrapped for `passthrough`, ready to write
+/// to the terminal.
+pub fn apply(palette: &Palette, passthrough: Passthrough) -> String {
+    apply_sequences(palette)
+        .iter()
+        .map(|seq| wrap(seq, passthrough))
+        .collect()
+}
+
+/// OSC 104/110/111/112: restore the ANSI palette, foreground, background,
+/// and cursor to the terminal's configured defaults.
+pub fn reset(passthrough: Passthrough) -> String {
+    ["104", "110", "111", "112"]
+        .iter()
+        .map(|code| wrap(&format!("{OSC}{code}{ST}"), passthrough))
+        .collect()
+}
+
+impl Palette {
+    /// See [`apply`].
+    pub fn to_osc(&self, passthrough: Passthrough) -> String {
+        apply(self, passthrough)
+    }
+}
+
+/// Query sequences for the 16 ANSI entries, foreground, background, and
+/// cursor. Feed the terminal's reply to [`parse_responses`].
+pub fn query_sequences() -> Vec<String> {
+    (0..16)
+        .map(|i| format!("{OSC}4;{i};?{ST}"))
+        .chain(["10", "11", "12"].iter().map(|code| format!("{OSC}{code};?{ST}")))
+        .collect()
+}
+
+/// Parse an X11 color spec as sent in OSC replies: `rgb:r/g/b` with 1–4
+/// hex digits per channel, `rgba:r/g/b/a` (alpha ignored), or `#rrggbb`.
+pub fn parse_color_spec(spec: &str) -> Option<Color> {
+    if spec.starts_with('#') {
+        return Color::from_hex(spec).ok();
+    }
+    let channels = spec.strip_prefix("rgb:").or_else(|| spec.strip_prefix("rgba:"))?;
+    let mut parts = channels.split('/').map(scale_channel);
+    let (r, g, b) = (parts.next()??, parts.next()??, parts.next()??);
+    Some(Color { r, g, b })
+}
+
+/// Scale a 1–4 digit hex channel to 8 bits (`ffff` and `ff` are both 255).
+fn scale_channel(hex: &str) -> Option<u8> {
+    if hex.is_empty() || hex.len() > 4 {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    let max = (1u32 << (4 * hex.len())) - 1;
+    u8::try_from((value * 255 + max / 2) / max).ok()
+}
+
+/// Colors reported by the terminal in reply to [`query_sequences`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueriedColors {
+    pub ansi: TerminalAnsiColors,
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub cursor: Option<Color>,
+}
+
+/// Extract every OSC 4/10/11/12 reply from raw terminal input.
+///
+/// Replies may end in BEL or ST and arrive in any order mixed with other
+/// input; anything unrecognized is skipped. Terminals that do not answer
+/// simply leave fields unset.
+pub fn parse_responses(bytes: &[u8]) -> QueriedColors {
+    let mut out = QueriedColors::default();
+    let mut rest = bytes;
+    while let Some(start) = find(rest, OSC.as_bytes()) {
+        let body = &rest[start + OSC.len()..];
+        let end = body.iter().position(|&b| b == 0x07 || b == 0x1b).unwrap_or(body.len());
+        if let Ok(text) = std::str::from_utf8(&body[..end]) {
+            out.apply_reply(text);
+        }
+        rest = &body[end..];
+    }
+    out
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+impl QueriedColors {
+    fn apply_reply(&mut self, reply: &str) {
+        let mut parts = reply.splitn(3, ';');
+        let (code, arg) = match (parts.next(), parts.next()) {
+            (Some(code), Some(arg)) => (code, arg),
+            _ => return,
+        };
+        let (slot, spec) = match code {
+            "4" => {
+                let field = arg.parse::<usize>().ok().and_then(ansi_field);
+                (field.and_then(|f| self.ansi.get_mut(f)), parts.next())
+            }
+            "10" => (Some(&mut self.foreground), Some(arg)),
+            "11" => (Some(&mut self.background), Some(arg)),
+            "12" => (Some(&mut self.cursor), Some(arg)),
+            _ => return,
+        };
+        if let (Some(slot), Some(color)) = (slot, spec.and_then(parse_color_spec)) {
+            *slot = Some(color);
+        }
+    }
+
+    /// Whether the terminal answered nothing usable.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// A palette built only from the queried colors.
+    ///
+    /// Semantic colors come from the matching ANSI entries (red → `error`,
+    /// green → `success`, ...), and `bright_black` fills the dim foreground and
+    /// border. Everything else is unset; use [`over`](Self::over) to fill the
+    /// gaps from a preset.
+    pub fn to_palette(&self) -> Palette {
+        let ansi = &self.ansi;
+        Palette {
+            base: BaseColors {
+                background: self.background,
+                foreground: self.foreground,
+                foreground_dark: ansi.bright_black,
+                border: ansi.bright_black,
+                ..BaseColors::default()
+            },
+            semantic: SemanticColors {
+                success: ansi.green,
+                warning: ansi.yellow,
+                error: ansi.red,
+                info: ansi.blue,
+                hint: ansi.bright_black,
+            },
+            surface: SurfaceColors::default(),
+            editor: EditorColors {
+                cursor: self.cursor,
+                ..EditorColors::default()
+            },
+            terminal_ansi: ansi.clone(),
+            ..Palette::default()
+        }
+    }
+
+    /// `fallback` with every queried color laid over it, so a bundled preset
+    /// supplies the syntax and UI slots a terminal cannot report.
+    pub fn over(&self, fallback: &Palette) -> Palette {
+        let mut palette = fallback.clone();
+        for (slot, color) in [
+            (&mut palette.base.foreground, self.foreground),
+            (&mut palette.base.background, self.background),
+            (&mut palette.editor.cursor, self.cursor),
+        ] {
+            if color.is_some() {
+                *slot = color;
+            }
+        }
+        for (field, color) in self.ansi.populated_slots() {
+            if let Some(slot) = palette.terminal_ansi.get_mut(field) {
+                *slot = Some(*color);
+            }
+        }
+        palette
+    }
+}
+
+/// `terminal_ansi` field for an ANSI index below 16.
+fn ansi_field(index: usize) -> Option<&'static str> {
+    TerminalAnsiColors::default().indexed().nth(index).map(|(_, name, _)| name)
+}
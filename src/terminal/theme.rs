@@ -0,0 +1,364 @@
+use ratatui::style::{Color as RatatuiColor, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Gauge, Tabs};
+
+use crate::color::Color;
+use crate::manipulation::delta_e;
+use crate::pairs::StylePair;
+use crate::palette::{Palette, TerminalAnsiColors};
+use crate::style::TextStyle;
+
+pub fn to_ratatui_color(color: &Color) -> RatatuiColor {
+    RatatuiColor::Rgb(color.r, color.g, color.b)
+}
+
+/// How many colors the target terminal can display.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorDepth {
+    /// 24-bit RGB (`RatatuiColor::Rgb`).
+    #[default]
+    TrueColor,
+    /// xterm 256-color palette (`RatatuiColor::Indexed(16..=255)`).
+    Ansi256,
+    /// The 16 named ANSI colors, matched against the palette's own
+    /// `terminal_ansi` entries.
+    Ansi16,
+}
+
+/// Guess the color depth from `COLORTERM` and `TERM` values.
+///
+/// `COLORTERM=truecolor|24bit` or a `-direct` terminfo means true color; a
+/// `256color` `TERM` means 256 colors. Anything else, including a missing
+/// `TERM`, falls back to 16 colors.
+pub fn detect_color_depth(colorterm: Option<&str>, term: Option<&str>) -> ColorDepth {
+    if matches!(colorterm, Some("truecolor" | "24bit")) {
+        return ColorDepth::TrueColor;
+    }
+    match term {
+        Some(t) if t.ends_with("-direct") => ColorDepth::TrueColor,
+        Some(t) if t.contains("256color") => ColorDepth::Ansi256,
+        _ => ColorDepth::Ansi16,
+    }
+}
+
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// xterm color for indices `16..=255`: a 6x6x6 cube, then 24 grays.
+fn xterm_color(index: u8) -> Color {
+    match index.checked_sub(232) {
+        Some(gray) => {
+            let v = 8 + gray * 10;
+            Color { r: v, g: v, b: v }
+        }
+        None => {
+            let i = (index - 16) as usize;
+            Color {
+                r: CUBE_LEVELS[i / 36],
+                g: CUBE_LEVELS[(i / 6) % 6],
+                b: CUBE_LEVELS[i % 6],
+            }
+        }
+    }
+}
+
+/// Nearest xterm-256 index by OKLab distance.
+///
+/// Only the fixed cube and gray ramp (`16..=255`) are candidates; indices
+/// `0..16` vary by terminal theme.
+pub fn to_ansi256(color: &Color) -> u8 {
+    (16..=255u8)
+        .min_by(|a, b| delta_e(*color, xterm_color(*a)).total_cmp(&delta_e(*color, xterm_color(*b))))
+        .unwrap_or(16)
+}
+
+/// xterm defaults for ANSI slots the palette leaves unset.
+const XTERM_ANSI: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+const NAMED_ANSI: [RatatuiColor; 16] = [
+    RatatuiColor::Black,
+    RatatuiColor::Red,
+    RatatuiColor::Green,
+    RatatuiColor::Yellow,
+    RatatuiColor::Blue,
+    RatatuiColor::Magenta,
+    RatatuiColor::Cyan,
+    RatatuiColor::Gray,
+    RatatuiColor::DarkGray,
+    RatatuiColor::LightRed,
+    RatatuiColor::LightGreen,
+    RatatuiColor::LightYellow,
+    RatatuiColor::LightBlue,
+    RatatuiColor::LightMagenta,
+    RatatuiColor::LightCyan,
+    RatatuiColor::White,
+];
+
+fn ansi_table(ansi: &TerminalAnsiColors) -> [Color; 16] {
+    let mut table = XTERM_ANSI.map(|(r, g, b)| Color { r, g, b });
+    for (index, _, color) in ansi.indexed() {
+        if let (Some(slot), Some(c)) = (table.get_mut(index), color) {
+            *slot = *c;
+        }
+    }
+    table
+}
+
+/// Nearest of the 16 named ANSI colors, comparing against the palette's
+/// `terminal_ansi` values (xterm defaults where unset) by OKLab distance.
+pub fn to_ansi16(color: &Color, ansi: &TerminalAnsiColors) -> RatatuiColor {
+    let table = ansi_table(ansi);
+    let nearest = (0..16)
+        .min_by(|a, b| delta_e(*color, table[*a]).total_cmp(&delta_e(*color, table[*b])))
+        .unwrap_or(0);
+    NAMED_ANSI[nearest]
+}
+
+/// Convert one color for the given depth. `ansi` is only used for
+/// [`ColorDepth::Ansi16`].
+pub fn to_ratatui_color_with_depth(color: &Color, depth: ColorDepth, ansi: &TerminalAnsiColors) -> RatatuiColor {
+    match depth {
+        ColorDepth::TrueColor => to_ratatui_color(color),
+        ColorDepth::Ansi256 => RatatuiColor::Indexed(to_ansi256(color)),
+        ColorDepth::Ansi16 => to_ansi16(color, ansi),
+    }
+}
+
+/// Map text style flags to ratatui modifiers.
+///
+/// ratatui has no curly underline, so `undercurl` becomes `UNDERLINED`.
+pub fn to_ratatui_modifier(style: &TextStyle) -> Modifier {
+    let flags = [
+        (style.bold, Modifier::BOLD),
+        (style.italic, Modifier::ITALIC),
+        (style.underline || style.undercurl, Modifier::UNDERLINED),
+        (style.strikethrough, Modifier::CROSSED_OUT),
+    ];
+    flags
+        .into_iter()
+        .filter(|(set, _)| *set)
+        .fold(Modifier::empty(), |acc, (_, m)| acc | m)
+}
+
+macro_rules! terminal_group {
+    ($color_type:ident, $style_type:ident { $($field:ident),+ $(,)? }) => {
+        paste::paste! {
+            #[derive(Debug, Clone)]
+            pub struct [<Terminal $color_type>] {
+                $(pub $field: Option<RatatuiColor>,)+
+            }
+
+            impl [<Terminal $color_type>] {
+                fn from_palette(group: &crate::palette::$color_type, convert: &impl Fn(&Color) -> RatatuiColor) -> Self {
+                    Self {
+                        $($field: group.$field.as_ref().map(convert),)+
+                    }
+                }
+            }
+
+            /// Per-slot modifiers; `Modifier::empty()` where no style is set.
+            #[derive(Debug, Clone)]
+            pub struct [<Terminal $style_type>] {
+                $(pub $field: Modifier,)+
+            }
+
+            impl [<Terminal $style_type>] {
+                fn from_palette(group: &crate::palette::$style_type) -> Self {
+                    Self {
+                        $($field: group.$field.map_or(Modifier::empty(), |s| to_ratatui_modifier(&s)),)+
+                    }
+                }
+            }
+        }
+    };
+}
+
+crate::palette::color_fields!(terminal_group);
+
+#[derive(Debug, Clone)]
+pub struct TerminalTheme {
+    pub base: TerminalBaseColors,
+    pub semantic: TerminalSemanticColors,
+    pub diff: TerminalDiffColors,
+    pub surface: TerminalSurfaceColors,
+    pub typography: TerminalTypographyColors,
+    pub syntax: TerminalSyntaxColors,
+    pub editor: TerminalEditorColors,
+    pub terminal_ansi: TerminalTerminalAnsiColors,
+    pub modifiers: TerminalModifiers,
+}
+
+#[derive(Debug, Clone)]
+pub struct TerminalModifiers {
+    pub base: TerminalBaseStyles,
+    pub semantic: TerminalSemanticStyles,
+    pub diff: TerminalDiffStyles,
+    pub surface: TerminalSurfaceStyles,
+    pub typography: TerminalTypographyStyles,
+    pub syntax: TerminalSyntaxStyles,
+    pub editor: TerminalEditorStyles,
+    pub terminal_ansi: TerminalTerminalAnsiStyles,
+}
+
+pub fn to_terminal_theme(palette: &Palette) -> TerminalTheme {
+    to_terminal_theme_with_depth(palette, ColorDepth::TrueColor)
+}
+
+/// Like [`to_terminal_theme`], with every color reduced to `depth`.
+pub fn to_terminal_theme_with_depth(palette: &Palette, depth: ColorDepth) -> TerminalTheme {
+    let convert = |c: &Color| to_ratatui_color_with_depth(c, depth, &palette.terminal_ansi);
+    TerminalTheme {
+        base: TerminalBaseColors::from_palette(&palette.base, &convert),
+        semantic: TerminalSemanticColors::from_palette(&palette.semantic, &convert),
+        diff: TerminalDiffColors::from_palette(&palette.diff, &convert),
+        surface: TerminalSurfaceColors::from_palette(&palette.surface, &convert),
+        typography: TerminalTypographyColors::from_palette(&palette.typography, &convert),
+        syntax: TerminalSyntaxColors::from_palette(&palette.syntax, &convert),
+        editor: TerminalEditorColors::from_palette(&palette.editor, &convert),
+        terminal_ansi: TerminalTerminalAnsiColors::from_palette(&palette.terminal_ansi, &convert),
+        modifiers: TerminalModifiers {
+            base: TerminalBaseStyles::from_palette(&palette.styles.base),
+            semantic: TerminalSemanticStyles::from_palette(&palette.styles.semantic),
+            diff: TerminalDiffStyles::from_palette(&palette.styles.diff),
+            surface: TerminalSurfaceStyles::from_palette(&palette.styles.surface),
+            typography: TerminalTypographyStyles::from_palette(&palette.styles.typography),
+            syntax: TerminalSyntaxStyles::from_palette(&palette.styles.syntax),
+            editor: TerminalEditorStyles::from_palette(&palette.styles.editor),
+            terminal_ansi: TerminalTerminalAnsiStyles::from_palette(&palette.styles.terminal_ansi),
+        },
+    }
+}
+
+/// Terminal theme for a point `t` in a transition from palette `a` to `b`.
+///
+/// Blends in OKLab; see [`Palette::interpolate`].
+pub fn interpolate_terminal_theme(a: &Palette, b: &Palette, t: f64) -> TerminalTheme {
+    to_terminal_theme(&Palette::interpolate(a, b, t))
+}
+
+/// Prebuilt `Style`s for common UI roles.
+///
+/// Each role pairs a foreground and background from the palette, falling
+/// back to `base.foreground` / `base.background` like [`Palette::pairs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalStyles {
+    pub text: Style,
+    pub text_dim: Style,
+    pub title: Style,
+    pub selection: Style,
+    pub search_match: Style,
+    pub border: Style,
+    pub border_focused: Style,
+    pub status_line: Style,
+    pub popup: Style,
+    pub diff_added: Style,
+    pub diff_modified: Style,
+    pub diff_removed: Style,
+    pub error: Style,
+    pub warning: Style,
+    pub info: Style,
+    pub hint: Style,
+    /// Filled part of a gauge or progress bar.
+    pub gauge: Style,
+}
+
+impl TerminalStyles {
+    pub fn from_palette(palette: &Palette) -> Self {
+        Self::from_palette_with_depth(palette, ColorDepth::TrueColor)
+    }
+
+    pub fn from_palette_with_depth(palette: &Palette, depth: ColorDepth) -> Self {
+        let convert = |c: Option<Color>| c.map(|c| to_ratatui_color_with_depth(&c, depth, &palette.terminal_ansi));
+        let style = |fg: Option<Color>, bg: Option<Color>| {
+            let s = Style::default();
+            let s = match convert(fg) {
+                Some(c) => s.fg(c),
+                None => s,
+            };
+            match convert(bg) {
+                Some(c) => s.bg(c),
+                None => s,
+            }
+        };
+        let pair = |p: StylePair| style(p.fg, p.bg);
+        let or = |a: Option<Color>, b: Option<Color>| a.or(b);
+
+        let fg = palette.base.foreground;
+        let bg = palette.base.background;
+        let diagnostic = |d: Option<Color>, s: Option<Color>| style(or(d, s), bg);
+
+        Self {
+            text: style(fg, bg),
+            text_dim: style(or(palette.base.foreground_dark, palette.typography.comment), bg),
+            title: style(or(palette.typography.title, fg), None).add_modifier(Modifier::BOLD),
+            selection: pair(palette.selection_pair()),
+            search_match: pair(palette.search_pair()),
+            border: style(palette.base.border, bg),
+            border_focused: style(or(palette.base.border_highlight, palette.surface.focus), bg),
+            status_line: style(fg, or(palette.surface.statusline, palette.base.background_dark)),
+            popup: style(fg, or(palette.surface.popup, palette.surface.float)),
+            diff_added: pair(palette.diff_added_pair()),
+            diff_modified: pair(palette.diff_modified_pair()),
+            diff_removed: pair(palette.diff_removed_pair()),
+            error: diagnostic(palette.editor.diagnostic_error, palette.semantic.error),
+            warning: diagnostic(palette.editor.diagnostic_warn, palette.semantic.warning),
+            info: diagnostic(palette.editor.diagnostic_info, palette.semantic.info),
+            hint: diagnostic(palette.editor.diagnostic_hint, palette.semantic.hint),
+            gauge: style(
+                or(palette.semantic.info, palette.base.border_highlight),
+                or(palette.base.background_highlight, bg),
+            ),
+        }
+    }
+
+    /// Bordered block with text, border, and title styles applied.
+    pub fn block(&self, focused: bool) -> Block<'static> {
+        let border = match focused {
+            true => self.border_focused,
+            false => self.border,
+        };
+        Block::bordered().style(self.text).border_style(border).title_style(self.title)
+    }
+
+    /// Tabs with dim inactive titles and a bold selected title.
+    pub fn tabs<'a, I>(&self, titles: I) -> Tabs<'a>
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        Tabs::new(titles)
+            .style(self.text_dim)
+            .highlight_style(self.list_highlight())
+    }
+
+    /// Highlight style for the selected `List` or `Table` row.
+    pub fn list_highlight(&self) -> Style {
+        self.selection.add_modifier(Modifier::BOLD)
+    }
+
+    /// Gauge styled with [`gauge`](Self::gauge) on the normal text style.
+    pub fn gauge(&self) -> Gauge<'static> {
+        Gauge::default().style(self.text).gauge_style(self.gauge)
+    }
+}
+
+/// See [`TerminalStyles::from_palette`].
+pub fn to_terminal_styles(palette: &Palette) -> TerminalStyles {
+    TerminalStyles::from_palette(palette)
+}
@@ -0,0 +1,364 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::contrast::SlotRef;
+use crate::error::PaletteError;
+use crate::json::Value;
+use crate::manifest::PaletteManifest;
+use crate::palette::Palette;
+use crate::plist;
+use crate::style::TextStyle;
+
+/// TextMate scope selectors for each palette slot.
+///
+/// Most entries are `syntax` slots; markup links and headings come from
+/// `typography`, diff markup from `diff`, and `invalid` from `semantic.error`.
+/// TextMate picks the most specific matching selector, so `constant` and
+/// `constant.numeric` can map to different slots.
+pub const SCOPES: [(SlotRef, &[&str]); 33] = [
+    (("syntax", "comments"), &["comment", "punctuation.definition.comment"]),
+    (("syntax", "strings_doc"), &["comment.block.documentation", "string.quoted.docstring"]),
+    (("syntax", "keywords"), &["keyword", "keyword.control", "storage.type", "storage.modifier"]),
+    (("syntax", "keywords_fn"), &["storage.type.function", "keyword.function", "keyword.other.fn"]),
+    (("syntax", "operators"), &["keyword.operator"]),
+    (("syntax", "functions"), &["entity.name.function", "support.function", "meta.function-call"]),
+    (("syntax", "constructor"), &["entity.name.function.constructor", "meta.class.constructor"]),
+    (("syntax", "variables"), &["variable", "variable.other.readwrite"]),
+    (("syntax", "variables_builtin"), &["variable.language", "support.variable"]),
+    (("syntax", "parameters"), &["variable.parameter"]),
+    (
+        ("syntax", "properties"),
+        &["variable.other.property", "variable.other.object.property", "support.type.property-name", "meta.object-literal.key"],
+    ),
+    (
+        ("syntax", "types"),
+        &["entity.name.type", "entity.name.class", "support.class", "entity.other.inherited-class"],
+    ),
+    (("syntax", "types_builtin"), &["support.type", "storage.type.primitive", "storage.type.built-in"]),
+    (("syntax", "constants"), &["constant", "variable.other.constant", "support.constant"]),
+    (("syntax", "numbers"), &["constant.numeric"]),
+    (("syntax", "booleans"), &["constant.language.boolean"]),
+    (("syntax", "strings"), &["string"]),
+    (("syntax", "strings_escape"), &["constant.character.escape"]),
+    (("syntax", "strings_regex"), &["string.regexp"]),
+    (("syntax", "punctuation"), &["punctuation", "punctuation.separator", "punctuation.terminator"]),
+    (("syntax", "punctuation_bracket"), &["punctuation.section", "punctuation.bracket", "meta.brace"]),
+    (("syntax", "annotations"), &["meta.decorator", "punctuation.decorator", "storage.type.annotation"]),
+    (("syntax", "attributes"), &["meta.attribute", "entity.name.function.macro"]),
+    (("syntax", "tag"), &["entity.name.tag"]),
+    (("syntax", "tag_delimiter"), &["punctuation.definition.tag"]),
+    (("syntax", "tag_attribute"), &["entity.other.attribute-name"]),
+    (("typography", "link"), &["markup.underline.link", "string.other.link"]),
+    (("typography", "title"), &["markup.heading", "entity.name.section"]),
+    (("typography", "comment"), &["markup.quote"]),
+    (("diff", "added"), &["markup.inserted"]),
+    (("diff", "modified"), &["markup.changed"]),
+    (("diff", "removed"), &["markup.deleted"]),
+    (("semantic", "error"), &["invalid", "invalid.illegal"]),
+];
+
+/// One [`SCOPES`] entry resolved against a palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScopeRule {
+    pub slot: SlotRef,
+    pub scopes: &'static [&'static str],
+    pub foreground: Color,
+    pub style: Option<TextStyle>,
+}
+
+/// Rules for every [`SCOPES`] entry whose slot is set, in table order.
+pub fn scope_rules(palette: &Palette) -> Vec<ScopeRule> {
+    SCOPES
+        .iter()
+        .filter_map(|&(slot, scopes)| {
+            let foreground = *palette.slot(slot.0, slot.1)?;
+            Some(ScopeRule {
+                slot,
+                scopes,
+                foreground,
+                style: palette.styles.get(slot.0, slot.1).copied(),
+            })
+        })
+        .collect()
+}
+
+/// TextMate `fontStyle`: space-separated `bold`, `italic`, `underline`,
+/// `strikethrough`. Undercurl reads as underline.
+pub fn font_style(style: &TextStyle) -> String {
+    let words: Vec<&str> = [
+        (style.bold, "bold"),
+        (style.italic, "italic"),
+        (style.underline || style.undercurl, "underline"),
+        (style.strikethrough, "strikethrough"),
+    ]
+    .into_iter()
+    .filter_map(|(set, word)| set.then_some(word))
+    .collect();
+    words.join(" ")
+}
+
+/// Parse a TextMate `fontStyle` string. Unknown words are ignored.
+pub fn parse_font_style(s: &str) -> TextStyle {
+    let mut style = TextStyle::default();
+    for word in s.split_whitespace() {
+        match word {
+            "bold" => style.bold = true,
+            "italic" => style.italic = true,
+            "underline" => style.underline = true,
+            "strikethrough" => style.strikethrough = true,
+            _ => {}
+        }
+    }
+    style
+}
+
+/// How well a theme scope matches a table entry; lower is better. Exact
+/// matches rank by position in the slot's list, prefix matches by length.
+type Rank = (u8, usize);
+
+fn scope_match(scope: &str) -> Option<(SlotRef, Rank)> {
+    let mut best: Option<(SlotRef, Rank)> = None;
+    for (slot, scopes) in &SCOPES {
+        for (i, known) in scopes.iter().enumerate() {
+            let rank = match scope.strip_prefix(known) {
+                Some("") => (0, i),
+                Some(rest) if rest.starts_with('.') => (1, usize::MAX - known.len()),
+                _ => continue,
+            };
+            if best.is_none_or(|(_, r)| rank < r) {
+                best = Some((*slot, rank));
+            }
+        }
+    }
+    best
+}
+
+/// The slot a TextMate scope maps to: an exact [`SCOPES`] entry, else the
+/// longest entry it extends (`keyword.operator.arithmetic` maps through
+/// `keyword.operator` to `syntax.operators`).
+pub fn scope_slot(scope: &str) -> Option<SlotRef> {
+    scope_match(scope).map(|(slot, _)| slot)
+}
+
+/// Parse `#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa`, compositing any alpha
+/// over `background` when one is known.
+pub(crate) fn parse_theme_color(value: &str, background: Option<Color>) -> Option<Color> {
+    let digits = value.trim().strip_prefix('#')?;
+    let expanded: String = match digits.len() {
+        3 | 4 => digits.chars().flat_map(|c| [c, c]).collect(),
+        6 | 8 => digits.to_owned(),
+        _ => return None,
+    };
+    let color = Color::from_hex(&format!("#{}", expanded.get(..6)?)).ok()?;
+    let alpha = match expanded.get(6..8) {
+        Some(a) => f64::from(u8::from_str_radix(a, 16).ok()?) / 255.0,
+        None => 1.0,
+    };
+    Some(match background {
+        Some(bg) if alpha < 1.0 => color.blend(bg, alpha),
+        _ => color,
+    })
+}
+
+/// Scope rules read from a theme, keeping the best-matching rule per slot.
+#[derive(Default)]
+pub(crate) struct ScopeImport {
+    best: BTreeMap<SlotRef, (Rank, Color, TextStyle)>,
+}
+
+impl ScopeImport {
+    /// Add one rule. `selector` may list several scopes separated by commas;
+    /// for descendant selectors (`source.rust keyword`) the last scope counts,
+    /// and `-` exclusions are dropped.
+    pub(crate) fn add(&mut self, selector: &str, color: Color, style: TextStyle) {
+        for scope in selector.split(',') {
+            let scope = scope.split(" -").next().unwrap_or_default();
+            let Some(last) = scope.split_whitespace().last() else {
+                continue;
+            };
+            let Some((slot, rank)) = scope_match(last) else {
+                continue;
+            };
+            if self.best.get(&slot).is_none_or(|(r, ..)| rank < *r) {
+                self.best.insert(slot, (rank, color, style));
+            }
+        }
+    }
+
+    /// Add a rule from a `tokenColors` / `.tmTheme` `settings` entry.
+    pub(crate) fn add_rule(&mut self, rule: &Value, background: Option<Color>) {
+        let settings = rule.get("settings");
+        let color = settings
+            .and_then(|s| s.get("foreground"))
+            .and_then(Value::as_str)
+            .and_then(|c| parse_theme_color(c, background));
+        let Some(color) = color else {
+            return;
+        };
+        let style = settings
+            .and_then(|s| s.get("fontStyle"))
+            .and_then(Value::as_str)
+            .map_or_else(TextStyle::default, parse_font_style);
+        match rule.get("scope") {
+            Some(Value::String(selector)) => self.add(selector, color, style),
+            Some(Value::Array(selectors)) => {
+                for selector in selectors.iter().filter_map(Value::as_str) {
+                    self.add(selector, color, style);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Fill slots the manifest doesn't set yet, plus their styles.
+    pub(crate) fn apply(self, manifest: &mut PaletteManifest) {
+        for ((section, field), (_, color, style)) in self.best {
+            let Some(slots) = manifest.section_mut(section) else {
+                continue;
+            };
+            if slots.contains_key(field) {
+                continue;
+            }
+            slots.insert(Arc::from(field), Arc::from(color.to_hex()));
+            if !style.is_plain() {
+                manifest
+                    .styles
+                    .entry(Arc::from(section))
+                    .or_default()
+                    .insert(Arc::from(field), style);
+            }
+        }
+    }
+}
+
+/// `.tmTheme` global settings and the palette slots tried for each, in order.
+///
+/// Like [`WORKBENCH_COLORS`](crate::vscode::WORKBENCH_COLORS), the first slot
+/// is the one the key represents and the rest are export fallbacks.
+pub const GLOBALS: [(&str, &[SlotRef]); 14] = [
+    ("background", &[("base", "background")]),
+    ("foreground", &[("base", "foreground")]),
+    ("caret", &[("editor", "cursor"), ("base", "foreground")]),
+    ("selection", &[("editor", "selection_bg"), ("surface", "selection")]),
+    ("selectionForeground", &[("editor", "selection_fg"), ("typography", "selection_text")]),
+    ("lineHighlight", &[("surface", "highlight"), ("base", "background_highlight")]),
+    ("findHighlight", &[("editor", "search_bg"), ("surface", "search")]),
+    ("findHighlightForeground", &[("editor", "search_fg")]),
+    ("gutter", &[("base", "background")]),
+    ("gutterForeground", &[("typography", "line_number"), ("typography", "gutter")]),
+    ("invisibles", &[("typography", "gutter")]),
+    ("guide", &[("base", "border")]),
+    ("activeGuide", &[("base", "border_highlight")]),
+    ("bracketsForeground", &[("editor", "match_paren")]),
+];
+
+/// [`GLOBALS`] resolved against a palette, skipping keys with no slot set.
+pub fn global_colors(palette: &Palette) -> Vec<(&'static str, Color)> {
+    GLOBALS
+        .iter()
+        .filter_map(|&(key, slots)| {
+            let (color, _) = palette.pick_slot(slots)?;
+            Some((key, color))
+        })
+        .collect()
+}
+
+pub(crate) fn style_for(background: Color) -> &'static str {
+    match background.is_light() {
+        true => "light",
+        false => "dark",
+    }
+}
+
+pub(crate) fn invalid_theme(format: &str, reason: String) -> PaletteError {
+    PaletteError::InvalidTheme {
+        format: Arc::from(format),
+        reason: Arc::from(reason),
+    }
+}
+
+/// Import a TextMate / Sublime `.tmTheme` plist.
+///
+/// The scope-less entry supplies [`GLOBALS`] (background, caret, ...);
+/// scoped entries map through [`scope_slot`]. Colors with alpha are
+/// composited over the theme background.
+pub fn from_tmtheme(xml: &str, id: &str) -> Result<PaletteManifest, PaletteError> {
+    let doc = plist::parse(xml).map_err(|reason| invalid_theme("tmTheme", reason))?;
+    let rules = doc.get("settings").map_or(&[][..], Value::as_array);
+    let globals = rules
+        .iter()
+        .find(|r| r.get("scope").is_none())
+        .and_then(|r| r.get("settings"));
+    let global = |key: &str, bg: Option<Color>| {
+        globals
+            .and_then(|g| g.get(key))
+            .and_then(Value::as_str)
+            .and_then(|c| parse_theme_color(c, bg))
+    };
+    let background = global("background", None)
+        .ok_or_else(|| invalid_theme("tmTheme", "no global `background` setting".to_owned()))?;
+
+    let name = doc.get("name").and_then(Value::as_str).unwrap_or(id);
+    let mut manifest = PaletteManifest::imported(Arc::from(name), id, style_for(background));
+    for (key, slots) in GLOBALS {
+        let Some(&(section, field)) = slots.first() else {
+            continue;
+        };
+        if let (Some(color), Some(slots)) = (global(key, Some(background)), manifest.section_mut(section)) {
+            slots.entry(Arc::from(field)).or_insert_with(|| Arc::from(color.to_hex()));
+        }
+    }
+
+    let mut scopes = ScopeImport::default();
+    for rule in rules.iter().filter(|r| r.get("scope").is_some()) {
+        scopes.add_rule(rule, Some(background));
+    }
+    scopes.apply(&mut manifest);
+    Ok(manifest)
+}
+
+/// A `.tmTheme` plist for TextMate, Sublime Text, bat, and other syntect
+/// users: [`GLOBALS`] in the scope-less first entry, then one entry per
+/// [`scope_rules`] rule.
+pub fn to_tmtheme(palette: &Palette) -> String {
+    let name = palette.meta.as_ref().map_or("palette", |m| &m.name);
+    let mut out = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+         <plist version=\"1.0\">\n<dict>\n\t<key>name</key>\n\t<string>{}</string>\n\t<key>settings</key>\n\t<array>\n",
+        plist::escape(name),
+    );
+    // String::write_fmt is infallible
+    out.push_str("\t\t<dict>\n\t\t\t<key>settings</key>\n\t\t\t<dict>\n");
+    for (key, color) in global_colors(palette) {
+        let _ = writeln!(out, "\t\t\t\t<key>{key}</key>\n\t\t\t\t<string>{color}</string>");
+    }
+    out.push_str("\t\t\t</dict>\n\t\t</dict>\n");
+    for rule in scope_rules(palette) {
+        let _ = write!(
+            out,
+            "\t\t<dict>\n\t\t\t<key>name</key>\n\t\t\t<string>{}.{}</string>\n\t\t\t<key>scope</key>\n\t\t\t\
+             <string>{}</string>\n\t\t\t<key>settings</key>\n\t\t\t<dict>\n\t\t\t\t<key>foreground</key>\n\t\t\t\t\
+             <string>{}</string>\n",
+            rule.slot.0,
+            rule.slot.1,
+            rule.scopes.join(", "),
+            rule.foreground,
+        );
+        if let Some(style) = &rule.style {
+            let _ = writeln!(out, "\t\t\t\t<key>fontStyle</key>\n\t\t\t\t<string>{}</string>", font_style(style));
+        }
+        out.push_str("\t\t\t</dict>\n\t\t</dict>\n");
+    }
+    out.push_str("\t</array>\n</dict>\n</plist>\n");
+    out
+}
+
+impl Palette {
+    /// See [`to_tmtheme`].
+    pub fn to_tmtheme(&self) -> String {
+        to_tmtheme(self)
+    }
+}
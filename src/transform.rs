@@ -0,0 +1,90 @@
+use crate::color::Color;
+use crate::manipulation::blend;
+use crate::palette::{CustomColors, Palette};
+
+const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+
+#[cfg(feature = "platform")]
+fn map_platform(
+    overrides: &crate::platform::PlatformOverrides,
+    f: &mut impl FnMut(&str, &str, Color) -> Color,
+) -> crate::platform::PlatformOverrides {
+    overrides
+        .iter()
+        .map(|(name, o)| {
+            let section = format!("platform.{name}");
+            let mapped = crate::platform::PlatformOverride {
+                background: o.background.map(|c| f(&section, "background", c)),
+                foreground: o.foreground.map(|c| f(&section, "foreground", c)),
+            };
+            (name.clone(), mapped)
+        })
+        .collect()
+}
+
+fn map_custom(custom: &CustomColors, f: &mut impl FnMut(&str, &str, Color) -> Color) -> CustomColors {
+    custom
+        .iter()
+        .map(|(name, section)| {
+            let mapped = section
+                .iter()
+                .map(|(field, c)| (field.clone(), f(name, field, *c)))
+                .collect();
+            (name.clone(), mapped)
+        })
+        .collect()
+}
+
+impl Palette {
+    /// Apply `f(section, field, color)` to every populated slot.
+    ///
+    /// Sections use manifest names (`base`, `syntax`, `terminal`, ...) and
+    /// `platform.<name>` for platform overrides. Unset slots stay unset;
+    /// `meta` and text styles are kept as-is.
+    pub fn map<F>(&self, mut f: F) -> Palette
+    where
+        F: FnMut(&str, &str, Color) -> Color,
+    {
+        Palette {
+            meta: self.meta.clone(),
+            base: self.base.map_slots("base", &mut f),
+            semantic: self.semantic.map_slots("semantic", &mut f),
+            diff: self.diff.map_slots("diff", &mut f),
+            surface: self.surface.map_slots("surface", &mut f),
+            typography: self.typography.map_slots("typography", &mut f),
+            syntax: self.syntax.map_slots("syntax", &mut f),
+            editor: self.editor.map_slots("editor", &mut f),
+            terminal_ansi: self.terminal_ansi.map_slots("terminal", &mut f),
+            styles: self.styles.clone(),
+            semantic_tokens: self.semantic_tokens.clone(),
+            custom: map_custom(&self.custom, &mut f),
+            #[cfg(feature = "platform")]
+            platform: map_platform(&self.platform, &mut f),
+        }
+    }
+
+    /// Mix `tint` into every slot by `amount` (`0.0` = unchanged, `1.0` = solid tint).
+    pub fn tinted(&self, tint: Color, amount: f64) -> Palette {
+        self.map(|_, _, c| blend(tint, c, amount))
+    }
+
+    /// Reduce HSL saturation of every slot by an absolute `amount`.
+    pub fn desaturated(&self, amount: f64) -> Palette {
+        self.map(|_, _, c| c.desaturate(amount))
+    }
+
+    /// Scale every slot toward black by `amount`, like lowering screen brightness.
+    pub fn dimmed(&self, amount: f64) -> Palette {
+        self.map(|_, _, c| blend(BLACK, c, amount))
+    }
+
+    /// Replace every slot with a neutral gray of the same perceived lightness.
+    pub fn grayscale(&self) -> Palette {
+        self.map(|_, _, c| c.grayscale())
+    }
+
+    /// Rotate the hue of every slot by `degrees`.
+    pub fn hue_rotated(&self, degrees: f64) -> Palette {
+        self.map(|_, _, c| c.rotate_hue(degrees))
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::contrast::SlotRef;
+use crate::error::PaletteError;
+use crate::json::{self, quote, Value};
+use crate::manifest::PaletteManifest;
+use crate::palette::Palette;
+use crate::semantic_tokens::semantic_token_slot;
+use crate::style::TextStyle;
+use crate::textmate::{font_style, invalid_theme, parse_theme_color, scope_rules, style_for, ScopeImport};
+
+/// Workbench color keys and the palette slots tried for each, in order.
+///
+/// The first slot listed is the one the key represents; the rest are
+/// fallbacks for sparse palettes. Keys whose slots are all unset are left
+/// out so VS Code uses its own default.
+pub const WORKBENCH_COLORS: &[(&str, &[SlotRef])] = &[
+    ("focusBorder", &[("surface", "focus"), ("base", "border_highlight")]),
+    ("foreground", &[("base", "foreground")]),
+    ("errorForeground", &[("semantic", "error")]),
+    ("textLink.foreground", &[("typography", "link")]),
+    ("textLink.activeForeground", &[("typography", "link")]),
+    // Editor
+    ("editor.background", &[("base", "background")]),
+    ("editor.foreground", &[("base", "foreground")]),
+    ("editorCursor.foreground", &[("editor", "cursor"), ("base", "foreground")]),
+    ("editorCursor.background", &[("editor", "cursor_text")]),
+    ("editor.selectionBackground", &[("editor", "selection_bg"), ("surface", "selection")]),
+    ("editor.selectionForeground", &[("editor", "selection_fg"), ("typography", "selection_text")]),
+    ("editor.lineHighlightBackground", &[("surface", "highlight"), ("base", "background_highlight")]),
+    ("editor.findMatchBackground", &[("editor", "search_bg"), ("surface", "search")]),
+    ("editor.findMatchForeground", &[("editor", "search_fg")]),
+    ("editor.findMatchHighlightBackground", &[("surface", "search"), ("editor", "search_bg")]),
+    ("editorBracketMatch.border", &[("editor", "match_paren")]),
+    ("editorLineNumber.foreground", &[("typography", "line_number"), ("typography", "gutter")]),
+    ("editorLineNumber.activeForeground", &[("base", "foreground")]),
+    ("editorWhitespace.foreground", &[("typography", "gutter")]),
+    ("editorLink.activeForeground", &[("typography", "link")]),
+    ("editorInlayHint.background", &[("editor", "inlay_hint_bg")]),
+    ("editorInlayHint.foreground", &[("editor", "inlay_hint_fg"), ("typography", "comment")]),
+    ("editorError.foreground", &[("editor", "diagnostic_underline_error"), ("editor", "diagnostic_error"), ("semantic", "error")]),
+    ("editorWarning.foreground", &[("editor", "diagnostic_underline_warn"), ("editor", "diagnostic_warn"), ("semantic", "warning")]),
+    ("editorInfo.foreground", &[("editor", "diagnostic_underline_info"), ("editor", "diagnostic_info"), ("semantic", "info")]),
+    ("editorHint.foreground", &[("editor", "diagnostic_underline_hint"), ("editor", "diagnostic_hint"), ("semantic", "hint")]),
+    ("editorGutter.background", &[("base", "background")]),
+    ("editorGutter.addedBackground", &[("diff", "added")]),
+    ("editorGutter.modifiedBackground", &[("diff", "modified")]),
+    ("editorGutter.deletedBackground", &[("diff", "removed")]),
+    ("editorWidget.background", &[("surface", "float"), ("surface", "popup")]),
+    ("editorHoverWidget.background", &[("surface", "float"), ("surface", "popup")]),
+    ("editorSuggestWidget.background", &[("surface", "menu"), ("surface", "popup")]),
+    ("editorSuggestWidget.selectedBackground", &[("surface", "selection")]),
+    ("editorGroup.border", &[("base", "border")]),
+    // Diff editor
+    ("diffEditor.insertedLineBackground", &[("diff", "added_bg")]),
+    ("diffEditor.removedLineBackground", &[("diff", "removed_bg")]),
+    ("diffEditor.insertedTextBackground", &[("diff", "text_bg"), ("diff", "added_bg")]),
+    ("diffEditor.removedTextBackground", &[("diff", "text_bg"), ("diff", "removed_bg")]),
+    // Workbench chrome
+    ("activityBar.background", &[("base", "background_dark"), ("surface", "sidebar")]),
+    ("activityBar.foreground", &[("base", "foreground")]),
+    ("sideBar.background", &[("surface", "sidebar"), ("base", "background_dark")]),
+    ("sideBar.foreground", &[("base", "foreground_dark"), ("base", "foreground")]),
+    ("sideBarTitle.foreground", &[("typography", "title")]),
+    ("statusBar.background", &[("surface", "statusline"), ("base", "background_dark")]),
+    ("statusBar.foreground", &[("base", "foreground_dark"), ("base", "foreground")]),
+    ("titleBar.activeBackground", &[("base", "background_dark")]),
+    ("titleBar.activeForeground", &[("base", "foreground")]),
+    ("tab.activeBackground", &[("base", "background")]),
+    ("tab.inactiveBackground", &[("base", "background_dark")]),
+    ("panel.background", &[("base", "background_dark"), ("base", "background")]),
+    ("panel.border", &[("base", "border")]),
+    ("input.background", &[("surface", "float")]),
+    ("dropdown.background", &[("surface", "menu")]),
+    ("list.activeSelectionBackground", &[("surface", "selection")]),
+    ("list.hoverBackground", &[("surface", "highlight")]),
+    ("gitDecoration.addedResourceForeground", &[("diff", "added")]),
+    ("gitDecoration.modifiedResourceForeground", &[("diff", "modified")]),
+    ("gitDecoration.deletedResourceForeground", &[("diff", "removed")]),
+    ("gitDecoration.ignoredResourceForeground", &[("diff", "ignored")]),
+    // Integrated terminal
+    ("terminal.background", &[("base", "background")]),
+    ("terminal.foreground", &[("base", "foreground")]),
+    ("terminalCursor.foreground", &[("editor", "cursor")]),
+    ("terminal.selectionBackground", &[("editor", "selection_bg"), ("surface", "selection")]),
+    ("terminal.ansiBlack", &[("terminal", "black")]),
+    ("terminal.ansiRed", &[("terminal", "red")]),
+    ("terminal.ansiGreen", &[("terminal", "green")]),
+    ("terminal.ansiYellow", &[("terminal", "yellow")]),
+    ("terminal.ansiBlue", &[("terminal", "blue")]),
+    ("terminal.ansiMagenta", &[("terminal", "magenta")]),
+    ("terminal.ansiCyan", &[("terminal", "cyan")]),
+    ("terminal.ansiWhite", &[("terminal", "white")]),
+    ("terminal.ansiBrightBlack", &[("terminal", "bright_black")]),
+    ("terminal.ansiBrightRed", &[("terminal", "bright_red")]),
+    ("terminal.ansiBrightGreen", &[("terminal", "bright_green")]),
+    ("terminal.ansiBrightYellow", &[("terminal", "bright_yellow")]),
+    ("terminal.ansiBrightBlue", &[("terminal", "bright_blue")]),
+    ("terminal.ansiBrightMagenta", &[("terminal", "bright_magenta")]),
+    ("terminal.ansiBrightCyan", &[("terminal", "bright_cyan")]),
+    ("terminal.ansiBrightWhite", &[("terminal", "bright_white")]),
+];
+
+/// Semantic token value: a bare color, or an object when the slot is styled.
+fn semantic_value(color: &Color, style: Option<&TextStyle>) -> String {
+    let Some(style) = style.filter(|s| !s.is_plain()) else {
+        return format!("\"{color}\"");
+    };
+    let flags = [
+        ("bold", style.bold),
+        ("italic", style.italic),
+        ("underline", style.underline || style.undercurl),
+        ("strikethrough", style.strikethrough),
+    ];
+    let mut fields = vec![format!("\"foreground\": \"{color}\"")];
+    fields.extend(flags.iter().filter(|(_, set)| *set).map(|(name, _)| format!("\"{name}\": true")));
+    format!("{{ {} }}", fields.join(", "))
+}
+
+/// A complete `*-color-theme.json` for VS Code and Monaco.
+///
+/// `colors` comes from [`WORKBENCH_COLORS`], `tokenColors` from the
+/// [`SCOPES`](crate::textmate::SCOPES) table, and `semanticTokenColors` from
+/// [`Palette::semantic_token_rules`].
+pub fn to_vscode_theme(palette: &Palette) -> String {
+    let name = palette.meta.as_ref().map_or("palette", |m| &m.name);
+    let kind = match palette.base.background.is_some_and(|bg| bg.is_light()) {
+        true => "light",
+        false => "dark",
+    };
+
+    let colors: Vec<String> = WORKBENCH_COLORS
+        .iter()
+        .filter_map(|(key, slots)| palette.pick_slot(slots).map(|(c, _)| format!("    \"{key}\": \"{c}\"")))
+        .collect();
+
+    let tokens: Vec<String> = scope_rules(palette)
+        .iter()
+        .map(|rule| {
+            let scopes: Vec<String> = rule.scopes.iter().map(|s| quote(s)).collect();
+            let mut settings = format!("\"foreground\": \"{}\"", rule.foreground);
+            if let Some(style) = &rule.style {
+                // String::write_fmt is infallible
+                let _ = write!(settings, ", \"fontStyle\": {}", quote(&font_style(style)));
+            }
+            format!(
+                "    {{\n      \"name\": \"{}.{}\",\n      \"scope\": [{}],\n      \"settings\": {{ {settings} }}\n    }}",
+                rule.slot.0,
+                rule.slot.1,
+                scopes.join(", "),
+            )
+        })
+        .collect();
+
+    let semantic: Vec<String> = palette
+        .semantic_token_rules()
+        .into_iter()
+        .filter_map(|(selector, slot)| {
+            let color = palette.syntax.get(slot)?;
+            let value = semantic_value(color, palette.styles.syntax.get(slot));
+            Some(format!("    {}: {value}", quote(selector)))
+        })
+        .collect();
+
+    format!(
+        "{{\n  \"$schema\": \"vscode://schemas/color-theme\",\n  \"name\": {},\n  \"type\": \"{kind}\",\n  \
+         \"semanticHighlighting\": true,\n  \"colors\": {{\n{}\n  }},\n  \"tokenColors\": [\n{}\n  ],\n  \
+         \"semanticTokenColors\": {{\n{}\n  }}\n}}\n",
+        quote(name),
+        colors.join(",\n"),
+        tokens.join(",\n"),
+        semantic.join(",\n"),
+    )
+}
+
+/// Whether `contents` parses as a VS Code color theme: a JSON object with
+/// `colors` or `tokenColors`.
+pub fn is_vscode_theme(contents: &str) -> bool {
+    json::parse(contents).is_ok_and(|doc| is_vscode_document(&doc))
+}
+
+pub(crate) fn is_vscode_document(doc: &Value) -> bool {
+    doc.get("colors").is_some() || doc.get("tokenColors").is_some()
+}
+
+/// A semantic token value: a color string or `{ "foreground": ..., "bold": true }`.
+fn semantic_token(value: &Value, background: Color) -> Option<(Color, TextStyle)> {
+    let (color, flags) = match value {
+        Value::String(color) => (color.as_str(), value),
+        _ => (value.get("foreground")?.as_str()?, value),
+    };
+    let flag = |name: &str| matches!(flags.get(name), Some(Value::Bool(true)));
+    let style = TextStyle {
+        bold: flag("bold"),
+        italic: flag("italic"),
+        underline: flag("underline"),
+        strikethrough: flag("strikethrough"),
+        ..TextStyle::default()
+    };
+    Some((parse_theme_color(color, Some(background))?, style))
+}
+
+/// Import a VS Code `*-color-theme.json`. Comments and trailing commas are
+/// accepted, as VS Code accepts them.
+///
+/// Workbench `colors` map back through [`WORKBENCH_COLORS`] to each key's
+/// primary slot (the first key seen wins), `tokenColors` through
+/// [`scope_slot`](crate::textmate::scope_slot), and `semanticTokenColors`
+/// fill any `syntax` slot still unset. `#RRGGBBAA` colors are composited
+/// over `editor.background`; values that aren't colors are skipped.
+pub fn from_vscode_theme(contents: &str, id: &str) -> Result<PaletteManifest, PaletteError> {
+    let doc = json::parse(contents).map_err(|reason| invalid_theme("vscode", reason))?;
+    vscode_manifest(&doc, id)
+}
+
+/// [`from_vscode_theme`] for an already parsed document.
+pub(crate) fn vscode_manifest(doc: &Value, id: &str) -> Result<PaletteManifest, PaletteError> {
+    let colors = doc.get("colors").map_or(&[][..], Value::entries);
+    let background = colors
+        .iter()
+        .find(|(key, _)| key == "editor.background")
+        .and_then(|(_, value)| parse_theme_color(value.as_str()?, None))
+        .ok_or_else(|| invalid_theme("vscode", "no `editor.background` color".to_owned()))?;
+    let style = match doc.get("type").and_then(Value::as_str) {
+        Some("light" | "hcLight") => "light",
+        Some("dark" | "hc" | "hcDark") => "dark",
+        _ => style_for(background),
+    };
+    let name = doc.get("name").and_then(Value::as_str).unwrap_or(id);
+    let mut manifest = PaletteManifest::imported(Arc::from(name), id, style);
+
+    for (key, value) in colors {
+        let Some(&(section, field)) = WORKBENCH_COLORS.iter().find(|(k, _)| k == key).and_then(|(_, slots)| slots.first())
+        else {
+            continue;
+        };
+        let color = value.as_str().and_then(|v| parse_theme_color(v, Some(background)));
+        if let (Some(color), Some(slots)) = (color, manifest.section_mut(section)) {
+            slots.entry(Arc::from(field)).or_insert_with(|| Arc::from(color.to_hex()));
+        }
+    }
+
+    let mut scopes = ScopeImport::default();
+    for rule in doc.get("tokenColors").map_or(&[][..], Value::as_array) {
+        scopes.add_rule(rule, Some(background));
+    }
+    scopes.apply(&mut manifest);
+
+    for (selector, value) in doc.get("semanticTokenColors").map_or(&[][..], Value::entries) {
+        let selector = selector.split(':').next().unwrap_or_default();
+        let mut parts = selector.split('.');
+        let token_type = parts.next().unwrap_or_default();
+        let modifiers: Vec<&str> = parts.collect();
+        let Some(slot) = semantic_token_slot(token_type, &modifiers) else {
+            continue;
+        };
+        let Some((color, style)) = semantic_token(value, background).filter(|_| !manifest.syntax.contains_key(slot))
+        else {
+            continue;
+        };
+        manifest.syntax.insert(Arc::from(slot), Arc::from(color.to_hex()));
+        if !style.is_plain() {
+            manifest.styles.entry(Arc::from("syntax")).or_default().insert(Arc::from(slot), style);
+        }
+    }
+    Ok(manifest)
+}
+
+impl Palette {
+    /// See [`to_vscode_theme`].
+    pub fn to_vscode_theme(&self) -> String {
+        to_vscode_theme(self)
+    }
+}
@@ -146,6 +146,26 @@ impl JsPalette {
         crate::snapshot::to_json(&self.inner).map_err(to_js_error)
     }
 
+    /// Accessibility variant meeting `level` (`"aa"`, `"aaa"`, ...).
+    #[wasm_bindgen(js_name = "highContrast")]
+    pub fn high_contrast(&self, level: &str) -> Result<JsPalette, JsValue> {
+        let level = parse_contrast_level(level)?;
+        Ok(JsPalette {
+            inner: self.inner.high_contrast(level),
+        })
+    }
+
+    /// Changes from `self` to `other` as JSON (`{ meta: [...], slots: [...] }`).
+    pub fn diff(&self, other: &JsPalette) -> Result<String, JsValue> {
+        serde_json::to_string(&self.inner.diff(&other.inner)).map_err(to_js_error)
+    }
+
+    /// Changes from `self` to `other` as a plain-text table.
+    #[wasm_bindgen(js_name = "diffReport")]
+    pub fn diff_report(&self, other: &JsPalette) -> String {
+        self.inner.diff(&other.inner).to_string()
+    }
+
     #[wasm_bindgen(js_name = "baseSlots")]
     pub fn base_slots(&self) -> js_sys::Map {
         slots_to_js_map(self.inner.base.populated_slots())
@@ -238,6 +258,14 @@ pub fn blend_js(fg: &JsColor, bg: &JsColor, alpha: f64) -> JsColor {
     }
 }
 
+/// Blend palette `a` toward `b` in OKLab, `t` in `[0, 1]`.
+#[wasm_bindgen(js_name = "interpolate")]
+pub fn interpolate_js(a: &JsPalette, b: &JsPalette, t: f64) -> JsPalette {
+    JsPalette {
+        inner: Palette::interpolate(&a.inner, &b.inner, t),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Registry wrappers
 // ---------------------------------------------------------------------------
@@ -0,0 +1,167 @@
+use crate::color::Color;
+use crate::contrast::SlotRef;
+use crate::json::quote;
+use crate::palette::Palette;
+use crate::style::TextStyle;
+
+/// Zed theme `style` keys and the palette slots tried for each, in order.
+///
+/// Like [`WORKBENCH_COLORS`](crate::vscode::WORKBENCH_COLORS), the first slot
+/// is the one the key represents and the rest are fallbacks. The
+/// `terminal.ansi.*` keys are added from the ANSI slots directly.
+pub const STYLE_COLORS: &[(&str, &[SlotRef])] = &[
+    ("background", &[("base", "background_dark"), ("base", "background")]),
+    ("border", &[("base", "border")]),
+    ("border.variant", &[("base", "border")]),
+    ("border.focused", &[("surface", "focus"), ("base", "border_highlight")]),
+    ("border.selected", &[("base", "border_highlight"), ("surface", "focus")]),
+    ("elevated_surface.background", &[("surface", "float"), ("surface", "popup")]),
+    ("surface.background", &[("surface", "sidebar"), ("base", "background_dark")]),
+    ("element.background", &[("base", "background_highlight"), ("surface", "highlight")]),
+    ("element.hover", &[("surface", "highlight"), ("base", "background_highlight")]),
+    ("element.selected", &[("surface", "selection"), ("editor", "selection_bg")]),
+    ("ghost_element.hover", &[("surface", "highlight"), ("base", "background_highlight")]),
+    ("ghost_element.selected", &[("surface", "selection"), ("editor", "selection_bg")]),
+    ("text", &[("base", "foreground")]),
+    ("text.muted", &[("base", "foreground_dark"), ("typography", "comment")]),
+    ("text.placeholder", &[("typography", "comment")]),
+    ("text.accent", &[("typography", "link"), ("surface", "focus")]),
+    ("icon", &[("base", "foreground")]),
+    ("icon.muted", &[("base", "foreground_dark"), ("typography", "comment")]),
+    ("status_bar.background", &[("surface", "statusline"), ("base", "background_dark")]),
+    ("title_bar.background", &[("base", "background_dark")]),
+    ("toolbar.background", &[("base", "background")]),
+    ("tab_bar.background", &[("base", "background_dark")]),
+    ("tab.inactive_background", &[("base", "background_dark")]),
+    ("tab.active_background", &[("base", "background")]),
+    ("panel.background", &[("surface", "sidebar"), ("base", "background_dark")]),
+    ("search.match_background", &[("surface", "search"), ("editor", "search_bg")]),
+    // Editor
+    ("editor.background", &[("base", "background")]),
+    ("editor.foreground", &[("base", "foreground")]),
+    ("editor.gutter.background", &[("base", "background")]),
+    ("editor.active_line.background", &[("surface", "highlight"), ("base", "background_highlight")]),
+    ("editor.highlighted_line.background", &[("base", "background_highlight"), ("surface", "highlight")]),
+    ("editor.line_number", &[("typography", "line_number"), ("typography", "gutter")]),
+    ("editor.active_line_number", &[("base", "foreground")]),
+    ("editor.invisible", &[("typography", "gutter")]),
+    ("editor.wrap_guide", &[("base", "border")]),
+    ("editor.active_wrap_guide", &[("base", "border_highlight")]),
+    ("editor.document_highlight.read_background", &[("surface", "highlight")]),
+    // Version control and diagnostics
+    ("created", &[("diff", "added")]),
+    ("created.background", &[("diff", "added_bg")]),
+    ("modified", &[("diff", "modified")]),
+    ("modified.background", &[("diff", "modified_bg")]),
+    ("deleted", &[("diff", "removed")]),
+    ("deleted.background", &[("diff", "removed_bg")]),
+    ("ignored", &[("diff", "ignored")]),
+    ("error", &[("semantic", "error")]),
+    ("warning", &[("semantic", "warning")]),
+    ("info", &[("semantic", "info")]),
+    ("hint", &[("semantic", "hint")]),
+    ("success", &[("semantic", "success")]),
+    // Terminal
+    ("terminal.background", &[("base", "background")]),
+    ("terminal.foreground", &[("base", "foreground")]),
+];
+
+/// Zed `syntax` highlight names and the slots tried for each, in order.
+pub const SYNTAX: &[(&str, &[SlotRef])] = &[
+    ("attribute", &[("syntax", "attributes"), ("syntax", "annotations")]),
+    ("boolean", &[("syntax", "booleans")]),
+    ("comment", &[("syntax", "comments")]),
+    ("comment.doc", &[("syntax", "strings_doc"), ("syntax", "comments")]),
+    ("constant", &[("syntax", "constants")]),
+    ("constructor", &[("syntax", "constructor")]),
+    ("enum", &[("syntax", "types")]),
+    ("function", &[("syntax", "functions")]),
+    ("keyword", &[("syntax", "keywords")]),
+    ("link_text", &[("typography", "link")]),
+    ("link_uri", &[("typography", "link")]),
+    ("number", &[("syntax", "numbers")]),
+    ("operator", &[("syntax", "operators")]),
+    ("preproc", &[("syntax", "attributes")]),
+    ("property", &[("syntax", "properties")]),
+    ("punctuation", &[("syntax", "punctuation")]),
+    ("punctuation.bracket", &[("syntax", "punctuation_bracket")]),
+    ("punctuation.delimiter", &[("syntax", "punctuation")]),
+    ("string", &[("syntax", "strings")]),
+    ("string.escape", &[("syntax", "strings_escape")]),
+    ("string.regex", &[("syntax", "strings_regex")]),
+    ("tag", &[("syntax", "tag")]),
+    ("title", &[("typography", "title")]),
+    ("type", &[("syntax", "types")]),
+    ("variable", &[("syntax", "variables")]),
+    ("variable.special", &[("syntax", "variables_builtin")]),
+];
+
+/// A `syntax` entry: `color`, plus `font_style` / `font_weight` when styled.
+fn syntax_value(color: Color, style: Option<TextStyle>) -> String {
+    let mut fields = vec![format!("\"color\": \"{color}\"")];
+    if let Some(style) = style {
+        if style.italic {
+            fields.push("\"font_style\": \"italic\"".to_owned());
+        }
+        if style.bold {
+            fields.push("\"font_weight\": 700".to_owned());
+        }
+    }
+    format!("{{ {} }}", fields.join(", "))
+}
+
+/// A Zed theme family file for `~/.config/zed/themes/`, holding one theme.
+///
+/// `style` comes from [`STYLE_COLORS`] plus `terminal.ansi.*`, `players`
+/// from the cursor and selection pairs, and `syntax` from [`SYNTAX`].
+pub fn to_zed_theme(palette: &Palette) -> String {
+    let name = quote(palette.meta.as_ref().map_or("palette", |m| &m.name));
+    let appearance = match palette.base.background.is_some_and(|bg| bg.is_light()) {
+        true => "light",
+        false => "dark",
+    };
+
+    let ansi = palette
+        .terminal_ansi
+        .slots()
+        .filter_map(|(field, color)| Some((format!("terminal.ansi.{field}"), *color?)));
+    let mut style: Vec<String> = STYLE_COLORS
+        .iter()
+        .filter_map(|(key, slots)| palette.pick_slot(slots).map(|(c, _)| ((*key).to_owned(), c)))
+        .chain(ansi)
+        .map(|(key, color)| format!("        \"{key}\": \"{color}\""))
+        .collect();
+
+    let cursor = palette.cursor_pair();
+    let selection = palette.selection_pair();
+    let player: Vec<String> = [("cursor", cursor.bg), ("background", cursor.bg), ("selection", selection.bg)]
+        .iter()
+        .filter_map(|(key, color)| color.map(|c| format!("\"{key}\": \"{c}\"")))
+        .collect();
+    if !player.is_empty() {
+        style.push(format!("        \"players\": [{{ {} }}]", player.join(", ")));
+    }
+
+    let syntax: Vec<String> = SYNTAX
+        .iter()
+        .filter_map(|(key, slots)| {
+            let (color, text_style) = palette.pick_slot(slots)?;
+            Some(format!("          \"{key}\": {}", syntax_value(color, text_style)))
+        })
+        .collect();
+    style.push(format!("        \"syntax\": {{\n{}\n        }}", syntax.join(",\n")));
+
+    format!(
+        "{{\n  \"$schema\": \"https://zed.dev/schema/themes/v0.2.0.json\",\n  \"name\": {name},\n  \
+         \"author\": \"palette-core\",\n  \"themes\": [\n    {{\n      \"name\": {name},\n      \
+         \"appearance\": \"{appearance}\",\n      \"style\": {{\n{}\n      }}\n    }}\n  ]\n}}\n",
+        style.join(",\n"),
+    )
+}
+
+impl Palette {
+    /// See [`to_zed_theme`].
+    pub fn to_zed_theme(&self) -> String {
+        to_zed_theme(self)
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "anstyle")]
+
+use anstyle::{Color as AnstyleColor, Effects, RgbColor};
+
+use palette_core::anstyle::{slot_style, to_anstyle_color, to_anstyle_effects, to_anstyle_theme, to_cli_styles};
+use palette_core::color::Color;
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::style::TextStyle;
+
+mod common;
+
+fn rgb(color: Option<Color>) -> Option<AnstyleColor> {
+    color.as_ref().map(to_anstyle_color)
+}
+
+#[test]
+fn single_color_converts_rgb() {
+    let color = Color { r: 26, g: 27, b: 42 };
+    assert_eq!(to_anstyle_color(&color), AnstyleColor::Rgb(RgbColor(26, 27, 42)));
+}
+
+#[test]
+fn text_styles_map_to_effects() {
+    let style = TextStyle { bold: true, undercurl: true, ..TextStyle::default() };
+    assert_eq!(to_anstyle_effects(&style), Effects::BOLD | Effects::CURLY_UNDERLINE);
+    assert_eq!(to_anstyle_effects(&TextStyle::default()), Effects::new());
+}
+
+#[test]
+fn theme_matches_palette() {
+    let palette = Palette::from_manifest(&common::load_preset("tokyonight")).unwrap();
+    let theme = to_anstyle_theme(&palette);
+
+    assert_eq!(theme.base.background, Some(AnstyleColor::Rgb(RgbColor(26, 27, 42))));
+    assert_eq!(theme.syntax.keywords, rgb(palette.syntax.keywords));
+    assert_eq!(theme.effects.syntax.keywords, Effects::new());
+}
+
+#[test]
+fn slot_style_combines_color_and_effects() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#000000"
+
+[syntax]
+comments = { fg = "#565F89", italic = true }
+"##,
+    )
+    .unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let style = slot_style(&palette, "syntax", "comments");
+
+    assert_eq!(style.get_fg_color(), Some(AnstyleColor::Rgb(RgbColor(0x56, 0x5F, 0x89))));
+    assert_eq!(style.get_effects(), Effects::ITALIC);
+    assert_eq!(slot_style(&palette, "syntax", "keywords"), anstyle::Style::new());
+}
+
+#[test]
+fn cli_styles_use_semantic_roles() {
+    let palette = Palette::from_manifest(&common::load_preset("tokyonight")).unwrap();
+    let styles = to_cli_styles(&palette);
+
+    assert_eq!(styles.error.get_fg_color(), rgb(palette.semantic.error));
+    assert!(styles.error.get_effects().contains(Effects::BOLD));
+    assert_eq!(styles.valid.get_fg_color(), rgb(palette.semantic.success));
+    assert_eq!(styles.invalid.get_fg_color(), rgb(palette.semantic.warning));
+    assert_eq!(styles.usage, styles.header);
+    assert!(styles.literal.get_effects().contains(Effects::BOLD));
+}
+
+#[test]
+fn cli_styles_fall_back_on_sparse_palette() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#000000"
+foreground_dark = "#888888"
+
+[syntax]
+keywords = "#BB9AF7"
+variables = "#C0CAF5"
+"##,
+    )
+    .unwrap();
+    let styles = to_cli_styles(&Palette::from_manifest(&manifest).unwrap());
+    let keywords = Some(AnstyleColor::Rgb(RgbColor(0xBB, 0x9A, 0xF7)));
+
+    assert_eq!(styles.header.get_fg_color(), keywords);
+    assert_eq!(styles.literal.get_fg_color(), keywords);
+    assert_eq!(styles.placeholder.get_fg_color(), Some(AnstyleColor::Rgb(RgbColor(0xC0, 0xCA, 0xF5))));
+    assert_eq!(styles.context.get_fg_color(), Some(AnstyleColor::Rgb(RgbColor(0x88, 0x88, 0x88))));
+    assert_eq!(styles.error.get_fg_color(), None);
+}
@@ -0,0 +1,153 @@
+use palette_core::base16::Base16Scheme;
+use palette_core::color::Color;
+use palette_core::error::PaletteError;
+use palette_core::{preset, Registry};
+
+const CLASSIC: &str = r#"scheme: "Tomorrow Night"
+author: "Chris Kempson (http://chriskempson.com)"
+base00: "1d1f21"
+base01: "282a2e"
+base02: "373b41"
+base03: "969896"
+base04: "b4b7b4"
+base05: "c5c8c6"
+base06: "e0e0e0"
+base07: "ffffff"
+base08: "cc6666"
+base09: "de935f"
+base0A: "f0c674"
+base0B: "b5bd68"
+base0C: "8abeb7"
+base0D: "81a2be"
+base0E: "b294bb"
+base0F: "a3685a"
+"#;
+
+fn tinted_base24() -> String {
+    let mut yaml = String::from(
+        "system: \"base24\"\nname: \"Test 24\"\nauthor: \"\"\nvariant: \"light\" # comment\npalette:\n",
+    );
+    for i in 0..24 {
+        yaml.push_str(&format!("  base{i:02X}: \"#{:02x}{:02x}{:02x}\"\n", i * 10, i * 10, i * 10));
+    }
+    yaml
+}
+
+fn hex(s: &str) -> Color {
+    Color::from_hex(s).unwrap()
+}
+
+#[test]
+fn parses_classic_layout() {
+    let scheme = Base16Scheme::from_yaml(CLASSIC).unwrap();
+    assert_eq!(&*scheme.name, "Tomorrow Night");
+    assert_eq!(scheme.base16[0x0A], hex("#F0C674"));
+    assert!(!scheme.is_base24());
+    assert_eq!(scheme.style(), "dark");
+    assert_eq!(scheme.slug(), "tomorrow_night");
+}
+
+#[test]
+fn parses_tinted_base24_layout() {
+    let scheme = Base16Scheme::from_yaml(&tinted_base24()).unwrap();
+    assert!(scheme.is_base24());
+    assert_eq!(scheme.color(0x17), Color { r: 230, g: 230, b: 230 });
+    assert_eq!(scheme.style(), "light");
+    assert_eq!(scheme.author, None);
+}
+
+#[test]
+fn base24_slots_fall_back_on_base16() {
+    let scheme = Base16Scheme::from_yaml(CLASSIC).unwrap();
+    assert_eq!(scheme.color(0x10), scheme.color(0x00));
+    assert_eq!(scheme.color(0x12), scheme.color(0x08));
+    assert_eq!(scheme.color(0x17), scheme.color(0x0E));
+}
+
+#[test]
+fn missing_slot_is_an_error() {
+    let yaml = CLASSIC.replace("base0F: \"a3685a\"\n", "");
+    match Base16Scheme::from_yaml(&yaml) {
+        Err(PaletteError::MissingSlot { field, .. }) => assert_eq!(&*field, "base0F"),
+        other => panic!("expected MissingSlot, got {other:?}"),
+    }
+}
+
+#[test]
+fn invalid_hex_names_the_slot() {
+    let yaml = CLASSIC.replace("b5bd68", "b5bd6");
+    assert!(matches!(
+        Base16Scheme::from_yaml(&yaml),
+        Err(PaletteError::InvalidHex { field, .. }) if &*field == "base0B"
+    ));
+}
+
+#[test]
+fn palette_follows_styling_guidelines() {
+    let palette = Base16Scheme::from_yaml(CLASSIC).unwrap().to_palette().unwrap();
+
+    assert_eq!(palette.base.background, Some(hex("#1D1F21")));
+    assert_eq!(palette.base.foreground, Some(hex("#C5C8C6")));
+    assert_eq!(palette.editor.selection_bg, Some(hex("#373B41")));
+    assert_eq!(palette.syntax.comments, Some(hex("#969896")));
+    assert_eq!(palette.syntax.keywords, Some(hex("#B294BB")));
+    assert_eq!(palette.syntax.strings, Some(hex("#B5BD68")));
+    assert_eq!(palette.syntax.functions, Some(hex("#81A2BE")));
+    assert_eq!(palette.diff.removed, Some(hex("#CC6666")));
+    assert_eq!(palette.terminal_ansi.bright_white, Some(hex("#FFFFFF")));
+    assert_eq!(palette.terminal_ansi.bright_red, palette.terminal_ansi.red);
+
+    let added_bg = palette.diff.added_bg.unwrap();
+    assert_ne!(added_bg, palette.base.background.unwrap());
+    assert!(added_bg.relative_luminance() < 0.18);
+}
+
+#[test]
+fn export_picks_matching_slots() {
+    let palette = preset("tokyonight").unwrap();
+    let scheme = palette.to_base16();
+
+    assert_eq!(scheme.base16[0x00], palette.base.background.unwrap());
+    assert_eq!(scheme.base16[0x02], palette.editor.selection_bg.unwrap());
+    assert_eq!(scheme.base16[0x05], palette.base.foreground.unwrap());
+    assert_eq!(scheme.base16[0x0B], palette.syntax.strings.unwrap());
+    assert_eq!(scheme.base16[0x0E], palette.syntax.keywords.unwrap());
+    assert_eq!(scheme.base16[0x07], palette.terminal_ansi.bright_white.unwrap());
+    assert_eq!(scheme.variant.as_deref(), Some("dark"));
+    assert!(scheme.base24.is_none());
+}
+
+#[test]
+fn base24_export_round_trips_through_yaml() {
+    let palette = preset("catppuccin_latte").unwrap();
+    let scheme = palette.to_base24();
+    let parsed = Base16Scheme::from_yaml(&scheme.to_yaml()).unwrap();
+
+    assert_eq!(parsed, scheme);
+    assert_eq!(parsed.style(), "light");
+    assert_eq!(parsed.color(0x12), palette.terminal_ansi.bright_red.unwrap());
+}
+
+#[test]
+fn every_preset_exports_a_valid_scheme() {
+    for id in palette_core::preset_ids() {
+        let scheme = preset(id).unwrap().to_base24();
+        let parsed = Base16Scheme::from_yaml(&scheme.to_yaml()).unwrap();
+        assert!(parsed.to_palette().is_ok(), "{id}");
+    }
+}
+
+#[test]
+fn registry_accepts_schemes() {
+    let mut reg = Registry::new();
+    reg.add_base16(CLASSIC).unwrap();
+    let info = reg.list().find(|t| &*t.id == "tomorrow_night").unwrap();
+    assert_eq!(&*info.name, "Tomorrow Night");
+    assert_eq!(&*info.style, "dark");
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("test-24.yaml"), tinted_base24()).unwrap();
+    reg.add_dir(dir.path()).unwrap();
+    let palette = reg.load("test-24").unwrap();
+    assert_eq!(palette.base.background_dark, Some(Color { r: 160, g: 160, b: 160 }));
+}
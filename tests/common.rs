@@ -14,6 +14,7 @@ pub fn load_preset(name: &str) -> PaletteManifest {
 pub fn manifest_with_base(base: BTreeMap<Arc<str>, Arc<str>>) -> PaletteManifest {
     PaletteManifest {
         meta: None,
+        include: Vec::new(),
         base,
         semantic: BTreeMap::new(),
         diff: BTreeMap::new(),
@@ -22,6 +23,9 @@ pub fn manifest_with_base(base: BTreeMap<Arc<str>, Arc<str>>) -> PaletteManifest
         syntax: BTreeMap::new(),
         editor: BTreeMap::new(),
         terminal: BTreeMap::new(),
+        styles: BTreeMap::new(),
+        semantic_tokens: BTreeMap::new(),
+        custom: BTreeMap::new(),
         #[cfg(feature = "platform")]
         platform: BTreeMap::new(),
     }
@@ -140,6 +140,7 @@ fn bad_palette_produces_violations() {
     base.insert(Arc::from("background"), Arc::from("#121212"));
     let manifest = PaletteManifest {
         meta: None,
+        include: Vec::new(),
         base,
         semantic: BTreeMap::new(),
         diff: BTreeMap::new(),
@@ -148,6 +149,9 @@ fn bad_palette_produces_violations() {
         syntax: BTreeMap::new(),
         editor: BTreeMap::new(),
         terminal: BTreeMap::new(),
+        styles: BTreeMap::new(),
+        semantic_tokens: BTreeMap::new(),
+        custom: BTreeMap::new(),
         #[cfg(feature = "platform")]
         platform: BTreeMap::new(),
     };
@@ -166,6 +170,7 @@ fn bad_palette_produces_violations() {
 fn none_fields_skipped_without_error() {
     let manifest = PaletteManifest {
         meta: None,
+        include: Vec::new(),
         base: BTreeMap::new(),
         semantic: BTreeMap::new(),
         diff: BTreeMap::new(),
@@ -174,6 +179,9 @@ fn none_fields_skipped_without_error() {
         syntax: BTreeMap::new(),
         editor: BTreeMap::new(),
         terminal: BTreeMap::new(),
+        styles: BTreeMap::new(),
+        semantic_tokens: BTreeMap::new(),
+        custom: BTreeMap::new(),
         #[cfg(feature = "platform")]
         platform: BTreeMap::new(),
     };
@@ -181,3 +189,83 @@ fn none_fields_skipped_without_error() {
     let violations = validate_palette(&palette, ContrastLevel::AaNormal);
     assert!(violations.is_empty(), "empty palette should produce no violations");
 }
+
+// --- ensure_contrast ---
+
+#[test]
+fn ensure_contrast_keeps_passing_color() {
+    let fg = color("#FFFFFF");
+    let bg = color("#000000");
+    assert_eq!(fg.ensure_contrast(&bg, ContrastLevel::AaaNormal), fg);
+}
+
+#[test]
+fn ensure_contrast_lightens_on_dark_background() {
+    let fg = color("#565f89");
+    let bg = color("#1a1b26");
+    let adjusted = fg.ensure_contrast(&bg, ContrastLevel::AaaNormal);
+    assert!(meets_level(&adjusted, &bg, ContrastLevel::AaaNormal));
+    assert!(adjusted.relative_luminance() > fg.relative_luminance());
+    // Minimal push: barely over the threshold
+    assert!(contrast_ratio(&adjusted, &bg) < 7.3);
+}
+
+#[test]
+fn ensure_contrast_darkens_on_light_background() {
+    let fg = color("#a0a0a0");
+    let bg = color("#FFFFFF");
+    let adjusted = fg.ensure_contrast(&bg, ContrastLevel::AaNormal);
+    assert!(meets_level(&adjusted, &bg, ContrastLevel::AaNormal));
+    assert!(adjusted.relative_luminance() < fg.relative_luminance());
+}
+
+#[test]
+fn ensure_contrast_falls_back_to_black_or_white() {
+    // Nothing reaches 7:1 against mid-gray except by going to an extreme
+    let bg = color("#767676");
+    let adjusted = color("#777777").ensure_contrast(&bg, ContrastLevel::AaaNormal);
+    assert!(adjusted == color("#000000") || adjusted == color("#FFFFFF"));
+}
+
+// --- high_contrast ---
+
+#[test]
+fn high_contrast_variants_pass_aaa_for_all_presets() {
+    for id in palette_core::registry::preset_ids() {
+        let palette = palette_core::registry::load_preset(id).unwrap();
+        let hc = palette.high_contrast(ContrastLevel::AaaNormal);
+        let violations = validate_palette(&hc, ContrastLevel::AaaNormal);
+        assert!(violations.is_empty(), "{id}: {violations:?}");
+    }
+}
+
+#[test]
+fn high_contrast_removes_faint_diff_tints() {
+    let palette = palette_core::registry::load_preset("catppuccin").unwrap();
+    assert!(palette.diff.added_bg.is_some());
+
+    let hc = palette.high_contrast(ContrastLevel::AaaNormal);
+    assert!(hc.diff.added_bg.is_none());
+    let bg = hc.base.background.unwrap();
+    assert!(meets_level(&hc.diff.added_fg.unwrap(), &bg, ContrastLevel::AaaNormal));
+}
+
+#[test]
+fn high_contrast_strengthens_borders() {
+    let palette = palette_core::registry::load_preset("catppuccin").unwrap();
+    let hc = palette.high_contrast(ContrastLevel::AaaNormal);
+    let bg = hc.base.background.unwrap();
+    assert!(contrast_ratio(&hc.base.border.unwrap(), &bg) >= 3.0);
+    assert!(contrast_ratio(&hc.base.border_highlight.unwrap(), &bg) >= 4.5);
+}
+
+#[test]
+fn high_contrast_keeps_backgrounds_and_renames_meta() {
+    let palette = palette_core::registry::load_preset("nord").unwrap();
+    let hc = palette.high_contrast(ContrastLevel::AaaNormal);
+
+    assert_eq!(hc.base.background, palette.base.background);
+    let meta = hc.meta.unwrap();
+    assert_eq!(meta.preset_id.as_ref(), "nord+hc");
+    assert_eq!(meta.name.as_ref(), "Nord (High Contrast)");
+}
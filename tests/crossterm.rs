@@ -0,0 +1,76 @@
+#![cfg(feature = "crossterm")]
+
+use crossterm::style::{Attribute, Attributes, Color as CrosstermColor};
+
+use palette_core::color::Color;
+use palette_core::crossterm::{slot_style, to_crossterm_attributes, to_crossterm_color, to_crossterm_theme};
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::style::TextStyle;
+
+mod common;
+
+fn styled_palette() -> Palette {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#000000"
+
+[semantic]
+error = { fg = "#DB4B4B", bold = true, undercurl = true }
+"##,
+    )
+    .unwrap();
+    Palette::from_manifest(&manifest).unwrap()
+}
+
+#[test]
+fn single_color_converts_rgb() {
+    let color = Color { r: 26, g: 27, b: 42 };
+    assert_eq!(to_crossterm_color(&color), CrosstermColor::Rgb { r: 26, g: 27, b: 42 });
+}
+
+#[test]
+fn text_styles_map_to_attributes() {
+    let style = TextStyle { italic: true, strikethrough: true, ..TextStyle::default() };
+    let attrs = to_crossterm_attributes(&style);
+    assert!(attrs.has(Attribute::Italic));
+    assert!(attrs.has(Attribute::CrossedOut));
+    assert!(!attrs.has(Attribute::Bold));
+    assert!(to_crossterm_attributes(&TextStyle::default()).is_empty());
+}
+
+#[test]
+fn theme_matches_palette() {
+    let palette = Palette::from_manifest(&common::load_preset("tokyonight")).unwrap();
+    let theme = to_crossterm_theme(&palette);
+
+    assert_eq!(theme.base.background, Some(CrosstermColor::Rgb { r: 26, g: 27, b: 42 }));
+    assert_eq!(theme.semantic.error, palette.semantic.error.as_ref().map(to_crossterm_color));
+}
+
+#[test]
+fn theme_carries_slot_attributes() {
+    let theme = to_crossterm_theme(&styled_palette());
+
+    assert!(theme.attributes.semantic.error.has(Attribute::Bold));
+    assert!(theme.attributes.semantic.error.has(Attribute::Undercurled));
+    assert_eq!(theme.attributes.semantic.warning, Attributes::none());
+    assert_eq!(theme.semantic.warning, None);
+}
+
+#[test]
+fn slot_style_combines_color_and_attributes() {
+    let style = slot_style(&styled_palette(), "semantic", "error");
+
+    assert_eq!(style.foreground_color, Some(CrosstermColor::Rgb { r: 0xDB, g: 0x4B, b: 0x4B }));
+    assert!(style.attributes.has(Attribute::Bold));
+    assert_eq!(style.background_color, None);
+}
+
+#[test]
+fn slot_style_unknown_slot_is_plain() {
+    let style = slot_style(&styled_palette(), "nope", "missing");
+    assert_eq!(style.foreground_color, None);
+    assert!(style.attributes.is_empty());
+}
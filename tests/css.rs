@@ -133,3 +133,52 @@ fn all_css_names_match_design_spec() {
     assert!(css.contains("--ui-menu:"), "surface: --ui-menu");
     assert!(css.contains("--text-comment:"), "typography: --text-comment");
 }
+
+#[test]
+fn styled_slots_emit_font_variables() {
+    use palette_core::manifest::PaletteManifest;
+
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+
+[syntax]
+keywords = { fg = "#cba6f7", bold = true }
+
+[editor]
+diagnostic_underline_error = { undercurl = true }
+"##,
+    )
+    .unwrap();
+    let css = to_css_custom_properties(&Palette::from_manifest(&manifest).unwrap(), None);
+
+    assert!(css.contains("--syn-keyword: #CBA6F7;"));
+    assert!(css.contains("--syn-keyword-font-weight: bold;"));
+    assert!(css.contains("--syn-keyword-font-style: normal;"));
+    assert!(css.contains("--syn-keyword-text-decoration: none;"));
+    assert!(css.contains("--ed-diag-ul-error-text-decoration: underline wavy;"));
+}
+
+#[test]
+fn unstyled_palette_emits_no_font_variables() {
+    let manifest = common::load_preset("tokyonight");
+    let css = to_css_custom_properties(&Palette::from_manifest(&manifest).unwrap(), None);
+    assert!(!css.contains("font-weight"));
+}
+
+#[test]
+fn custom_sections_use_namespaced_names() {
+    use palette_core::manifest::PaletteManifest;
+
+    let manifest = PaletteManifest::from_toml(
+        "[base]\n[custom.chart]\nseries_1 = \"#ff0000\"\n[custom.syn]\nkeyword = \"#0000ff\"\n[custom.app]\nsidebar_badge = { fg = \"#00ff00\", italic = true }\n",
+    )
+    .unwrap();
+    let css = to_css_custom_properties(&Palette::from_manifest(&manifest).unwrap(), Some("x"));
+
+    assert!(css.contains("--x-custom-chart-series-1: #FF0000;"));
+    assert!(css.contains("--x-custom-syn-keyword: #0000FF;"));
+    assert!(!css.contains("--x-syn-keyword:"));
+    assert!(css.contains("--x-custom-app-sidebar-badge: #00FF00;"));
+    assert!(css.contains("--x-custom-app-sidebar-badge-font-style: italic;"));
+}
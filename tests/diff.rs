@@ -0,0 +1,165 @@
+use palette_core::color::Color;
+use palette_core::diff::SlotChange;
+use palette_core::palette::Palette;
+use palette_core::registry::load_preset;
+use palette_core::style::TextStyle;
+
+fn color(hex: &str) -> Color {
+    Color::from_hex(hex).unwrap()
+}
+
+#[test]
+fn identical_palettes_produce_empty_diff() {
+    let palette = load_preset("tokyonight").unwrap();
+    let diff = palette.diff(&palette);
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "no changes\n");
+}
+
+#[test]
+fn changed_slot_reports_old_new_and_delta_e() {
+    let old = Palette::default();
+    let mut new = old.clone();
+    new.base.background = Some(color("#000000"));
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.len(), 1);
+
+    let slot = &diff.slots[0];
+    assert_eq!(slot.section.as_ref(), "base");
+    assert_eq!(slot.field.as_ref(), "background");
+    match slot.change {
+        SlotChange::Changed { old, new, delta_e } => {
+            assert_eq!(old, color("#1a1a2e"));
+            assert_eq!(new, color("#000000"));
+            assert!(delta_e > 0.0);
+        }
+        other => panic!("expected Changed, got {other:?}"),
+    }
+}
+
+#[test]
+fn added_and_removed_slots_are_classified() {
+    let old = Palette::default();
+    let mut new = old.clone();
+    new.syntax.keywords = Some(color("#bb9af7"));
+    new.semantic.hint = None;
+
+    let diff = old.diff(&new);
+    let added: Vec<_> = diff.added().map(|d| format!("{}.{}", d.section, d.field)).collect();
+    let removed: Vec<_> = diff.removed().map(|d| format!("{}.{}", d.section, d.field)).collect();
+
+    assert_eq!(added, ["syntax.keywords"]);
+    assert_eq!(removed, ["semantic.hint"]);
+    assert_eq!(diff.changed().count(), 0);
+}
+
+#[test]
+fn meta_changes_are_reported() {
+    let old = load_preset("tokyonight").unwrap();
+    let new = load_preset("tokyonight_storm").unwrap();
+
+    let diff = old.diff(&new);
+    let fields: Vec<_> = diff.meta.iter().map(|m| m.field).collect();
+    assert_eq!(fields, ["name", "preset_id", "style"]);
+    assert_eq!(diff.meta[1].old.as_deref(), Some("tokyonight"));
+    assert_eq!(diff.meta[1].new.as_deref(), Some("tokyonight_storm"));
+}
+
+#[test]
+fn variant_diff_lists_overridden_slots() {
+    let old = load_preset("tokyonight").unwrap();
+    let new = load_preset("tokyonight_storm").unwrap();
+
+    let diff = old.diff(&new);
+    assert!(diff
+        .changed()
+        .any(|d| d.section.as_ref() == "base" && d.field.as_ref() == "background"));
+    assert_eq!(diff.added().count(), 0);
+}
+
+#[test]
+fn display_renders_aligned_table() {
+    let old = Palette::default();
+    let mut new = old.clone();
+    new.base.background = Some(color("#000000"));
+    new.syntax.keywords = Some(color("#bb9af7"));
+
+    let table = new.diff(&old).to_string();
+    let lines: Vec<&str> = table.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("slot"));
+    assert!(lines[1].starts_with("base.background"));
+    assert!(lines[1].contains("#000000"));
+    assert!(lines[1].contains("#1A1A2E"));
+    assert!(lines[2].starts_with("syntax.keywords"));
+    assert!(lines[2].contains("#BB9AF7  -"));
+    // Columns line up, and the ΔE header sits over the right-aligned values
+    assert_eq!(lines[1].find("#000000"), lines[2].find("#BB9AF7"));
+    assert_eq!(lines[0].chars().count(), lines[1].chars().count());
+    assert!(lines[0].ends_with("ΔE"));
+}
+
+#[cfg(feature = "platform")]
+#[test]
+fn platform_overrides_are_compared() {
+    let old = load_preset("tokyonight").unwrap();
+    let new = load_preset("tokyonight_storm").unwrap();
+
+    let diff = old.diff(&new);
+    assert!(diff
+        .slots
+        .iter()
+        .any(|d| d.section.as_ref() == "platform.terminal" && d.field.as_ref() == "background"));
+}
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn diff_serializes_with_change_kind() {
+    let old = Palette::default();
+    let mut new = old.clone();
+    new.syntax.keywords = Some(color("#bb9af7"));
+
+    let value = serde_json::to_value(old.diff(&new)).unwrap();
+    let slot = &value["slots"][0];
+    assert_eq!(slot["section"], "syntax");
+    assert_eq!(slot["change"]["kind"], "added");
+    assert_eq!(slot["change"]["new"], "#BB9AF7");
+}
+
+#[test]
+fn custom_sections_are_compared() {
+    let old = Palette::default();
+    let mut new = old.clone();
+    new.custom.entry("chart".into()).or_default().insert("series_1".into(), color("#ff0000"));
+
+    let diff = old.diff(&new);
+    let added: Vec<_> = diff.added().map(|d| format!("{}.{}", d.section, d.field)).collect();
+    assert_eq!(added, ["custom.chart.series_1"]);
+    assert!(new.diff(&old).removed().any(|d| &*d.section == "custom.chart"));
+}
+
+#[test]
+fn style_only_changes_are_reported() {
+    let old = Palette::default();
+    let mut new = old.clone();
+    new.styles.syntax.keywords = Some(TextStyle {
+        bold: true,
+        italic: true,
+        ..TextStyle::default()
+    });
+    new.styles.custom.entry("chart".into()).or_default().insert("series_1".into(), TextStyle::default());
+
+    let diff = old.diff(&new);
+    assert!(diff.slots.is_empty());
+    assert_eq!(diff.len(), 2);
+    assert_eq!((&*diff.styles[0].section, &*diff.styles[0].field), ("syntax", "keywords"));
+    assert_eq!(diff.styles[0].old, None);
+    assert_eq!((&*diff.styles[1].section, &*diff.styles[1].field), ("custom.chart", "series_1"));
+
+    let table = diff.to_string();
+    let rows: Vec<Vec<&str>> = table.lines().map(|l| l.split_whitespace().collect()).collect();
+    assert_eq!(rows[1], ["syntax.keywords", "-", "bold+italic"]);
+    assert_eq!(rows[2], ["custom.chart.series_1", "-", "plain"]);
+}
@@ -78,3 +78,17 @@ fn weak_text_maps_foreground_dark() {
     // foreground_dark = "#a9b1d6" => (169, 177, 214)
     assert_eq!(v.weak_text_color, Some(Color32::from_rgb(169, 177, 214)));
 }
+
+#[test]
+fn interpolated_visuals_blend_panel_fill() {
+    use palette_core::egui::interpolate_visuals;
+    use palette_core::registry::load_preset;
+
+    let a = load_preset("tokyonight").unwrap();
+    let b = load_preset("catppuccin_latte").unwrap();
+
+    assert_eq!(interpolate_visuals(&a, &b, 0.0).panel_fill, to_egui_visuals(&a).panel_fill);
+    let mid = interpolate_visuals(&a, &b, 0.5).panel_fill;
+    assert_ne!(mid, to_egui_visuals(&a).panel_fill);
+    assert_ne!(mid, to_egui_visuals(&b).panel_fill);
+}
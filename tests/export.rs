@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use palette_core::color::Color;
+use palette_core::export::{
+    to_alacritty, to_foot, to_ghostty, to_kitty, to_wezterm, to_windows_terminal, to_xresources, TerminalColors,
+    TerminalFormat,
+};
+use palette_core::palette::Palette;
+use palette_core::{preset, preset_ids};
+
+mod common;
+
+fn tokyonight() -> Palette {
+    Palette::from_manifest(&common::load_preset("tokyonight")).unwrap()
+}
+
+fn sparse() -> Palette {
+    let manifest = common::manifest_with_base(BTreeMap::from([
+        (Arc::from("background"), Arc::from("#000000")),
+        (Arc::from("foreground"), Arc::from("#FFFFFF")),
+    ]));
+    Palette::from_manifest(&manifest).unwrap()
+}
+
+#[test]
+fn terminal_colors_resolve_pairs_and_ansi() {
+    let palette = tokyonight();
+    let colors = TerminalColors::from_palette(&palette);
+
+    assert_eq!(colors.background, palette.base.background);
+    assert_eq!(colors.cursor, palette.editor.cursor);
+    assert_eq!(colors.selection_bg, palette.editor.selection_bg);
+    assert_eq!(colors.ansi[0], palette.terminal_ansi.black);
+    assert_eq!(colors.ansi[15], palette.terminal_ansi.bright_white);
+}
+
+#[test]
+fn terminal_colors_cursor_falls_back_to_base() {
+    let colors = TerminalColors::from_palette(&sparse());
+    assert_eq!(colors.cursor, Some(Color { r: 255, g: 255, b: 255 }));
+    assert_eq!(colors.cursor_text, Some(Color { r: 0, g: 0, b: 0 }));
+    assert!(colors.ansi.iter().all(Option::is_none));
+}
+
+#[test]
+fn alacritty_is_valid_toml() {
+    let palette = tokyonight();
+    let doc: toml::Table = toml::from_str(&to_alacritty(&palette)).unwrap();
+    let colors = doc["colors"].as_table().unwrap();
+
+    assert_eq!(colors["primary"]["background"].as_str(), Some("#1A1B2A"));
+    assert_eq!(colors["normal"].as_table().unwrap().len(), 8);
+    assert_eq!(
+        colors["bright"]["magenta"].as_str().map(String::from),
+        palette.terminal_ansi.bright_magenta.map(|c| c.to_hex()),
+    );
+    assert_eq!(
+        colors["cursor"]["cursor"].as_str().map(String::from),
+        palette.editor.cursor.map(|c| c.to_hex()),
+    );
+}
+
+#[test]
+fn alacritty_skips_empty_tables() {
+    let out = to_alacritty(&sparse());
+    assert!(!out.contains("[colors.normal]"));
+    assert!(out.contains("[colors.primary]"));
+}
+
+#[test]
+fn kitty_uses_numbered_colors() {
+    let out = to_kitty(&tokyonight());
+    assert!(out.contains("background #1A1B2A\n"));
+    assert!(out.contains("cursor_text_color "));
+    assert!(out.contains("\ncolor0 "));
+    assert!(out.contains("\ncolor15 "));
+}
+
+#[test]
+fn wezterm_has_full_rows_and_metadata() {
+    let doc: toml::Table = toml::from_str(&to_wezterm(&tokyonight())).unwrap();
+
+    assert_eq!(doc["colors"]["ansi"].as_array().unwrap().len(), 8);
+    assert_eq!(doc["colors"]["brights"].as_array().unwrap().len(), 8);
+    assert_eq!(doc["metadata"]["name"].as_str(), Some("TokyoNight (Night)"));
+}
+
+#[test]
+fn wezterm_omits_partial_rows() {
+    let doc: toml::Table = toml::from_str(&to_wezterm(&sparse())).unwrap();
+
+    assert!(doc["colors"].get("ansi").is_none());
+    assert_eq!(doc["colors"]["background"].as_str(), Some("#000000"));
+}
+
+#[test]
+fn foot_uses_bare_hex() {
+    let out = to_foot(&tokyonight());
+    assert!(out.contains("[colors]\nforeground="));
+    assert!(out.contains("background=1a1b2a\n"));
+    assert!(out.contains("\nregular0="));
+    assert!(out.contains("\nbright7="));
+    assert!(out.contains("[cursor]\ncolor=1a1b26 c0caf5\n"));
+}
+
+#[test]
+fn ghostty_writes_palette_entries() {
+    let out = to_ghostty(&tokyonight());
+    assert!(out.contains("palette = 0=#"));
+    assert!(out.contains("palette = 15=#"));
+    assert!(out.contains("cursor-color = #C0CAF5\n"));
+}
+
+#[test]
+fn windows_terminal_uses_purple_keys() {
+    let out = to_windows_terminal(&tokyonight());
+    assert!(out.starts_with("{\n  \"name\": \"TokyoNight (Night)\",\n"));
+    assert!(out.contains("\"purple\": \"#"));
+    assert!(out.contains("\"brightPurple\": \"#"));
+    assert!(!out.contains("magenta"));
+    assert!(out.ends_with("\"\n}\n"));
+}
+
+#[test]
+fn xresources_uses_wildcard_resources() {
+    let out = to_xresources(&tokyonight());
+    assert!(out.starts_with("! "));
+    assert!(out.contains("*.background: #1A1B2A\n"));
+    assert!(out.contains("*.color15: #"));
+}
+
+#[test]
+fn every_preset_exports_sixteen_ansi_colors() {
+    for id in preset_ids() {
+        let palette = preset(id).unwrap();
+        let colors = TerminalColors::from_palette(&palette);
+        assert!(colors.ansi.iter().all(Option::is_some), "{id} is missing ANSI slots");
+
+        // Foot writes bare lowercase hex, so compare without `#` and case
+        let bright_white = palette.terminal_ansi.bright_white.unwrap().to_hex()[1..].to_lowercase();
+        for format in TerminalFormat::ALL {
+            let out = palette.to_terminal_config(format).to_lowercase();
+            assert!(out.contains(&bright_white), "{id} {format:?}");
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use palette_core::helix::{DIAGNOSTICS, SCOPES};
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::preset;
+
+mod common;
+
+fn theme(palette: &Palette) -> toml::Table {
+    palette.to_helix_theme().parse().unwrap()
+}
+
+fn hex(color: Option<palette_core::Color>) -> toml::Value {
+    toml::Value::String(color.unwrap().to_hex())
+}
+
+#[test]
+fn ui_scopes_map_slots() {
+    let palette = preset("tokyonight").unwrap();
+    let theme = theme(&palette);
+
+    assert_eq!(theme["ui.background"]["bg"], hex(palette.base.background));
+    assert_eq!(theme["ui.text"], hex(palette.base.foreground));
+    assert_eq!(
+        theme["ui.selection"]["bg"],
+        hex(palette.editor.selection_bg.or(palette.surface.selection)),
+    );
+    assert_eq!(
+        theme["ui.statusline"]["bg"],
+        hex(palette.surface.statusline.or(palette.base.background_dark)),
+    );
+    assert_eq!(theme["diff.plus"], hex(palette.diff.added));
+    assert_eq!(theme["keyword.function"], hex(palette.syntax.keywords_fn.or(palette.syntax.keywords)));
+    assert_eq!(theme["markup.link.url"], hex(palette.typography.link));
+}
+
+#[test]
+fn diagnostics_use_curly_underlines() {
+    let palette = preset("tokyonight").unwrap();
+    let theme = theme(&palette);
+
+    let error = &theme["diagnostic.error"]["underline"];
+    assert_eq!(error["style"].as_str(), Some("curl"));
+    let expected = palette.editor.diagnostic_underline_error.or(palette.editor.diagnostic_error).or(palette.semantic.error);
+    assert_eq!(error["color"], hex(expected));
+    assert_eq!(theme["error"], hex(palette.editor.diagnostic_error.or(palette.semantic.error)));
+}
+
+#[test]
+fn styles_become_modifiers_and_underlines() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#000000"
+
+[syntax]
+comments = { fg = "#565F89", italic = true, strikethrough = true }
+keywords = { fg = "#BB9AF7", bold = true, undercurl = true }
+strings = "#9ECE6A"
+"##,
+    )
+    .unwrap();
+    let theme = theme(&Palette::from_manifest(&manifest).unwrap());
+
+    let comment = &theme["comment"];
+    assert_eq!(comment["fg"].as_str(), Some("#565F89"));
+    let modifiers: Vec<&str> = comment["modifiers"].as_array().unwrap().iter().filter_map(|m| m.as_str()).collect();
+    assert_eq!(modifiers, ["italic", "crossed_out"]);
+    assert_eq!(theme["keyword"]["underline"]["style"].as_str(), Some("curl"));
+    assert_eq!(theme["string"].as_str(), Some("#9ECE6A"));
+    assert!(!theme.contains_key("function"));
+    assert!(!theme.contains_key("diff.plus"));
+}
+
+#[test]
+fn scope_tables_have_no_duplicates() {
+    let mut seen = std::collections::BTreeSet::new();
+    for scope in SCOPES.iter().map(|(s, ..)| s).chain(DIAGNOSTICS.iter().map(|(s, _)| s)) {
+        assert!(seen.insert(*scope), "{scope} listed twice");
+    }
+}
+
+#[test]
+fn header_name_stays_on_the_comment_line() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[meta]
+name = "Evil\r\"ui.background\" = \"red\"\u2028\u001Bx"
+preset_id = "evil"
+schema_version = "1"
+style = "dark"
+kind = "preset-base"
+
+[base]
+background = "#1A1B26"
+"##,
+    )
+    .unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let out = palette.to_helix_theme();
+
+    assert!(out.lines().next().unwrap().starts_with("# Evil \"ui.background\""));
+    assert_eq!(theme(&palette)["ui.background"]["bg"], hex(palette.base.background));
+}
+
+#[test]
+fn every_preset_exports_valid_toml() {
+    for id in palette_core::preset_ids() {
+        let theme = theme(&preset(id).unwrap());
+        assert!(theme.contains_key("ui.background"), "{id}");
+    }
+}
@@ -0,0 +1,77 @@
+use palette_core::color::Color;
+use palette_core::highlight::{capture_names, syntax_slot, HighlightTable};
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::registry::load_preset;
+
+fn color(hex: &str) -> Color {
+    Color::from_hex(hex).unwrap()
+}
+
+#[test]
+fn direct_captures_map_to_slots() {
+    assert_eq!(syntax_slot("@keyword.function"), Some("keywords_fn"));
+    assert_eq!(syntax_slot("@string.special.regex"), Some("strings_regex"));
+    assert_eq!(syntax_slot("@variable.builtin"), Some("variables_builtin"));
+    assert_eq!(syntax_slot("@punctuation.bracket"), Some("punctuation_bracket"));
+    assert_eq!(syntax_slot("@tag.attribute"), Some("tag_attribute"));
+    assert_eq!(syntax_slot("comment"), Some("comments"));
+}
+
+#[test]
+fn unknown_suffixes_fall_back_to_prefix() {
+    assert_eq!(syntax_slot("@keyword.control.return"), Some("keywords"));
+    assert_eq!(syntax_slot("@string.special.url"), Some("strings"));
+    assert_eq!(syntax_slot("@type.builtin.rust"), Some("types_builtin"));
+}
+
+#[test]
+fn unmapped_captures_return_none() {
+    assert_eq!(syntax_slot("@spell"), None);
+    assert_eq!(syntax_slot(""), None);
+}
+
+#[test]
+fn capture_names_are_sorted_and_unique() {
+    let names: Vec<_> = capture_names().collect();
+    assert!(names.windows(2).all(|w| w[0] < w[1]), "capture table must stay sorted");
+}
+
+#[test]
+fn capture_color_falls_back_when_slot_unset() {
+    let manifest = PaletteManifest::from_toml(
+        "[base]\n[syntax]\nkeywords = \"#bb9af7\"\nstrings = \"#9ece6a\"\n",
+    )
+    .unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+
+    assert_eq!(palette.capture_color("@keyword.function"), Some(color("#bb9af7")));
+    assert_eq!(palette.capture_color("@string.special.regex"), Some(color("#9ece6a")));
+    assert_eq!(palette.capture_color("@type"), None);
+}
+
+#[test]
+fn capture_style_follows_slot_styles() {
+    let manifest = PaletteManifest::from_toml(
+        "[base]\n[syntax]\ncomments = { fg = \"#565f89\", italic = true }\n",
+    )
+    .unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    assert!(palette.capture_style("@comment.documentation").unwrap().italic);
+    assert!(palette.capture_style("@keyword").is_none());
+}
+
+#[test]
+fn table_indexes_match_names() {
+    let palette = load_preset("tokyonight").unwrap();
+    let names = ["keyword", "string", "spell", "variable.builtin"];
+    let table = HighlightTable::new(&palette, &names);
+
+    assert_eq!(table.len(), 4);
+    assert_eq!(table.color(0), palette.syntax.keywords);
+    assert_eq!(table.color(1), palette.syntax.strings);
+    assert_eq!(table.color(2), None);
+    assert_eq!(table.color(3), palette.syntax.variables_builtin);
+    assert_eq!(table.color(99), None);
+    assert_eq!(palette.highlight_table(&names), table);
+}
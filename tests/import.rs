@@ -0,0 +1,319 @@
+use std::path::Path;
+
+use palette_core::color::Color;
+use palette_core::error::PaletteError;
+use palette_core::export::{self, TerminalColors};
+use palette_core::import::{
+    from_alacritty, from_iterm2, from_kitty, from_wezterm, from_windows_terminal, from_xresources, ImportFormat,
+};
+use palette_core::palette::Palette;
+use palette_core::{preset, Registry};
+
+fn tokyonight() -> Palette {
+    preset("tokyonight").unwrap()
+}
+
+fn hex(s: &str) -> Option<Color> {
+    Some(Color::from_hex(s).unwrap())
+}
+
+#[test]
+fn alacritty_round_trips() {
+    let palette = tokyonight();
+    let imported = from_alacritty(&export::to_alacritty(&palette)).unwrap();
+    assert_eq!(imported.colors, TerminalColors::from_palette(&palette));
+}
+
+#[test]
+fn kitty_round_trips() {
+    let palette = tokyonight();
+    let imported = from_kitty(&export::to_kitty(&palette)).unwrap();
+    assert_eq!(imported.colors, TerminalColors::from_palette(&palette));
+}
+
+#[test]
+fn wezterm_round_trips_with_name() {
+    let palette = tokyonight();
+    let imported = from_wezterm(&export::to_wezterm(&palette)).unwrap();
+    assert_eq!(imported.colors, TerminalColors::from_palette(&palette));
+    assert_eq!(imported.name.as_deref(), Some("TokyoNight (Night)"));
+}
+
+#[test]
+fn windows_terminal_round_trips_its_keys() {
+    let palette = tokyonight();
+    let expected = TerminalColors::from_palette(&palette);
+    let imported = from_windows_terminal(&export::to_windows_terminal(&palette)).unwrap();
+
+    assert_eq!(imported.name.as_deref(), Some("TokyoNight (Night)"));
+    assert_eq!(imported.colors.ansi, expected.ansi);
+    assert_eq!(imported.colors.cursor, expected.cursor);
+    assert_eq!(imported.colors.selection_bg, expected.selection_bg);
+    assert_eq!(imported.colors.cursor_text, None);
+}
+
+#[test]
+fn windows_terminal_skips_comments_and_other_values() {
+    let json = r##"{
+        // from settings.json
+        "name": "Campbell \"Mod\"",
+        "background": "#0C0C0C",
+        "opacity": 80,
+        "purple": "#881798"
+    }"##;
+    let imported = from_windows_terminal(json).unwrap();
+    assert_eq!(imported.name.as_deref(), Some("Campbell \"Mod\""));
+    assert_eq!(imported.colors.background, hex("#0C0C0C"));
+    assert_eq!(imported.colors.ansi[5], hex("#881798"));
+}
+
+#[test]
+fn windows_terminal_accepts_trailing_commas_and_rejects_malformed_json() {
+    let imported = from_windows_terminal("{ /* scheme */ \"name\": \"x\", \"red\": \"#C50F1F\", }").unwrap();
+    assert_eq!(imported.colors.ansi[1], hex("#C50F1F"));
+
+    let err = from_windows_terminal("{ \"red\": ").unwrap_err();
+    assert!(matches!(err, PaletteError::InvalidTheme { .. }), "{err}");
+}
+
+#[test]
+fn deeply_nested_json_is_an_error() {
+    let err = from_windows_terminal(&"[".repeat(200_000)).unwrap_err();
+    assert!(err.to_string().contains("nesting too deep"), "{err}");
+}
+
+#[test]
+fn xresources_resolves_defines_and_patterns() {
+    let xresources = "\
+! comment
+#define base00 #1d1f21
+*.background: base00
+URxvt*foreground: #c5c8c6
+*color1:  #cc6666
+XTerm.vt100.cursorColor: #aabbcc
+*.color15: #fff
+";
+    let colors = from_xresources(xresources).unwrap().colors;
+    assert_eq!(colors.background, hex("#1D1F21"));
+    assert_eq!(colors.foreground, hex("#C5C8C6"));
+    assert_eq!(colors.ansi[1], hex("#CC6666"));
+    assert_eq!(colors.cursor, hex("#AABBCC"));
+    assert_eq!(colors.ansi[15], hex("#FFFFFF"));
+}
+
+#[test]
+fn xresources_round_trips_its_keys() {
+    let palette = tokyonight();
+    let expected = TerminalColors::from_palette(&palette);
+    let colors = from_xresources(&export::to_xresources(&palette)).unwrap().colors;
+    assert_eq!((colors.background, colors.ansi), (expected.background, expected.ansi));
+}
+
+#[test]
+fn alacritty_accepts_0x_and_cell_keywords() {
+    let toml = r##"
+[colors.primary]
+background = "0x282a36"
+
+[colors.cursor]
+text = "CellBackground"
+cursor = "CellForeground"
+"##;
+    let colors = from_alacritty(toml).unwrap().colors;
+    assert_eq!(colors.background, hex("#282A36"));
+    assert_eq!(colors.cursor, None);
+}
+
+#[test]
+fn kitty_skips_deferred_values() {
+    let colors = from_kitty("# theme\nbackground #000000\nselection_foreground none\ncolor16 #ffffff\n")
+        .unwrap()
+        .colors;
+    assert_eq!(colors.background, hex("#000000"));
+    assert_eq!(colors.selection_fg, None);
+}
+
+#[test]
+fn invalid_color_names_format_and_key() {
+    let err = from_kitty("color3 yellowish\n").unwrap_err();
+    match err {
+        PaletteError::InvalidHex { section, field, .. } => {
+            assert_eq!((&*section, &*field), ("kitty", "color3"));
+        }
+        other => panic!("expected InvalidHex, got {other:?}"),
+    }
+}
+
+const ITERM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+	<key>Ansi 1 Color</key>
+	<dict>
+		<key>Alpha Component</key>
+		<real>1</real>
+		<key>Blue Component</key>
+		<real>0.0</real>
+		<key>Color Space</key>
+		<string>sRGB</string>
+		<key>Green Component</key>
+		<real>0.0</real>
+		<key>Red Component</key>
+		<real>1</real>
+	</dict>
+	<key>Background Color</key>
+	<dict>
+		<key>Blue Component</key>
+		<real>0.2</real>
+		<key>Green Component</key>
+		<real>0.1</real>
+		<key>Red Component</key>
+		<real>0.1</real>
+	</dict>
+	<key>Selection Color</key>
+	<dict>
+		<key>Red Component</key>
+		<real>0.5</real>
+	</dict>
+</dict>
+</plist>
+"#;
+
+#[test]
+fn iterm2_reads_component_dicts() {
+    let colors = from_iterm2(ITERM).unwrap().colors;
+    assert_eq!(colors.ansi[1], hex("#FF0000"));
+    assert_eq!(colors.background, Some(Color { r: 26, g: 26, b: 51 }));
+    assert_eq!(colors.selection_bg, None);
+}
+
+#[test]
+fn iterm2_reads_the_plist_structure() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+	<!-- <key>Ansi 2 Color</key><dict><key>Red Component</key><real>1</real></dict> -->
+	<key>Ansi 1 Color</key>
+	<dict>
+		<key>Red Component</key>
+		<integer>1</integer>
+		<key>Green Component</key>
+		<real>0</real>
+		<key>Blue Component</key>
+		<real>0</real>
+	</dict>
+	<key>Profile</key>
+	<dict>
+		<key>Background Color</key>
+		<dict>
+			<key>Red Component</key>
+			<real>1</real>
+			<key>Green Component</key>
+			<real>1</real>
+			<key>Blue Component</key>
+			<real>1</real>
+		</dict>
+	</dict>
+	<key>Background Color</key>
+	<dict>
+		<key>Red Component</key>
+		<real>0</real>
+		<key>Green Component</key>
+		<real>0</real>
+		<key>Blue Component</key>
+		<real>0</real>
+	</dict>
+</dict>
+</plist>
+"#;
+    let colors = from_iterm2(xml).unwrap().colors;
+    assert_eq!(colors.ansi[1], hex("#FF0000"));
+    assert_eq!(colors.ansi[2], None);
+    assert_eq!(colors.background, hex("#000000"));
+
+    let err = from_iterm2("<plist><dict><key>Background Color</key>").unwrap_err();
+    assert!(matches!(err, PaletteError::InvalidTheme { .. }), "{err}");
+}
+
+#[test]
+fn to_manifest_populates_sections() {
+    let imported = from_kitty(&export::to_kitty(&tokyonight())).unwrap();
+    let manifest = imported.to_manifest("tokyo_kitty").unwrap();
+    let meta = manifest.meta.as_ref().unwrap();
+
+    assert_eq!((&*meta.preset_id, &*meta.name, &*meta.style), ("tokyo_kitty", "tokyo_kitty", "dark"));
+    assert_eq!(manifest.terminal.len(), 16);
+    assert!(manifest.editor.contains_key("selection_bg"));
+
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    assert_eq!(palette.terminal_ansi.red, tokyonight().terminal_ansi.red);
+}
+
+#[test]
+fn to_manifest_requires_background() {
+    let imported = from_kitty("color0 #000000\n").unwrap();
+    assert!(matches!(
+        imported.to_manifest("x"),
+        Err(PaletteError::MissingSlot { section, field }) if &*section == "base" && &*field == "background"
+    ));
+}
+
+#[test]
+fn detect_by_extension_and_contents() {
+    let alacritty = export::to_alacritty(&tokyonight());
+    let wezterm = export::to_wezterm(&tokyonight());
+    let manifest = "[meta]\nname = \"x\"\n[base]\nbackground = \"#000000\"\n";
+
+    assert_eq!(ImportFormat::detect(Path::new("a.toml"), &alacritty), Some(ImportFormat::Alacritty));
+    assert_eq!(ImportFormat::detect(Path::new("w.toml"), &wezterm), Some(ImportFormat::WezTerm));
+    assert_eq!(ImportFormat::detect(Path::new("p.toml"), manifest), None);
+    assert_eq!(ImportFormat::detect(Path::new("k.conf"), ""), Some(ImportFormat::Kitty));
+    assert_eq!(ImportFormat::detect(Path::new("x.itermcolors"), ""), Some(ImportFormat::ITerm2));
+    assert_eq!(ImportFormat::detect(Path::new(".Xresources"), ""), Some(ImportFormat::Xresources));
+    assert_eq!(ImportFormat::detect(Path::new("notes.txt"), ""), None);
+}
+
+#[test]
+fn detect_sniffs_json_contents() {
+    let scheme = export::to_windows_terminal(&tokyonight());
+    assert_eq!(ImportFormat::detect(Path::new("s.json"), &scheme), Some(ImportFormat::WindowsTerminal));
+    assert_eq!(ImportFormat::detect(Path::new("package.json"), "{\"name\": \"app\"}"), None);
+    assert_eq!(ImportFormat::detect(Path::new("broken.json"), "{"), None);
+}
+
+#[test]
+fn registry_add_dir_skips_unrelated_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("night.conf"), export::to_kitty(&tokyonight())).unwrap();
+    std::fs::write(dir.path().join("package.json"), "{\"name\": \"app\", \"version\": \"1.0.0\"}").unwrap();
+    std::fs::write(dir.path().join("kitty.conf"), "font_size 12\n").unwrap();
+    std::fs::write(dir.path().join("broken.yaml"), "scheme: [").unwrap();
+
+    let mut reg = Registry::new();
+    let before = reg.list().count();
+    reg.add_dir(dir.path()).unwrap();
+
+    assert_eq!(reg.list().count(), before + 1);
+    assert!(reg.load("night").is_ok());
+    assert!(reg.load("kitty").is_err());
+}
+
+#[test]
+fn registry_add_file_imports_terminal_configs() {
+    let dir = tempfile::tempdir().unwrap();
+    let palette = tokyonight();
+    std::fs::write(dir.path().join("night.conf"), export::to_kitty(&palette)).unwrap();
+    std::fs::write(dir.path().join("storm.toml"), export::to_wezterm(&palette)).unwrap();
+
+    let mut reg = Registry::new();
+    reg.add_dir(dir.path()).unwrap();
+
+    let night = reg.list().find(|t| &*t.id == "night").unwrap();
+    assert_eq!(&*night.style, "dark");
+    let storm = reg.list().find(|t| &*t.id == "storm").unwrap();
+    assert_eq!(&*storm.name, "TokyoNight (Night)");
+
+    let loaded = reg.load("night").unwrap();
+    assert_eq!(loaded.base.background, palette.base.background);
+    assert_eq!(loaded.terminal_ansi.bright_cyan, palette.terminal_ansi.bright_cyan);
+    assert!(reg.load("storm+hc").is_ok());
+}
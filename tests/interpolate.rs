@@ -0,0 +1,102 @@
+use palette_core::color::Color;
+use palette_core::manipulation::BlendSpace;
+use palette_core::palette::Palette;
+use palette_core::registry::load_preset;
+
+fn color(hex: &str) -> Color {
+    Color::from_hex(hex).unwrap()
+}
+
+#[test]
+fn endpoints_reproduce_inputs() {
+    let a = load_preset("tokyonight").unwrap();
+    let b = load_preset("catppuccin_latte").unwrap();
+
+    assert_eq!(Palette::interpolate(&a, &b, 0.0), a);
+    assert_eq!(Palette::interpolate(&a, &b, 1.0), b);
+}
+
+#[test]
+fn midpoint_lies_between_endpoints() {
+    let a = load_preset("tokyonight").unwrap();
+    let b = load_preset("catppuccin_latte").unwrap();
+    let mid = Palette::interpolate(&a, &b, 0.5);
+
+    let (bg_a, bg_b, bg_mid) = (
+        a.base.background.unwrap(),
+        b.base.background.unwrap(),
+        mid.base.background.unwrap(),
+    );
+    let lum = |c: Color| c.relative_luminance();
+    assert!(lum(bg_mid) > lum(bg_a));
+    assert!(lum(bg_mid) < lum(bg_b));
+}
+
+#[test]
+fn srgb_space_mixes_channels_linearly() {
+    let mut a = Palette::default();
+    let mut b = Palette::default();
+    a.base.background = Some(color("#000000"));
+    b.base.background = Some(color("#FFFFFF"));
+
+    let mid = Palette::interpolate_in(&a, &b, 0.5, BlendSpace::Srgb);
+    assert_eq!(mid.base.background, Some(color("#808080")));
+}
+
+#[test]
+fn one_sided_slot_holds_until_far_endpoint() {
+    let a = Palette::default();
+    let mut b = Palette::default();
+    b.syntax.keywords = Some(color("#bb9af7"));
+    let mut a_only = Palette::default();
+    a_only.syntax.strings = Some(color("#9ece6a"));
+
+    assert_eq!(Palette::interpolate(&a, &b, 0.0).syntax.keywords, None);
+    assert_eq!(Palette::interpolate(&a, &b, 0.01).syntax.keywords, b.syntax.keywords);
+    assert_eq!(Palette::interpolate(&a_only, &a, 0.99).syntax.strings, a_only.syntax.strings);
+    assert_eq!(Palette::interpolate(&a_only, &a, 1.0).syntax.strings, None);
+}
+
+#[test]
+fn meta_switches_at_midpoint() {
+    let a = load_preset("tokyonight").unwrap();
+    let b = load_preset("nord").unwrap();
+
+    let before = Palette::interpolate(&a, &b, 0.49);
+    let after = Palette::interpolate(&a, &b, 0.5);
+    assert_eq!(before.meta, a.meta);
+    assert_eq!(after.meta, b.meta);
+}
+
+#[test]
+fn non_finite_t_returns_first_palette() {
+    let a = load_preset("tokyonight").unwrap();
+    let b = load_preset("nord").unwrap();
+    assert_eq!(Palette::interpolate(&a, &b, f64::NAN), a);
+}
+
+#[cfg(feature = "platform")]
+#[test]
+fn platform_overrides_are_blended() {
+    let a = load_preset("tokyonight").unwrap();
+    let b = load_preset("tokyonight_storm").unwrap();
+    let mid = Palette::interpolate(&a, &b, 0.5);
+
+    let bg = |p: &Palette| p.platform.get("terminal").unwrap().background.unwrap();
+    assert_ne!(bg(&mid), bg(&a));
+    assert_ne!(bg(&mid), bg(&b));
+}
+
+#[test]
+fn custom_sections_are_blended() {
+    let mut a = Palette::default();
+    let mut b = Palette::default();
+    a.custom.entry("chart".into()).or_default().insert("series_1".into(), color("#000000"));
+    b.custom.entry("chart".into()).or_default().insert("series_1".into(), color("#ffffff"));
+    b.custom.entry("app".into()).or_default().insert("badge".into(), color("#ff8800"));
+
+    let mid = Palette::interpolate_in(&a, &b, 0.5, BlendSpace::Srgb);
+    assert_eq!(mid.custom("chart", "series_1"), Some(&color("#808080")));
+    assert_eq!(mid.custom("app", "badge"), Some(&color("#ff8800")));
+    assert!(Palette::interpolate(&a, &b, 0.0).custom("app", "badge").is_none());
+}
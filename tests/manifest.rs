@@ -197,3 +197,208 @@ fn real_preset_tokyonight_storm_parses() {
     assert_eq!(&*meta.kind, "preset-variant");
     assert_eq!(manifest.inherits_from(), Some("tokyonight"));
 }
+
+#[test]
+fn include_list_parses_from_top_level_key() {
+    let toml = r##"
+include = ["../shared/ansi.toml", "ui.toml"]
+
+[base]
+background = "#000000"
+"##;
+
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let include: Vec<&str> = manifest.include.iter().map(|p| p.as_ref()).collect();
+    assert_eq!(include, ["../shared/ansi.toml", "ui.toml"]);
+}
+
+#[test]
+fn fragment_parses_without_meta_or_base() {
+    let toml = r##"
+[terminal]
+black = "#15161e"
+red = "#f7768e"
+"##;
+
+    let fragment = PaletteManifest::from_fragment_toml(toml).unwrap();
+    assert!(fragment.meta.is_none());
+    assert!(fragment.base.is_empty());
+    assert_eq!(fragment.terminal.len(), 2);
+}
+
+#[test]
+fn fragment_meta_is_ignored() {
+    let fragment = PaletteManifest::from_fragment_toml(BASE_TOML).unwrap();
+    assert!(fragment.meta.is_none());
+    assert_eq!(fragment.base.len(), 2);
+}
+
+const STYLED_TOML: &str = r##"
+[base]
+background = "#1e1e2e"
+
+[syntax]
+keywords = { fg = "#cba6f7", bold = true }
+comments = { fg = "#6c7086", italic = true }
+strings = "#a6e3a1"
+
+[editor]
+diagnostic_underline_error = { fg = "#f38ba8", undercurl = true }
+"##;
+
+#[test]
+fn styled_entry_splits_into_color_and_style() {
+    let manifest = PaletteManifest::from_toml(STYLED_TOML).unwrap();
+
+    assert_eq!(manifest.syntax.get("keywords").map(|v| &**v), Some("#cba6f7"));
+    assert_eq!(manifest.syntax.get("strings").map(|v| &**v), Some("#a6e3a1"));
+
+    let syntax = manifest.styles.get("syntax").unwrap();
+    assert!(syntax.get("keywords").unwrap().bold);
+    assert!(syntax.get("comments").unwrap().italic);
+    assert!(!syntax.contains_key("strings"));
+
+    let editor = manifest.styles.get("editor").unwrap();
+    assert!(editor.get("diagnostic_underline_error").unwrap().undercurl);
+}
+
+#[test]
+fn style_only_entry_has_no_color() {
+    let toml = r##"
+[base]
+
+[syntax]
+keywords = { italic = true }
+"##;
+
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    assert!(manifest.syntax.is_empty());
+    assert!(manifest.styles["syntax"]["keywords"].italic);
+}
+
+#[test]
+fn plain_manifest_has_no_styles() {
+    let manifest = PaletteManifest::from_toml(BASE_TOML).unwrap();
+    assert!(manifest.styles.is_empty());
+}
+
+#[test]
+fn custom_tables_become_custom_sections() {
+    let toml = r##"
+[base]
+background = "#000000"
+
+[custom.chart]
+series_1 = "#ff0000"
+series_2 = { fg = "#00ff00", bold = true }
+"##;
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let chart = manifest.custom.get("chart").unwrap();
+    assert_eq!(chart.get("series_1").map(|v| &**v), Some("#ff0000"));
+    assert_eq!(chart.get("series_2").map(|v| &**v), Some("#00ff00"));
+    assert!(manifest.styles["chart"]["series_2"].bold);
+    assert!(!manifest.custom.contains_key("base"));
+}
+
+#[test]
+fn platform_tables_are_not_custom() {
+    let toml = "[base]\n[platform.web]\nbackground = \"#000000\"\n";
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    assert!(manifest.custom.is_empty());
+}
+
+#[test]
+fn unknown_top_level_keys_are_not_custom() {
+    let toml = r##"
+foo = 1
+
+[base]
+background = "#000000"
+
+[git]
+add = "#449dab"
+
+[tool.nested]
+enabled = true
+"##;
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    assert!(manifest.custom.is_empty());
+    assert!(manifest.styles.is_empty());
+}
+
+#[test]
+fn custom_sections_skip_non_color_values() {
+    let toml = r##"
+[base]
+
+[custom]
+version = 2
+
+[custom.chart]
+series_1 = "#ff0000"
+count = 8
+labels = ["a", "b"]
+"##;
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    assert_eq!(manifest.custom.len(), 1);
+    let chart = &manifest.custom["chart"];
+    assert_eq!(chart.len(), 1);
+    assert_eq!(chart.get("series_1").map(|v| &**v), Some("#ff0000"));
+}
+
+#[test]
+fn custom_section_cannot_shadow_builtin() {
+    let err = PaletteManifest::from_toml("[base]\n[custom.syntax]\nkeywords = \"#ff0000\"\n").unwrap_err();
+    assert!(err.to_string().contains("[custom.syntax]"), "{err}");
+}
+
+#[test]
+fn custom_names_must_be_css_safe() {
+    for toml in [
+        "[base]\n[custom.\"a b\"]\nx = \"#ff0000\"\n",
+        "[base]\n[custom.\"x;}body{\"]\nx = \"#ff0000\"\n",
+        "[base]\n[custom.Chart]\nx = \"#ff0000\"\n",
+        "[base]\n[custom.chart]\n\"x;}body{\" = \"#ff0000\"\n",
+        "[base]\n[custom.chart]\n\"\" = { fg = \"#ff0000\" }\n",
+    ] {
+        let err = PaletteManifest::from_toml(toml).unwrap_err();
+        assert!(err.to_string().contains("must use only a-z, 0-9"), "{toml}: {err}");
+    }
+    assert!(PaletteManifest::from_toml("[base]\n[custom.chart-2]\nseries_1 = \"#ff0000\"\n").is_ok());
+}
+
+#[test]
+fn style_table_typo_is_an_error() {
+    let err = PaletteManifest::from_toml("[base]\n[syntax]\nkeywords = { fg = \"#ffffff\", bodl = true }\n").unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("unknown field `bodl`"), "{message}");
+    assert!(message.contains("keywords = "), "{message}");
+}
+
+#[test]
+fn style_table_rejects_bg() {
+    let err = PaletteManifest::from_toml("[base]\n[editor]\ncursor = { fg = \"#ffffff\", bg = \"#000000\" }\n").unwrap_err();
+    assert!(err.to_string().contains("unknown field `bg`"), "{err}");
+}
+
+#[test]
+fn style_flag_with_bad_value_is_an_error() {
+    let err = PaletteManifest::from_toml("[base]\n[syntax]\nkeywords = { bold = \"yes\" }\n").unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("expected a boolean"), "{message}");
+    assert!(message.contains("keywords = "), "{message}");
+}
+
+#[test]
+fn slot_with_bad_type_names_what_was_expected() {
+    let err = PaletteManifest::from_toml("[base]\n[syntax]\nkeywords = 5\n").unwrap_err();
+    assert!(err.to_string().contains("expected a hex color or an inline table"), "{err}");
+}
+
+#[test]
+fn custom_style_table_typo_names_the_slot() {
+    let err = PaletteManifest::from_toml("[base]\n[custom.chart]\nseries_1 = { fg = \"#ff0000\", itlaic = true }\n").unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("[custom.chart] slot `series_1`"), "{message}");
+    assert!(message.contains("unknown field `itlaic`"), "{message}");
+}
@@ -1,5 +1,5 @@
 use palette_core::color::Color;
-use palette_core::manipulation::blend;
+use palette_core::manipulation::{blend, delta_e, interpolate, BlendSpace};
 
 fn color(hex: &str) -> Color {
     Color::from_hex(hex).unwrap()
@@ -240,3 +240,85 @@ fn blend_infinity_returns_bg() {
     let bg = color("#0000FF");
     assert_eq!(blend(fg, bg, f64::INFINITY), bg);
 }
+
+// --- delta_e ---
+
+#[test]
+fn delta_e_identical_is_zero() {
+    let c = color("#FF4500");
+    assert!(delta_e(c, c).abs() < 1e-9);
+}
+
+#[test]
+fn delta_e_black_white_is_100() {
+    let d = color("#000000").delta_e(color("#FFFFFF"));
+    assert!((d - 100.0).abs() < 0.1, "expected ~100, got {d}");
+}
+
+#[test]
+fn delta_e_is_symmetric() {
+    let a = color("#336699");
+    let b = color("#FFCC00");
+    assert!((delta_e(a, b) - delta_e(b, a)).abs() < 1e-9);
+}
+
+#[test]
+fn delta_e_small_step_is_small() {
+    let d = delta_e(color("#808080"), color("#818181"));
+    assert!(d < 1.0, "one-step gray change should be imperceptible, got {d}");
+}
+
+// --- interpolate ---
+
+#[test]
+fn interpolate_endpoints_return_inputs() {
+    let a = color("#FF4500");
+    let b = color("#1E90FF");
+    for space in [BlendSpace::Srgb, BlendSpace::Oklab] {
+        assert_channel_eq(interpolate(a, b, 0.0, space), a, 1, "t=0");
+        assert_channel_eq(interpolate(a, b, 1.0, space), b, 1, "t=1");
+    }
+}
+
+#[test]
+fn interpolate_srgb_midpoint_matches_blend() {
+    let a = color("#000000");
+    let b = color("#FFFFFF");
+    assert_eq!(interpolate(a, b, 0.5, BlendSpace::Srgb), blend(b, a, 0.5));
+}
+
+#[test]
+fn interpolate_oklab_midpoint_is_perceptual_gray() {
+    // OKLab L = 0.5 is #636363, darker than the sRGB midpoint #808080
+    let mid = color("#000000").interpolate(color("#FFFFFF"), 0.5, BlendSpace::Oklab);
+    assert_channel_eq(mid, color("#636363"), 1, "oklab midpoint");
+}
+
+#[test]
+fn interpolate_clamps_t() {
+    let a = color("#FF4500");
+    let b = color("#1E90FF");
+    assert_eq!(interpolate(a, b, -1.0, BlendSpace::Srgb), a);
+    assert_eq!(interpolate(a, b, 2.0, BlendSpace::Srgb), b);
+}
+
+#[test]
+fn interpolate_nan_returns_from() {
+    let a = color("#FF4500");
+    let b = color("#1E90FF");
+    assert_eq!(interpolate(a, b, f64::NAN, BlendSpace::Oklab), a);
+}
+
+// --- grayscale ---
+
+#[test]
+fn grayscale_keeps_grays() {
+    let c = color("#808080");
+    assert_channel_eq(c.grayscale(), c, 1, "gray stays gray");
+}
+
+#[test]
+fn grayscale_removes_chroma() {
+    let g = color("#FF4500").grayscale();
+    assert!(g.r.abs_diff(g.g) <= 1 && g.g.abs_diff(g.b) <= 1, "expected neutral, got {g:?}");
+}
@@ -130,3 +130,48 @@ fn real_preset_tokyonight_storm_merge() {
     let meta = merged.meta.as_ref().unwrap();
     assert_eq!(&*meta.preset_id, "tokyonight_storm");
 }
+
+#[test]
+fn styles_inherit_independently_of_colors() {
+    let base = PaletteManifest::from_toml(
+        r##"
+[base]
+
+[syntax]
+keywords = { fg = "#111111", bold = true }
+comments = { fg = "#222222", italic = true }
+"##,
+    )
+    .unwrap();
+    let variant = PaletteManifest::from_toml(
+        r##"
+[base]
+
+[syntax]
+keywords = "#333333"
+comments = { fg = "#444444", bold = true }
+"##,
+    )
+    .unwrap();
+
+    let merged = merge_manifests(&variant, &base);
+    let syntax = &merged.styles["syntax"];
+
+    assert_eq!(&**merged.syntax.get("keywords").unwrap(), "#333333");
+    assert!(syntax["keywords"].bold, "plain variant color keeps parent style");
+    assert!(syntax["comments"].bold && !syntax["comments"].italic, "styled variant entry replaces parent style");
+}
+
+#[test]
+fn custom_sections_inherit_per_field() {
+    let base = PaletteManifest::from_toml(
+        "[base]\n[custom.chart]\nseries_1 = \"#111111\"\nseries_2 = \"#222222\"\n[custom.app]\nbadge = \"#333333\"\n",
+    )
+    .unwrap();
+    let variant = PaletteManifest::from_toml("[base]\n[custom.chart]\nseries_2 = \"#999999\"\n").unwrap();
+
+    let merged = merge_manifests(&variant, &base);
+    assert_eq!(&*merged.custom["chart"]["series_1"], "#111111");
+    assert_eq!(&*merged.custom["chart"]["series_2"], "#999999");
+    assert_eq!(&*merged.custom["app"]["badge"], "#333333");
+}
@@ -0,0 +1,144 @@
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::preset;
+
+mod common;
+
+fn line<'a>(lua: &'a str, group: &str) -> Option<&'a str> {
+    let prefix = format!("hl(0, \"{group}\", ");
+    lua.lines().find(|l| l.starts_with(&prefix))
+}
+
+fn hex(color: Option<palette_core::Color>) -> String {
+    color.unwrap().to_hex()
+}
+
+#[test]
+fn header_sets_background_and_name() {
+    let lua = preset("tokyonight").unwrap().to_neovim_lua();
+    assert!(lua.starts_with("-- TokyoNight (Night)\n"));
+    assert!(lua.contains("vim.cmd(\"highlight clear\")"));
+    assert!(lua.contains("vim.o.background = \"dark\""));
+    assert!(lua.contains("vim.g.colors_name = \"tokyonight\""));
+
+    let light = preset("github_light").unwrap().to_neovim_lua();
+    assert!(light.contains("vim.o.background = \"light\""));
+}
+
+#[test]
+fn ui_groups_use_base_and_surface_slots() {
+    let palette = preset("tokyonight").unwrap();
+    let lua = palette.to_neovim_lua();
+
+    let normal = line(&lua, "Normal").unwrap();
+    assert!(normal.contains(&format!("fg = \"{}\"", hex(palette.base.foreground))));
+    assert!(normal.contains(&format!("bg = \"{}\"", hex(palette.base.background))));
+    let float = line(&lua, "NormalFloat").unwrap();
+    assert!(float.contains(&format!("bg = \"{}\"", hex(palette.surface.float.or(palette.surface.popup)))));
+    let pmenu = line(&lua, "Pmenu").unwrap();
+    assert!(pmenu.contains(&format!("bg = \"{}\"", hex(palette.surface.menu.or(palette.surface.popup)))));
+    assert!(line(&lua, "StatusLine").is_some());
+    let add = line(&lua, "DiffAdd").unwrap();
+    assert!(add.contains(&format!("bg = \"{}\"", hex(palette.diff.added_bg))));
+}
+
+#[test]
+fn diagnostic_underlines_use_sp_and_undercurl() {
+    let palette = preset("tokyonight").unwrap();
+    let lua = palette.to_neovim_lua();
+
+    let error = line(&lua, "DiagnosticUnderlineError").unwrap();
+    let sp = palette.editor.diagnostic_underline_error.or(palette.editor.diagnostic_error).or(palette.semantic.error);
+    assert!(error.contains(&format!("sp = \"{}\"", hex(sp))), "{error}");
+    assert!(error.contains("undercurl = true"));
+    assert!(!error.contains("fg ="));
+    assert!(line(&lua, "DiagnosticWarn").is_some());
+}
+
+#[test]
+fn captures_semantic_tokens_and_links() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#1A1B26"
+foreground = "#C0CAF5"
+
+[syntax]
+keywords = { fg = "#BB9AF7", italic = true }
+keywords_fn = "#9D7CD8"
+variables = "#C0CAF5"
+constants = "#FF9E64"
+"##,
+    )
+    .unwrap();
+    let lua = Palette::from_manifest(&manifest).unwrap().to_neovim_lua();
+
+    assert_eq!(line(&lua, "@keyword").unwrap(), "hl(0, \"@keyword\", { fg = \"#BB9AF7\", italic = true })");
+    assert!(line(&lua, "@keyword.function").unwrap().contains("#9D7CD8"));
+    assert!(line(&lua, "@string").is_none());
+    assert!(line(&lua, "@lsp.type.keyword").unwrap().contains("#BB9AF7"));
+    assert!(line(&lua, "@lsp.typemod.variable.readonly").unwrap().contains("#FF9E64"));
+    assert!(lua.contains("hl(0, \"Keyword\", { link = \"@keyword\" })"));
+    assert!(!lua.contains("\"String\""));
+    assert!(!lua.contains("terminal_color"));
+}
+
+#[test]
+fn terminal_colors_follow_ansi_order() {
+    let palette = preset("tokyonight").unwrap();
+    let lua = palette.to_neovim_lua();
+
+    assert!(lua.contains(&format!("vim.g.terminal_color_0 = \"{}\"", hex(palette.terminal_ansi.black))));
+    assert!(lua.contains(&format!("vim.g.terminal_color_13 = \"{}\"", hex(palette.terminal_ansi.bright_magenta))));
+}
+
+#[test]
+fn terminal_colors_are_indexed_by_field_name() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#1A1B26"
+
+[terminal]
+bright_white = "#FFFFFF"
+black = "#15161E"
+"##,
+    )
+    .unwrap();
+    let lua = Palette::from_manifest(&manifest).unwrap().to_neovim_lua();
+
+    assert!(lua.contains("vim.g.terminal_color_0 = \"#15161E\""));
+    assert!(lua.contains("vim.g.terminal_color_15 = \"#FFFFFF\""));
+    assert_eq!(lua.matches("terminal_color_").count(), 2);
+}
+
+#[test]
+fn header_name_stays_on_the_comment_line() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[meta]
+name = "Evil\rvim.cmd('!ls')\u0085x\u000Bend"
+preset_id = "evil"
+schema_version = "1"
+style = "dark"
+kind = "preset-base"
+
+[base]
+background = "#1A1B26"
+"##,
+    )
+    .unwrap();
+    let lua = Palette::from_manifest(&manifest).unwrap().to_neovim_lua();
+
+    assert_eq!(lua.lines().next().unwrap(), "-- Evil vim.cmd('!ls') x end");
+    assert_eq!(lua.lines().nth(1).unwrap(), "");
+}
+
+#[test]
+fn every_preset_renders() {
+    for id in palette_core::preset_ids() {
+        let lua = preset(id).unwrap().to_neovim_lua();
+        assert!(line(&lua, "Normal").is_some(), "{id}");
+        assert_eq!(lua.matches("{ ").count(), lua.matches(" }").count(), "{id}");
+    }
+}
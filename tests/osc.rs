@@ -0,0 +1,151 @@
+#![cfg(feature = "osc")]
+
+use palette_core::color::Color;
+use palette_core::palette::Palette;
+use palette_core::registry::load_preset;
+use palette_core::terminal::osc::{
+    apply, apply_sequences, detect_passthrough, reset, set_ansi_color, set_background, wrap,
+    Passthrough,
+};
+
+#[test]
+fn ansi_entry_uses_x11_rgb_spec() {
+    let seq = set_ansi_color(1, &Color { r: 0xf7, g: 0x76, b: 0x8e });
+    assert_eq!(seq, "\x1b]4;1;rgb:f7/76/8e\x1b\\");
+}
+
+#[test]
+fn dynamic_colors_use_osc_10_11_12() {
+    let palette = load_preset("tokyonight").unwrap();
+    let seqs = apply_sequences(&palette);
+
+    assert_eq!(seqs.len(), 16 + 3);
+    assert!(seqs[0].starts_with("\x1b]4;0;"));
+    assert!(seqs[15].starts_with("\x1b]4;15;"));
+    assert_eq!(seqs[17], set_background(&palette.base.background.unwrap()));
+    assert!(seqs[16].starts_with("\x1b]10;"));
+    assert!(seqs[18].starts_with("\x1b]12;"));
+}
+
+#[test]
+fn unset_slots_are_skipped() {
+    let mut palette = Palette::default();
+    palette.terminal_ansi.red = Some(Color { r: 255, g: 0, b: 0 });
+    let seqs = apply_sequences(&palette);
+
+    assert_eq!(seqs[0], "\x1b]4;1;rgb:ff/00/00\x1b\\");
+    assert_eq!(seqs.len(), 3, "red, foreground, background; no cursor");
+}
+
+#[test]
+fn reset_restores_palette_and_dynamic_colors() {
+    assert_eq!(reset(Passthrough::None), "\x1b]104\x1b\\\x1b]110\x1b\\\x1b]111\x1b\\\x1b]112\x1b\\");
+}
+
+#[test]
+fn tmux_passthrough_doubles_escapes() {
+    let wrapped = wrap("\x1b]11;rgb:00/00/00\x1b\\", Passthrough::Tmux);
+    assert_eq!(wrapped, "\x1bPtmux;\x1b\x1b]11;rgb:00/00/00\x1b\x1b\\\x1b\\");
+}
+
+#[test]
+fn screen_passthrough_terminates_inner_with_bel() {
+    let wrapped = wrap("\x1b]11;rgb:00/00/00\x1b\\", Passthrough::Screen);
+    assert_eq!(wrapped, "\x1bP\x1b]11;rgb:00/00/00\x07\x1b\\");
+}
+
+#[test]
+fn apply_wraps_each_sequence() {
+    let palette = load_preset("nord").unwrap();
+    let out = apply(&palette, Passthrough::Tmux);
+    assert_eq!(out.matches("\x1bPtmux;").count(), apply_sequences(&palette).len());
+    assert_eq!(palette.to_osc(Passthrough::None), apply_sequences(&palette).concat());
+}
+
+#[test]
+fn passthrough_detection() {
+    assert_eq!(detect_passthrough(Some("/tmp/tmux-1000/default,123,0"), Some("screen-256color")), Passthrough::Tmux);
+    assert_eq!(detect_passthrough(None, Some("tmux-256color")), Passthrough::Tmux);
+    assert_eq!(detect_passthrough(Some(""), Some("screen")), Passthrough::Screen);
+    assert_eq!(detect_passthrough(None, Some("xterm-256color")), Passthrough::None);
+}
+
+// --- Query responses ---
+
+use palette_core::terminal::osc::{parse_color_spec, parse_responses, query_sequences};
+
+#[test]
+fn color_spec_scales_channel_widths() {
+    let c = |r, g, b| Some(Color { r, g, b });
+    assert_eq!(parse_color_spec("rgb:ffff/0000/8080"), c(255, 0, 128));
+    assert_eq!(parse_color_spec("rgb:ff/00/80"), c(255, 0, 128));
+    assert_eq!(parse_color_spec("rgb:f/0/8"), c(255, 0, 136));
+    assert_eq!(parse_color_spec("rgba:ffff/0000/0000/ffff"), c(255, 0, 0));
+    assert_eq!(parse_color_spec("#1a1b26"), c(0x1a, 0x1b, 0x26));
+    assert_eq!(parse_color_spec("rgb:ff/00"), None);
+    assert_eq!(parse_color_spec("rgb:fffff/0/0"), None);
+    assert_eq!(parse_color_spec("red"), None);
+}
+
+#[test]
+fn query_covers_ansi_and_dynamic_colors() {
+    let queries = query_sequences();
+    assert_eq!(queries.len(), 19);
+    assert_eq!(queries[3], "\x1b]4;3;?\x1b\\");
+    assert_eq!(queries[17], "\x1b]11;?\x1b\\");
+}
+
+#[test]
+fn responses_parse_with_either_terminator() {
+    let input = b"\x1b]4;1;rgb:ffff/0000/0000\x07\
+                  \x1b]11;rgb:1a1a/1b1b/2626\x1b\\\
+                  junk\x1b[0n\
+                  \x1b]10;rgb:c0c0/caca/f5f5\x07\
+                  \x1b]4;9;rgb:ff/80/80\x1b\\";
+    let colors = parse_responses(input);
+
+    assert_eq!(colors.ansi.red, Some(Color { r: 255, g: 0, b: 0 }));
+    assert_eq!(colors.ansi.bright_red, Some(Color { r: 255, g: 128, b: 128 }));
+    assert_eq!(colors.background, Some(Color { r: 0x1a, g: 0x1b, b: 0x26 }));
+    assert_eq!(colors.foreground, Some(Color { r: 0xc0, g: 0xca, b: 0xf5 }));
+    assert_eq!(colors.cursor, None);
+    assert!(colors.ansi.green.is_none());
+}
+
+#[test]
+fn malformed_and_out_of_range_replies_are_ignored() {
+    let colors = parse_responses(b"\x1b]4;200;rgb:ff/ff/ff\x07\x1b]4;x;rgb:ff/ff/ff\x07\x1b]11;?\x07\x1b]52;c;abc\x07");
+    assert!(colors.is_empty());
+    assert!(parse_responses(b"").is_empty());
+    assert!(parse_responses(b"\x1b]11;rgb:ff").background.is_none());
+}
+
+#[test]
+fn queried_colors_round_trip_through_apply() {
+    let palette = load_preset("tokyonight").unwrap();
+    let colors = parse_responses(apply(&palette, Passthrough::None).as_bytes());
+
+    assert_eq!(colors.ansi, palette.terminal_ansi);
+    assert_eq!(colors.background, palette.base.background);
+    assert_eq!(colors.cursor, palette.editor.cursor);
+}
+
+#[test]
+fn palette_from_queried_colors() {
+    let source = load_preset("nord").unwrap();
+    let colors = parse_responses(apply(&source, Passthrough::None).as_bytes());
+
+    let derived = colors.to_palette();
+    assert_eq!(derived.base.background, source.base.background);
+    assert_eq!(derived.semantic.error, source.terminal_ansi.red);
+    assert_eq!(derived.terminal_ansi, source.terminal_ansi);
+    assert!(derived.syntax.keywords.is_none());
+    assert!(derived.meta.is_none());
+
+    let fallback = load_preset("tokyonight").unwrap();
+    let merged = colors.over(&fallback);
+    assert_eq!(merged.base.background, source.base.background);
+    assert_eq!(merged.terminal_ansi, source.terminal_ansi);
+    assert_eq!(merged.syntax, fallback.syntax);
+    assert_eq!(merged.meta, fallback.meta);
+}
@@ -0,0 +1,165 @@
+use palette_core::color::Color;
+use palette_core::contrast::contrast_pairs;
+use palette_core::manifest::PaletteManifest;
+use palette_core::pairs::STYLE_PAIRS;
+use palette_core::palette::Palette;
+use palette_core::registry::load_preset;
+
+fn color(hex: &str) -> Color {
+    Color::from_hex(hex).unwrap()
+}
+
+fn palette(toml: &str) -> Palette {
+    Palette::from_manifest(&PaletteManifest::from_toml(toml).unwrap()).unwrap()
+}
+
+#[test]
+fn declared_slots_are_used_as_is() {
+    let p = palette(
+        r##"
+[base]
+background = "#000000"
+foreground = "#ffffff"
+
+[editor]
+search_fg = "#111111"
+search_bg = "#eeee00"
+"##,
+    );
+    let pair = p.search_pair();
+    assert_eq!(pair.name, "search");
+    assert_eq!(pair.colors(), Some((color("#111111"), color("#eeee00"))));
+    assert!(!pair.fg_fallback && !pair.bg_fallback);
+}
+
+#[test]
+fn missing_half_resolves_through_fallbacks() {
+    let p = palette(
+        r##"
+[base]
+background = "#000000"
+foreground = "#ffffff"
+
+[surface]
+selection = "#333366"
+
+[diff]
+added = "#00ff00"
+"##,
+    );
+
+    let selection = p.selection_pair();
+    assert_eq!(selection.fg, Some(color("#ffffff")));
+    assert_eq!(selection.bg, Some(color("#333366")));
+    assert!(selection.fg_fallback && selection.bg_fallback);
+
+    let added = p.diff_added_pair();
+    assert_eq!(added.fg, Some(color("#00ff00")));
+    assert_eq!(added.bg, Some(color("#000000")));
+}
+
+#[test]
+fn cursor_pair_inverts_base_colors() {
+    let p = palette("[base]\nbackground = \"#000000\"\nforeground = \"#ffffff\"\n");
+    let cursor = p.cursor_pair();
+    assert_eq!(cursor.colors(), Some((color("#000000"), color("#ffffff"))));
+}
+
+#[test]
+fn unresolvable_half_is_none() {
+    let pair = palette("[base]\n").inlay_hint_pair();
+    assert!(pair.fg.is_none() && pair.bg.is_none());
+    assert!(pair.colors().is_none());
+}
+
+#[test]
+fn pair_carries_foreground_style() {
+    let p = palette(
+        "[base]\nbackground = \"#000000\"\n[editor]\nsearch_fg = { fg = \"#ffffff\", bold = true }\n",
+    );
+    assert!(p.search_pair().style.unwrap().bold);
+    assert!(p.selection_pair().style.is_none());
+}
+
+#[test]
+fn pairs_follow_table_order_and_lookup_by_name() {
+    let p = load_preset("tokyonight").unwrap();
+    let names: Vec<_> = p.pairs().iter().map(|pair| pair.name).collect();
+    let expected: Vec<_> = STYLE_PAIRS.iter().map(|spec| spec.name).collect();
+    assert_eq!(names, expected);
+
+    assert_eq!(p.pair("diff_removed"), Some(p.diff_removed_pair()));
+    assert!(p.pair("nonexistent").is_none());
+}
+
+#[test]
+fn contrast_checks_every_resolved_pair() {
+    let p = load_preset("tokyonight").unwrap();
+    let checked = contrast_pairs(&p);
+    for spec in &STYLE_PAIRS {
+        let (Some(fg), Some(bg)) = spec.resolve_slots(&p) else {
+            panic!("{} did not resolve", spec.name);
+        };
+        assert!(checked.contains(&(fg, bg)), "{} not checked", spec.name);
+    }
+}
+
+#[test]
+fn contrast_checks_the_colors_renderers_use() {
+    let p = palette(
+        r##"
+[base]
+background = "#000000"
+foreground = "#ffffff"
+
+[surface]
+selection = "#ffffff"
+"##,
+    );
+    let checked = contrast_pairs(&p);
+    assert!(checked.contains(&(("base", "foreground"), ("surface", "selection"))));
+    assert!(!checked.contains(&(("editor", "selection_fg"), ("editor", "selection_bg"))));
+
+    let (fg, bg) = p.selection_pair().colors().unwrap();
+    let violations = palette_core::contrast::validate_palette(&p, palette_core::ContrastLevel::AaNormal);
+    assert!(violations.iter().any(|v| v.foreground == fg && v.background == bg));
+}
+
+#[test]
+fn pair_reached_twice_is_reported_once() {
+    let p = palette(
+        r##"
+[base]
+background = "#000000"
+foreground = "#ffffff"
+
+[typography]
+comment = "#303030"
+"##,
+    );
+    let comment = (("typography", "comment"), ("base", "background"));
+    assert_eq!(contrast_pairs(&p).iter().filter(|pair| **pair == comment).count(), 1);
+
+    let violations = palette_core::contrast::validate_palette(&p, palette_core::ContrastLevel::AaNormal);
+    let labels: Vec<_> = violations.iter().filter(|v| &*v.foreground_label == "typography.comment").collect();
+    assert_eq!(labels.len(), 1, "{violations:?}");
+}
+
+#[test]
+fn accessors_match_table_entries_by_name() {
+    let p = load_preset("tokyonight").unwrap();
+    let accessors = [
+        p.selection_pair(),
+        p.inlay_hint_pair(),
+        p.search_pair(),
+        p.cursor_pair(),
+        p.diff_added_pair(),
+        p.diff_modified_pair(),
+        p.diff_removed_pair(),
+    ];
+    for pair in accessors {
+        assert_eq!(p.pair(pair.name), Some(pair));
+    }
+    assert_eq!(p.selection_pair().name, "selection");
+    assert_eq!(p.diff_removed_pair().name, "diff_removed");
+}
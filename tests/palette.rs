@@ -123,3 +123,88 @@ fn default_produces_valid_css() {
     assert!(css.contains("--fg:"));
     assert!(css.contains("--error:"));
 }
+
+#[test]
+fn styles_resolve_alongside_colors() {
+    let toml = r##"
+[base]
+background = "#000000"
+
+[syntax]
+keywords = { fg = "#cba6f7", bold = true, italic = true }
+strings = "#a6e3a1"
+
+[typography]
+link = { fg = "#89dceb", underline = true }
+"##;
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+
+    assert_eq!(palette.syntax.keywords.unwrap().to_hex(), "#CBA6F7");
+    let keywords = palette.styles.syntax.keywords.unwrap();
+    assert!(keywords.bold && keywords.italic && !keywords.underline);
+    assert!(palette.styles.syntax.strings.is_none());
+    assert!(palette.styles.get("typography", "link").unwrap().underline);
+}
+
+#[test]
+fn styled_entry_with_invalid_fg_reports_field() {
+    let toml = r##"
+[base]
+
+[syntax]
+keywords = { fg = "purple", bold = true }
+"##;
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let err = Palette::from_manifest(&manifest).unwrap_err();
+    assert!(matches!(
+        &err,
+        PaletteError::InvalidHex { section, field, .. }
+            if section.as_ref() == "syntax" && field.as_ref() == "keywords"
+    ));
+}
+
+const CUSTOM_TOML: &str = r##"
+[base]
+background = "#000000"
+
+[custom.app]
+sidebar_badge = "#ff8800"
+
+[custom.chart]
+series_1 = "#ff0000"
+series_2 = "#00ff00"
+"##;
+
+#[test]
+fn custom_sections_resolve_as_colors() {
+    let manifest = PaletteManifest::from_toml(CUSTOM_TOML).unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+
+    assert_eq!(palette.custom("chart", "series_1").unwrap().to_hex(), "#FF0000");
+    assert_eq!(palette.slot("app", "sidebar_badge").unwrap().to_hex(), "#FF8800");
+    assert!(palette.custom("chart", "series_3").is_none());
+    assert!(palette.custom("base", "background").is_none());
+}
+
+#[test]
+fn custom_section_invalid_hex_reports_section() {
+    let manifest = PaletteManifest::from_toml("[base]\n[custom.chart]\nseries_1 = \"red\"\n").unwrap();
+    let err = Palette::from_manifest(&manifest).unwrap_err();
+    assert!(matches!(
+        &err,
+        PaletteError::InvalidHex { section, field, .. }
+            if section.as_ref() == "chart" && field.as_ref() == "series_1"
+    ));
+}
+
+#[test]
+fn terminal_slots_are_indexed_in_ansi_order() {
+    let palette = palette_core::preset("tokyonight").unwrap();
+    let indexed: Vec<_> = palette.terminal_ansi.indexed().collect();
+    assert_eq!(indexed.len(), 16);
+    assert_eq!((indexed[0].0, indexed[0].1), (0, "black"));
+    assert_eq!((indexed[8].0, indexed[8].1), (8, "bright_black"));
+    assert_eq!((indexed[15].0, indexed[15].1), (15, "bright_white"));
+    assert_eq!(indexed[1].2, palette.terminal_ansi.red.as_ref());
+}
@@ -141,8 +141,37 @@ inherits = "no_such_preset"
 background = "#000000"
 "##;
 
+const ANSI_FRAGMENT_TOML: &str = r##"
+[terminal]
+black = "#101010"
+red = "#ff0000"
+
+[semantic]
+error = "#ff0000"
+"##;
+
+const INCLUDING_TOML: &str = r##"
+include = ["../shared/ansi.toml"]
+
+[meta]
+name = "Including Theme"
+preset_id = "including"
+schema_version = "1"
+style = "dark"
+kind = "preset-base"
+
+[base]
+background = "#1a1b2a"
+
+[semantic]
+error = "#cc0000"
+"##;
+
 fn write_temp_file(dir: &tempfile::TempDir, name: &str, content: &str) -> std::path::PathBuf {
     let path = dir.path().join(name);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).unwrap();
+    }
     let mut f = std::fs::File::create(&path).unwrap();
     f.write_all(content.as_bytes()).unwrap();
     path
@@ -408,3 +437,142 @@ fn registry_builtin_metadata_matches_expected() {
         }
     );
 }
+
+// ---------------------------------------------------------------------------
+// Include tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn file_preset_merges_included_fragment() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "shared/ansi.toml", ANSI_FRAGMENT_TOML);
+    let path = write_temp_file(&dir, "themes/including.toml", INCLUDING_TOML);
+
+    let palette = load_preset_file(&path).unwrap();
+    assert_eq!(
+        palette.terminal_ansi.red,
+        Some(Color::from_hex("#ff0000").unwrap()),
+        "terminal colors come from the fragment"
+    );
+    assert_eq!(
+        palette.semantic.error,
+        Some(Color::from_hex("#cc0000").unwrap()),
+        "including file overrides fragment keys"
+    );
+}
+
+#[test]
+fn later_include_overrides_earlier() {
+    let toml = r##"
+include = ["a.toml", "b.toml"]
+
+[base]
+background = "#000000"
+"##;
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "a.toml", "[terminal]\nred = \"#aa0000\"\nblack = \"#000000\"\n");
+    write_temp_file(&dir, "b.toml", "[terminal]\nred = \"#bb0000\"\n");
+    let path = write_temp_file(&dir, "theme.toml", toml);
+
+    let palette = load_preset_file(&path).unwrap();
+    assert_eq!(palette.terminal_ansi.red, Some(Color::from_hex("#bb0000").unwrap()));
+    assert_eq!(palette.terminal_ansi.black, Some(Color::from_hex("#000000").unwrap()));
+}
+
+#[test]
+fn nested_include_resolves_relative_to_fragment() {
+    let toml = r##"
+include = ["shared/ui.toml"]
+
+[base]
+background = "#000000"
+"##;
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "shared/ui.toml", "include = [\"ansi.toml\"]\n");
+    write_temp_file(&dir, "shared/ansi.toml", ANSI_FRAGMENT_TOML);
+    let path = write_temp_file(&dir, "theme.toml", toml);
+
+    let palette = load_preset_file(&path).unwrap();
+    assert_eq!(palette.terminal_ansi.black, Some(Color::from_hex("#101010").unwrap()));
+}
+
+#[test]
+fn missing_include_returns_io_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_temp_file(&dir, "themes/including.toml", INCLUDING_TOML);
+
+    let result = load_preset_file(&path);
+    assert!(matches!(result, Err(PaletteError::Io { .. })));
+}
+
+#[test]
+fn include_cycle_returns_error() {
+    let toml = r##"
+include = ["loop.toml"]
+
+[base]
+background = "#000000"
+"##;
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "loop.toml", "include = [\"theme.toml\"]\n");
+    let path = write_temp_file(&dir, "theme.toml", toml);
+
+    let result = load_preset_file(&path);
+    assert!(matches!(result, Err(PaletteError::IncludeCycle(_))));
+}
+
+#[test]
+fn registry_add_file_resolves_includes() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "shared/ansi.toml", ANSI_FRAGMENT_TOML);
+    let path = write_temp_file(&dir, "themes/including.toml", INCLUDING_TOML);
+
+    let mut reg = Registry::new();
+    reg.add_file(&path).unwrap();
+
+    let palette = reg.load("including").unwrap();
+    assert_eq!(palette.terminal_ansi.black, Some(Color::from_hex("#101010").unwrap()));
+    assert_eq!(palette.semantic.error, Some(Color::from_hex("#cc0000").unwrap()));
+}
+
+#[test]
+fn registry_add_toml_with_include_fails_on_load() {
+    let mut reg = Registry::new();
+    reg.add_toml(INCLUDING_TOML.to_owned()).unwrap();
+
+    let result = reg.load("including");
+    assert!(matches!(result, Err(PaletteError::UnresolvedInclude(_))));
+}
+
+// ---------------------------------------------------------------------------
+// Derived high-contrast themes
+// ---------------------------------------------------------------------------
+
+#[test]
+fn hc_suffix_loads_derived_variant() {
+    let hc = load_preset("nord+hc").unwrap();
+    assert_eq!(hc.meta.unwrap().preset_id.as_ref(), "nord+hc");
+    assert!(preset("nord+hc").is_some());
+    assert!(matches!(load_preset("nonexistent+hc"), Err(PaletteError::UnknownPreset(_))));
+}
+
+#[test]
+fn registry_loads_hc_variant_of_custom_theme() {
+    let mut reg = Registry::new();
+    reg.add_toml(MINIMAL_TOML.to_owned()).unwrap();
+
+    let hc = reg.load("test_theme+hc").unwrap();
+    assert_eq!(hc.meta.unwrap().preset_id.as_ref(), "test_theme+hc");
+    assert_eq!(hc.base.background, Some(Color::from_hex("#1a1b2a").unwrap()));
+}
+
+#[test]
+fn registry_lists_hc_variants_separately() {
+    let reg = Registry::new();
+    let variants: Vec<_> = reg.high_contrast_variants().collect();
+    assert_eq!(variants.len(), reg.list().count());
+
+    let nord = variants.iter().find(|t| t.id.as_ref() == "nord+hc").unwrap();
+    assert_eq!(nord.name.as_ref(), "Nord (High Contrast)");
+    assert!(reg.load(&nord.id).is_ok());
+}
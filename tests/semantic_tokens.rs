@@ -0,0 +1,107 @@
+use palette_core::color::Color;
+use palette_core::error::PaletteError;
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::semantic_tokens::semantic_token_slot;
+
+fn color(hex: &str) -> Color {
+    Color::from_hex(hex).unwrap()
+}
+
+fn palette(toml: &str) -> Palette {
+    Palette::from_manifest(&PaletteManifest::from_toml(toml).unwrap()).unwrap()
+}
+
+#[test]
+fn token_types_map_to_syntax_slots() {
+    assert_eq!(semantic_token_slot("namespace", &[]), Some("types"));
+    assert_eq!(semantic_token_slot("enumMember", &[]), Some("constants"));
+    assert_eq!(semantic_token_slot("parameter", &["declaration"]), Some("parameters"));
+    assert_eq!(semantic_token_slot("label", &[]), None);
+}
+
+#[test]
+fn modifiers_select_more_specific_slots() {
+    assert_eq!(semantic_token_slot("variable", &["defaultLibrary"]), Some("variables_builtin"));
+    assert_eq!(semantic_token_slot("type", &["defaultLibrary"]), Some("types_builtin"));
+    assert_eq!(semantic_token_slot("variable", &["readonly"]), Some("constants"));
+    assert_eq!(semantic_token_slot("variable", &["static"]), Some("variables"));
+}
+
+const OVERRIDES_TOML: &str = r##"
+[base]
+
+[syntax]
+variables = "#c0caf5"
+variables_builtin = "#f7768e"
+properties = "#73daca"
+constants = "#ff9e64"
+functions = "#7aa2f7"
+
+[semantic_tokens]
+variable = "properties"
+"function.deprecated" = "constants"
+"##;
+
+#[test]
+fn manifest_rules_override_by_specificity() {
+    let p = palette(OVERRIDES_TOML);
+
+    assert_eq!(p.semantic_token_slot("variable", &[]), Some("properties"));
+    assert_eq!(p.semantic_token_slot("variable", &["defaultLibrary"]), Some("variables_builtin"));
+    assert_eq!(p.semantic_token_slot("function", &["deprecated"]), Some("constants"));
+    assert_eq!(p.semantic_token_slot("function", &[]), Some("functions"));
+}
+
+#[test]
+fn token_color_and_style_resolve() {
+    let p = palette(OVERRIDES_TOML);
+    assert_eq!(p.semantic_token_color("variable", &["defaultLibrary"]), Some(color("#f7768e")));
+    assert_eq!(p.semantic_token_color("type", &[]), None);
+
+    let deprecated = p.semantic_token_style("method", &["deprecated"]);
+    assert!(deprecated.strikethrough);
+    assert!(p.semantic_token_style("method", &[]).is_plain());
+}
+
+#[test]
+fn unknown_slot_in_rules_is_rejected() {
+    let manifest = PaletteManifest::from_toml("[base]\n[semantic_tokens]\nvariable = \"nope\"\n").unwrap();
+    let err = Palette::from_manifest(&manifest).unwrap_err();
+    assert!(matches!(
+        &err,
+        PaletteError::UnknownSlot { field, value, .. }
+            if field.as_ref() == "variable" && value.as_ref() == "nope"
+    ));
+}
+
+#[test]
+fn malformed_selector_is_rejected() {
+    for selector in ["x\", {}) os.execute(\"touch /tmp/pwned\") hl(0, \"y", "variable.", ".readonly", "a b", "*.read*"] {
+        let toml = format!("[base]\n[semantic_tokens]\n{} = \"variables\"\n", toml::Value::from(selector));
+        let manifest = PaletteManifest::from_toml(&toml).unwrap();
+        let err = Palette::from_manifest(&manifest).unwrap_err();
+        assert!(matches!(&err, PaletteError::InvalidSelector(s) if s.as_ref() == selector), "{selector}: {err}");
+    }
+
+    let manifest = PaletteManifest::from_toml("[base]\n[semantic_tokens]\n\"*.readonly\" = \"constants\"\n").unwrap();
+    assert!(Palette::from_manifest(&manifest).is_ok());
+}
+
+#[test]
+fn semantic_tokens_section_is_not_custom() {
+    let manifest = PaletteManifest::from_toml(OVERRIDES_TOML).unwrap();
+    assert!(manifest.custom.is_empty());
+    assert_eq!(manifest.semantic_tokens.len(), 2);
+}
+
+#[test]
+fn rules_inherit_through_merge() {
+    use palette_core::merge::merge_manifests;
+
+    let base = PaletteManifest::from_toml(OVERRIDES_TOML).unwrap();
+    let variant = PaletteManifest::from_toml("[base]\n[semantic_tokens]\nvariable = \"constants\"\n").unwrap();
+    let merged = merge_manifests(&variant, &base);
+    assert_eq!(&*merged.semantic_tokens["variable"], "constants");
+    assert!(merged.semantic_tokens.contains_key("function.deprecated"));
+}
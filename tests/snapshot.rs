@@ -48,3 +48,31 @@ fn snapshot_omits_none_colors() {
     assert!(base.get("background").unwrap().is_string());
     assert!(base.get("foreground").unwrap().is_null());
 }
+
+#[test]
+fn snapshot_includes_only_set_styles() {
+    let manifest = palette_core::manifest::PaletteManifest::from_toml(
+        "[base]\n[syntax]\nkeywords = { fg = \"#cba6f7\", bold = true }\n",
+    )
+    .unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let value = to_json_value(&palette).unwrap();
+
+    let syntax = value.get("styles").unwrap().get("syntax").unwrap();
+    assert_eq!(syntax.get("keywords").unwrap().get("bold").unwrap(), true);
+    assert!(syntax.get("strings").is_none());
+}
+
+#[test]
+fn snapshot_includes_custom_sections() {
+    let manifest = palette_core::manifest::PaletteManifest::from_toml(
+        "[base]\n[custom.chart]\nseries_1 = \"#ff0000\"\n",
+    )
+    .unwrap();
+    let value = to_json_value(&Palette::from_manifest(&manifest).unwrap()).unwrap();
+    let series = value.get("custom").unwrap().get("chart").unwrap().get("series_1").unwrap();
+    assert_eq!(series, "#FF0000");
+
+    let plain = to_json_value(&Palette::from_manifest(&common::manifest_with_base(Default::default())).unwrap()).unwrap();
+    assert!(plain.get("custom").is_none());
+}
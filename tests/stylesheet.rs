@@ -0,0 +1,141 @@
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::preset;
+use palette_core::stylesheet::{CodeClasses, ColorValues};
+
+mod common;
+
+/// Declarations of the rule whose selector list starts with `selector`.
+fn rule<'a>(css: &'a str, selector: &str) -> Option<&'a str> {
+    let start = css.find(&format!("{selector} {{")).or_else(|| css.find(&format!("{selector},\n")))?;
+    let body = &css[start..];
+    let open = body.find('{')?;
+    let close = body.find('}')?;
+    Some(&body[open + 1..close])
+}
+
+fn sparse() -> Palette {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#1A1B26"
+foreground = "#C0CAF5"
+
+[syntax]
+comments = { fg = "#565F89", italic = true }
+keywords = { fg = "#BB9AF7", bold = true, undercurl = true }
+strings = "#9ECE6A"
+"##,
+    )
+    .unwrap();
+    Palette::from_manifest(&manifest).unwrap()
+}
+
+#[test]
+fn variables_reference_css_custom_properties() {
+    let palette = sparse();
+    let css = palette.to_code_stylesheet(CodeClasses::HighlightJs, ColorValues::Variables(None));
+
+    let container = rule(&css, ".hljs").unwrap();
+    assert!(container.contains("color: var(--fg);"));
+    assert!(container.contains("background: var(--bg);"));
+
+    let keyword = rule(&css, ".hljs-keyword").unwrap();
+    assert!(keyword.contains("color: var(--syn-keyword);"));
+    assert!(keyword.contains("font-weight: var(--syn-keyword-font-weight, normal);"));
+    assert!(keyword.contains("text-decoration: var(--syn-keyword-text-decoration, none);"));
+
+    let built_in = rule(&css, ".hljs-built_in").unwrap();
+    assert!(built_in.contains("color: var(--syn-type-builtin, var(--syn-type));"));
+}
+
+#[test]
+fn variables_honor_prefix_and_cover_unset_slots() {
+    let palette = sparse();
+    let css = palette.to_code_stylesheet(CodeClasses::Prism, ColorValues::Variables(Some("pc")));
+
+    assert!(css.contains("code[class*=\"language-\"],\npre[class*=\"language-\"] {"));
+    assert!(rule(&css, ".token.keyword").unwrap().contains("color: var(--pc-syn-keyword);"));
+    // Functions are unset here, but a variable stylesheet follows whichever palette is active.
+    assert!(rule(&css, ".token.function").unwrap().contains("var(--pc-syn-fn)"));
+    assert!(!css.contains("var(--syn-"));
+}
+
+#[test]
+fn variable_styles_follow_the_slot_that_supplies_the_color() {
+    let palette = sparse();
+    let css = palette.to_code_stylesheet(CodeClasses::HighlightJs, ColorValues::Variables(None));
+
+    // Doc strings are unset, so both color and style come from comments.
+    let doctag = rule(&css, ".hljs-doctag").unwrap();
+    assert!(doctag.contains("color: var(--syn-comment);"));
+    assert!(doctag.contains("font-style: var(--syn-comment-font-style, normal);"));
+
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#1A1B26"
+
+[syntax]
+strings_doc = "#7AA2F7"
+comments = { fg = "#565F89", italic = true }
+"##,
+    )
+    .unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let css = palette.to_code_stylesheet(CodeClasses::HighlightJs, ColorValues::Variables(None));
+
+    // Doc strings are set and plain: comments' italic must not leak in.
+    let doctag = rule(&css, ".hljs-doctag").unwrap();
+    assert!(doctag.contains("color: var(--syn-string-doc, var(--syn-comment));"));
+    assert!(doctag.contains("font-style: var(--syn-string-doc-font-style, normal);"));
+    assert!(!doctag.contains("--syn-comment-font"));
+}
+
+#[test]
+fn literal_colors_and_styles() {
+    let palette = sparse();
+    let css = palette.to_code_stylesheet(CodeClasses::Pygments, ColorValues::Literal);
+
+    let container = rule(&css, ".highlight").unwrap();
+    assert!(container.contains("color: #C0CAF5;"));
+    assert!(container.contains("background: #1A1B26;"));
+
+    let keyword = rule(&css, ".highlight .k").unwrap();
+    assert!(keyword.contains("color: #BB9AF7;"));
+    assert!(keyword.contains("font-weight: bold;"));
+    assert!(keyword.contains("text-decoration: underline wavy;"));
+    assert!(!keyword.contains("font-style"));
+
+    let comment = rule(&css, ".highlight .c").unwrap();
+    assert!(comment.contains("font-style: italic;"));
+    assert!(!comment.contains("font-weight"));
+
+    // Doc strings fall back to strings.
+    assert!(rule(&css, ".highlight .sd").unwrap().contains("color: #9ECE6A;"));
+    assert!(rule(&css, ".highlight .nf").is_none());
+}
+
+#[test]
+fn emphasis_rules_are_always_written() {
+    let palette = sparse();
+    for classes in CodeClasses::ALL {
+        let css = palette.to_code_stylesheet(classes, ColorValues::Literal);
+        assert!(css.contains("font-style: italic;\n}\n"), "{}", classes.name());
+        assert!(css.ends_with("font-weight: bold;\n}\n"), "{}", classes.name());
+    }
+}
+
+#[test]
+fn braces_balance_for_every_preset() {
+    for id in palette_core::preset_ids() {
+        let palette = preset(id).unwrap();
+        for classes in CodeClasses::ALL {
+            for values in [ColorValues::Literal, ColorValues::Variables(None)] {
+                let css = palette.to_code_stylesheet(classes, values);
+                assert_eq!(css.matches('{').count(), css.matches('}').count(), "{id} {}", classes.name());
+                assert!(css.contains("keyword") || css.contains(".highlight .k,"), "{id} {}", classes.name());
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "syntect")]
+
+use syntect::highlighting::{Color as SyntectColor, FontStyle, Highlighter};
+use syntect::parsing::Scope;
+
+use palette_core::color::Color;
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::preset;
+use palette_core::style::TextStyle;
+use palette_core::syntect::{to_syntect_color, to_syntect_font_style, to_syntect_theme};
+
+mod common;
+
+fn style_for(palette: &Palette, scope: &str) -> syntect::highlighting::Style {
+    let theme = to_syntect_theme(palette);
+    Highlighter::new(&theme).style_for_stack(&[Scope::new(scope).unwrap()])
+}
+
+fn syntect(color: Option<Color>) -> SyntectColor {
+    to_syntect_color(&color.unwrap())
+}
+
+#[test]
+fn color_is_opaque_rgb() {
+    let color = Color { r: 26, g: 27, b: 42 };
+    assert_eq!(to_syntect_color(&color), SyntectColor { r: 26, g: 27, b: 42, a: 0xFF });
+}
+
+#[test]
+fn font_style_flags() {
+    let style = TextStyle { bold: true, undercurl: true, strikethrough: true, ..TextStyle::default() };
+    assert_eq!(to_syntect_font_style(&style), FontStyle::BOLD | FontStyle::UNDERLINE);
+    assert_eq!(to_syntect_font_style(&TextStyle::default()), FontStyle::empty());
+}
+
+#[test]
+fn settings_come_from_base_and_editor_slots() {
+    let palette = preset("tokyonight").unwrap();
+    let theme = to_syntect_theme(&palette);
+
+    assert_eq!(theme.name.as_deref(), Some("TokyoNight (Night)"));
+    assert_eq!(theme.settings.background, Some(syntect(palette.base.background)));
+    assert_eq!(theme.settings.foreground, Some(syntect(palette.base.foreground)));
+    assert_eq!(theme.settings.caret, Some(syntect(palette.editor.cursor.or(palette.base.foreground))));
+    assert_eq!(theme.settings.gutter, Some(syntect(palette.base.background)));
+    assert_eq!(
+        theme.settings.gutter_foreground,
+        Some(syntect(palette.typography.line_number.or(palette.typography.gutter))),
+    );
+}
+
+#[test]
+fn scopes_resolve_to_syntax_slots() {
+    let palette = preset("tokyonight").unwrap();
+
+    assert_eq!(style_for(&palette, "keyword.control.rust").foreground, syntect(palette.syntax.keywords));
+    assert_eq!(style_for(&palette, "keyword.operator.arithmetic").foreground, syntect(palette.syntax.operators));
+    assert_eq!(style_for(&palette, "constant.numeric.integer").foreground, syntect(palette.syntax.numbers));
+    assert_eq!(style_for(&palette, "string.quoted.double").foreground, syntect(palette.syntax.strings));
+    assert_eq!(style_for(&palette, "source.rust").foreground, syntect(palette.base.foreground));
+}
+
+#[test]
+fn styled_slots_set_font_style() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#000000"
+
+[syntax]
+comments = { fg = "#565F89", italic = true }
+strings = "#9ECE6A"
+"##,
+    )
+    .unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+
+    assert_eq!(style_for(&palette, "comment.line").font_style, FontStyle::ITALIC);
+    assert_eq!(style_for(&palette, "string.quoted").font_style, FontStyle::empty());
+}
@@ -0,0 +1,100 @@
+use std::collections::BTreeSet;
+
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::preset;
+
+mod common;
+
+fn sparse() -> Palette {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#1A1B26"
+foreground = "#C0CAF5"
+
+[surface]
+selection = "#283457"
+
+[syntax]
+keywords = "#BB9AF7"
+
+[custom.chart]
+series_1 = "#FF0000"
+"##,
+    )
+    .unwrap();
+    Palette::from_manifest(&manifest).unwrap()
+}
+
+#[test]
+fn config_extends_colors_with_alpha_value() {
+    let config: serde_json::Value = serde_json::from_str(&sparse().to_tailwind_config(None)).unwrap();
+    let colors = config["theme"]["extend"]["colors"].as_object().unwrap();
+
+    let keys: Vec<&str> = colors.keys().map(String::as_str).collect();
+    for key in ["bg", "fg", "ui-sel", "syn-keyword", "custom-chart-series-1"] {
+        assert!(keys.contains(&key), "missing {key}");
+    }
+    assert_eq!(colors.len(), 5);
+    assert_eq!(colors["syn-keyword"], "color-mix(in srgb, var(--syn-keyword) calc(<alpha-value> * 100%), transparent)");
+}
+
+#[test]
+fn config_honors_prefix() {
+    let config: serde_json::Value = serde_json::from_str(&sparse().to_tailwind_config(Some("pc"))).unwrap();
+    let bg = config["theme"]["extend"]["colors"]["bg"].as_str().unwrap();
+    assert!(bg.contains("var(--pc-bg)"), "{bg}");
+}
+
+#[test]
+fn keys_match_css_variables() {
+    for palette in [preset("tokyonight").unwrap(), sparse()] {
+        let css = palette.to_css();
+        let variables: BTreeSet<&str> = css
+            .lines()
+            .filter_map(|line| line.strip_prefix("  --")?.split_once(": #"))
+            .map(|(name, _)| name)
+            .collect();
+        let config: serde_json::Value = serde_json::from_str(&palette.to_tailwind_config(None)).unwrap();
+        let colors = config["theme"]["extend"]["colors"].as_object().unwrap();
+        let keys: BTreeSet<&str> = colors.keys().map(String::as_str).collect();
+        assert_eq!(keys, variables);
+    }
+}
+
+#[test]
+fn v4_theme_block() {
+    let theme = sparse().to_tailwind_theme(Some("pc"));
+    assert!(theme.starts_with("@theme inline {\n"));
+    assert!(theme.contains("  --color-bg: var(--pc-bg);\n"));
+    assert!(theme.contains("  --color-syn-keyword: var(--pc-syn-keyword);\n"));
+    assert!(theme.contains("  --color-custom-chart-series-1: var(--pc-custom-chart-series-1);\n"));
+    assert!(theme.ends_with("}\n"));
+}
+
+#[test]
+fn unocss_theme_uses_alpha_placeholder() {
+    let theme: serde_json::Value = serde_json::from_str(&sparse().to_unocss_theme(None)).unwrap();
+    let fg = theme["theme"]["colors"]["fg"].as_str().unwrap();
+    assert_eq!(fg, "color-mix(in srgb, var(--fg) calc(%alpha * 100%), transparent)");
+}
+
+#[test]
+fn empty_palette_produces_valid_json() {
+    let palette = Palette::from_manifest(&common::manifest_with_base(Default::default())).unwrap();
+    let config: serde_json::Value = serde_json::from_str(&palette.to_tailwind_config(None)).unwrap();
+    assert!(config["theme"]["extend"]["colors"].as_object().unwrap().is_empty());
+    assert_eq!(palette.to_tailwind_theme(None), "@theme inline {\n}\n");
+}
+
+#[test]
+fn every_preset_exports_valid_json() {
+    for id in palette_core::preset_ids() {
+        let palette = preset(id).unwrap();
+        let config: Result<serde_json::Value, _> = serde_json::from_str(&palette.to_tailwind_config(None));
+        assert!(config.is_ok(), "{id}");
+        let uno: Result<serde_json::Value, _> = serde_json::from_str(&palette.to_unocss_theme(None));
+        assert!(uno.is_ok(), "{id}");
+    }
+}
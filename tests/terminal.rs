@@ -75,3 +75,174 @@ fn terminal_ansi_maps_all_16_colors() {
     assert!(theme.terminal_ansi.bright_cyan.is_some());
     assert!(theme.terminal_ansi.bright_white.is_some());
 }
+
+#[test]
+fn interpolated_theme_matches_endpoints() {
+    use palette_core::terminal::interpolate_terminal_theme;
+
+    let a = common::load_preset("tokyonight");
+    let b = common::load_preset("nord");
+    let a = Palette::from_manifest(&a).unwrap();
+    let b = Palette::from_manifest(&b).unwrap();
+
+    let start = interpolate_terminal_theme(&a, &b, 0.0);
+    let end = interpolate_terminal_theme(&a, &b, 1.0);
+    assert_eq!(start.base.background, to_terminal_theme(&a).base.background);
+    assert_eq!(end.base.background, to_terminal_theme(&b).base.background);
+}
+
+#[test]
+fn text_styles_map_to_modifiers() {
+    use palette_core::manifest::PaletteManifest;
+    use palette_core::style::TextStyle;
+    use palette_core::terminal::to_ratatui_modifier;
+    use ratatui::style::Modifier;
+
+    let style = TextStyle { bold: true, undercurl: true, ..TextStyle::default() };
+    assert_eq!(to_ratatui_modifier(&style), Modifier::BOLD | Modifier::UNDERLINED);
+    assert_eq!(to_ratatui_modifier(&TextStyle::default()), Modifier::empty());
+
+    let manifest = PaletteManifest::from_toml(
+        "[base]\n[syntax]\ncomments = { fg = \"#6c7086\", italic = true, strikethrough = true }\n",
+    )
+    .unwrap();
+    let theme = to_terminal_theme(&Palette::from_manifest(&manifest).unwrap());
+    assert_eq!(theme.modifiers.syntax.comments, Modifier::ITALIC | Modifier::CROSSED_OUT);
+    assert_eq!(theme.modifiers.syntax.keywords, Modifier::empty());
+}
+
+#[test]
+fn detect_color_depth_from_env_values() {
+    use palette_core::terminal::{detect_color_depth, ColorDepth};
+
+    assert_eq!(detect_color_depth(Some("truecolor"), Some("xterm-256color")), ColorDepth::TrueColor);
+    assert_eq!(detect_color_depth(Some("24bit"), None), ColorDepth::TrueColor);
+    assert_eq!(detect_color_depth(None, Some("xterm-direct")), ColorDepth::TrueColor);
+    assert_eq!(detect_color_depth(None, Some("tmux-256color")), ColorDepth::Ansi256);
+    assert_eq!(detect_color_depth(Some(""), Some("xterm-256color")), ColorDepth::Ansi256);
+    assert_eq!(detect_color_depth(None, Some("linux")), ColorDepth::Ansi16);
+    assert_eq!(detect_color_depth(None, None), ColorDepth::Ansi16);
+}
+
+#[test]
+fn ansi256_picks_exact_cube_and_gray_entries() {
+    use palette_core::terminal::to_ansi256;
+
+    assert_eq!(to_ansi256(&Color { r: 255, g: 0, b: 0 }), 196);
+    assert_eq!(to_ansi256(&Color { r: 0, g: 0, b: 0 }), 16);
+    assert_eq!(to_ansi256(&Color { r: 0x80, g: 0x80, b: 0x80 }), 244);
+    assert_eq!(to_ansi256(&Color { r: 95, g: 135, b: 175 }), 67);
+}
+
+#[test]
+fn ansi16_matches_palette_ansi_entries() {
+    use palette_core::terminal::to_ansi16;
+
+    let palette = Palette::from_manifest(&common::load_preset("tokyonight")).unwrap();
+    let ansi = &palette.terminal_ansi;
+
+    assert_eq!(to_ansi16(&ansi.red.unwrap(), ansi), RatatuiColor::Red);
+    assert_eq!(to_ansi16(&ansi.bright_blue.unwrap(), ansi), RatatuiColor::LightBlue);
+    assert_eq!(to_ansi16(&palette.base.background.unwrap(), ansi), RatatuiColor::Black);
+}
+
+#[test]
+fn ansi16_uses_xterm_defaults_when_unset() {
+    use palette_core::palette::TerminalAnsiColors;
+    use palette_core::terminal::to_ansi16;
+
+    let empty = TerminalAnsiColors::default();
+    assert_eq!(to_ansi16(&Color { r: 250, g: 10, b: 10 }, &empty), RatatuiColor::LightRed);
+    assert_eq!(to_ansi16(&Color { r: 250, g: 250, b: 250 }, &empty), RatatuiColor::White);
+}
+
+#[test]
+fn theme_with_depth_quantizes_every_slot() {
+    use palette_core::terminal::{to_terminal_theme_with_depth, ColorDepth};
+
+    let palette = Palette::from_manifest(&common::load_preset("tokyonight")).unwrap();
+
+    let true_color = to_terminal_theme_with_depth(&palette, ColorDepth::TrueColor);
+    assert_eq!(true_color.base.background, to_terminal_theme(&palette).base.background);
+
+    let indexed = to_terminal_theme_with_depth(&palette, ColorDepth::Ansi256);
+    assert!(matches!(indexed.base.background, Some(RatatuiColor::Indexed(16..=255))));
+    assert!(matches!(indexed.syntax.keywords, Some(RatatuiColor::Indexed(_))));
+
+    let named = to_terminal_theme_with_depth(&palette, ColorDepth::Ansi16);
+    assert_eq!(named.terminal_ansi.green, Some(RatatuiColor::Green));
+    assert!(!matches!(named.syntax.strings, Some(RatatuiColor::Rgb(..) | RatatuiColor::Indexed(_))));
+}
+
+#[test]
+fn terminal_styles_pair_roles() {
+    use palette_core::terminal::to_terminal_styles;
+    use ratatui::style::Modifier;
+
+    let palette = Palette::from_manifest(&common::load_preset("tokyonight")).unwrap();
+    let styles = to_terminal_styles(&palette);
+    let rgb = |c: Option<Color>| c.map(|c| to_ratatui_color(&c));
+
+    assert_eq!(styles.text.fg, rgb(palette.base.foreground));
+    assert_eq!(styles.text.bg, rgb(palette.base.background));
+    let selection = palette.selection_pair();
+    assert_eq!((styles.selection.fg, styles.selection.bg), (rgb(selection.fg), rgb(selection.bg)));
+    assert_eq!(styles.diff_added.bg, rgb(palette.diff_added_pair().bg));
+    assert_eq!(styles.error.fg, rgb(palette.editor.diagnostic_error.or(palette.semantic.error)));
+    assert!(styles.title.add_modifier.contains(Modifier::BOLD));
+    assert!(styles.list_highlight().add_modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn terminal_styles_fall_back_on_sparse_palette() {
+    use palette_core::terminal::TerminalStyles;
+
+    let manifest = common::manifest_with_base(BTreeMap::from([
+        (Arc::from("background"), Arc::from("#000000")),
+        (Arc::from("foreground"), Arc::from("#ffffff")),
+    ]));
+    let styles = TerminalStyles::from_palette(&Palette::from_manifest(&manifest).unwrap());
+
+    assert_eq!(styles.selection.fg, Some(RatatuiColor::Rgb(255, 255, 255)));
+    assert_eq!(styles.selection.bg, Some(RatatuiColor::Rgb(0, 0, 0)));
+    assert_eq!(styles.error.fg, None);
+    assert_eq!(styles.error.bg, Some(RatatuiColor::Rgb(0, 0, 0)));
+}
+
+#[test]
+fn terminal_styles_respect_color_depth() {
+    use palette_core::terminal::{ColorDepth, TerminalStyles};
+
+    let palette = Palette::from_manifest(&common::load_preset("tokyonight")).unwrap();
+    let styles = TerminalStyles::from_palette_with_depth(&palette, ColorDepth::Ansi256);
+    assert!(matches!(styles.text.fg, Some(RatatuiColor::Indexed(_))));
+    assert!(matches!(styles.border_focused.fg, Some(RatatuiColor::Indexed(_))));
+}
+
+#[test]
+fn widget_helpers_render_with_theme_styles() {
+    use palette_core::terminal::to_terminal_styles;
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    let palette = Palette::from_manifest(&common::load_preset("tokyonight")).unwrap();
+    let styles = to_terminal_styles(&palette);
+    let area = Rect::new(0, 0, 10, 3);
+
+    let mut buf = Buffer::empty(area);
+    styles.block(true).render(area, &mut buf);
+    assert_eq!(buf[(0, 0)].fg, styles.border_focused.fg.unwrap());
+
+    let mut buf = Buffer::empty(area);
+    styles.block(false).render(area, &mut buf);
+    assert_eq!(buf[(0, 0)].fg, styles.border.fg.unwrap());
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+    styles.tabs(["one", "two"]).select(0).render(buf.area, &mut buf);
+    assert_eq!(buf[(1, 0)].bg, styles.selection.bg.unwrap());
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+    styles.gauge().ratio(1.0).render(buf.area, &mut buf);
+    assert_eq!(buf[(0, 0)].fg, styles.gauge.fg.unwrap());
+}
@@ -0,0 +1,181 @@
+use palette_core::error::PaletteError;
+use palette_core::palette::Palette;
+use palette_core::textmate::{from_tmtheme, parse_font_style, scope_slot};
+use palette_core::Registry;
+
+mod common;
+
+const MONOKAI: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+  <key>name</key>
+  <string>Monokai</string>
+  <key>settings</key>
+  <array>
+    <dict>
+      <key>settings</key>
+      <dict>
+        <key>background</key>
+        <string>#272822</string>
+        <key>foreground</key>
+        <string>#F8F8F2</string>
+        <key>caret</key>
+        <string>#F8F8F0</string>
+        <key>selection</key>
+        <string>#49483E</string>
+        <key>lineHighlight</key>
+        <string>#3E3D32</string>
+        <key>invisibles</key>
+        <string>#3B3A32</string>
+      </dict>
+    </dict>
+    <!-- scoped rules -->
+    <dict>
+      <key>name</key>
+      <string>Comment</string>
+      <key>scope</key>
+      <string>comment</string>
+      <key>settings</key>
+      <dict>
+        <key>foreground</key>
+        <string>#75715E</string>
+        <key>fontStyle</key>
+        <string>italic</string>
+      </dict>
+    </dict>
+    <dict>
+      <key>scope</key>
+      <string>constant.numeric</string>
+      <key>settings</key>
+      <dict>
+        <key>foreground</key>
+        <string>#AE81FF</string>
+      </dict>
+    </dict>
+    <dict>
+      <key>scope</key>
+      <string>keyword, storage.type</string>
+      <key>settings</key>
+      <dict>
+        <key>foreground</key>
+        <string>#F92672</string>
+      </dict>
+    </dict>
+    <dict>
+      <key>scope</key>
+      <string>entity.name.function.rust</string>
+      <key>settings</key>
+      <dict>
+        <key>foreground</key>
+        <string>#A6E22E</string>
+      </dict>
+    </dict>
+    <dict>
+      <key>scope</key>
+      <string>invalid</string>
+      <key>settings</key>
+      <dict>
+        <key>foreground</key>
+        <string>#F8F8F0</string>
+        <key>background</key>
+        <string>#F92672</string>
+      </dict>
+    </dict>
+  </array>
+</dict>
+</plist>
+"#;
+
+#[test]
+fn scope_slot_prefers_exact_then_longest_prefix() {
+    assert_eq!(scope_slot("keyword"), Some(("syntax", "keywords")));
+    assert_eq!(scope_slot("keyword.operator"), Some(("syntax", "operators")));
+    assert_eq!(scope_slot("keyword.operator.arithmetic.rust"), Some(("syntax", "operators")));
+    assert_eq!(scope_slot("constant.numeric.integer"), Some(("syntax", "numbers")));
+    assert_eq!(scope_slot("constant.language.null"), Some(("syntax", "constants")));
+    assert_eq!(scope_slot("markup.inserted.diff"), Some(("diff", "added")));
+    assert_eq!(scope_slot("keywordish"), None);
+    assert_eq!(scope_slot("source.rust"), None);
+}
+
+#[test]
+fn parse_font_style_words() {
+    let style = parse_font_style("bold  italic wavy");
+    assert!(style.bold && style.italic && !style.underline);
+    assert!(parse_font_style("").is_plain());
+}
+
+#[test]
+fn tmtheme_globals_and_scopes() {
+    let manifest = from_tmtheme(MONOKAI, "monokai").unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+
+    let meta = palette.meta.as_ref().unwrap();
+    assert_eq!(&*meta.name, "Monokai");
+    assert_eq!(&*meta.style, "dark");
+    assert_eq!(palette.base.background.unwrap().to_hex(), "#272822");
+    assert_eq!(palette.editor.cursor.unwrap().to_hex(), "#F8F8F0");
+    assert_eq!(palette.editor.selection_bg.unwrap().to_hex(), "#49483E");
+    assert_eq!(palette.surface.highlight.unwrap().to_hex(), "#3E3D32");
+    assert_eq!(palette.typography.gutter.unwrap().to_hex(), "#3B3A32");
+
+    assert_eq!(palette.syntax.comments.unwrap().to_hex(), "#75715E");
+    assert!(palette.styles.syntax.get("comments").unwrap().italic);
+    assert_eq!(palette.syntax.numbers.unwrap().to_hex(), "#AE81FF");
+    assert_eq!(palette.syntax.keywords.unwrap().to_hex(), "#F92672");
+    assert_eq!(palette.syntax.functions.unwrap().to_hex(), "#A6E22E");
+    assert_eq!(palette.semantic.error.unwrap().to_hex(), "#F8F8F0");
+}
+
+#[test]
+fn tmtheme_errors() {
+    let err = from_tmtheme("<plist><dict><key>settings</key><array/></dict></plist>", "x").unwrap_err();
+    assert!(matches!(&err, PaletteError::InvalidTheme { reason, .. } if reason.contains("background")), "{err}");
+    let err = from_tmtheme("<plist><dict><key>name</key>", "x").unwrap_err();
+    assert!(matches!(err, PaletteError::InvalidTheme { .. }), "{err}");
+}
+
+#[test]
+fn registry_imports_tmtheme_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("Monokai.tmTheme"), MONOKAI).unwrap();
+
+    let mut reg = Registry::new();
+    reg.add_dir(dir.path()).unwrap();
+    let palette = reg.load("Monokai").unwrap();
+    assert_eq!(palette.syntax.keywords.unwrap().to_hex(), "#F92672");
+}
+
+#[test]
+fn tmtheme_export_round_trips() {
+    let palette = palette_core::preset("tokyonight").unwrap();
+    let xml = palette.to_tmtheme();
+    assert!(xml.starts_with("<?xml"));
+    assert!(xml.contains("<string>keyword.operator</string>"));
+
+    let imported = Palette::from_manifest(&from_tmtheme(&xml, "tokyonight-tm").unwrap()).unwrap();
+    assert_eq!(&*imported.meta.as_ref().unwrap().name, "TokyoNight (Night)");
+    assert_eq!(imported.base.background, palette.base.background);
+    assert_eq!(imported.editor.selection_bg, palette.editor.selection_bg.or(palette.surface.selection));
+    for rule in palette_core::textmate::scope_rules(&palette) {
+        assert_eq!(imported.slot(rule.slot.0, rule.slot.1), Some(&rule.foreground), "{:?}", rule.slot);
+    }
+}
+
+#[test]
+fn tmtheme_export_escapes_name() {
+    let mut manifest = from_tmtheme(MONOKAI, "monokai").unwrap();
+    manifest.meta.as_mut().unwrap().name = "Salt & <Pepper>".into();
+    let xml = Palette::from_manifest(&manifest).unwrap().to_tmtheme();
+
+    assert!(xml.contains("<string>Salt &amp; &lt;Pepper&gt;</string>"));
+    let imported = from_tmtheme(&xml, "x").unwrap();
+    assert_eq!(&*imported.meta.unwrap().name, "Salt & <Pepper>");
+}
+
+#[test]
+fn deeply_nested_plist_is_an_error() {
+    let err = from_tmtheme(&format!("<plist>{}", "<array>".repeat(200_000)), "x").unwrap_err();
+    assert!(err.to_string().contains("nesting too deep"), "{err}");
+}
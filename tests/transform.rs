@@ -0,0 +1,125 @@
+use palette_core::color::Color;
+use palette_core::palette::Palette;
+use palette_core::registry::load_preset;
+
+fn color(hex: &str) -> Color {
+    Color::from_hex(hex).unwrap()
+}
+
+#[test]
+fn map_visits_every_populated_slot_with_section_names() {
+    let palette = load_preset("tokyonight").unwrap();
+    let mut seen = Vec::new();
+    palette.map(|section, field, c| {
+        seen.push(format!("{section}.{field}"));
+        c
+    });
+
+    assert!(seen.contains(&"base.background".to_owned()));
+    assert!(seen.contains(&"syntax.keywords".to_owned()));
+    assert!(seen.contains(&"terminal.bright_white".to_owned()));
+    #[cfg(feature = "platform")]
+    assert!(seen.contains(&"platform.terminal.background".to_owned()));
+}
+
+#[test]
+fn map_identity_preserves_palette() {
+    let palette = load_preset("catppuccin").unwrap();
+    assert_eq!(palette.map(|_, _, c| c), palette);
+}
+
+#[test]
+fn map_leaves_unset_slots_unset() {
+    let palette = Palette::default();
+    let mapped = palette.map(|_, _, _| color("#FF0000"));
+    assert_eq!(mapped.base.background, Some(color("#FF0000")));
+    assert!(mapped.syntax.keywords.is_none());
+    assert!(mapped.terminal_ansi.red.is_none());
+}
+
+#[test]
+fn map_can_target_one_section() {
+    let palette = load_preset("nord").unwrap();
+    let mapped = palette.map(|section, _, c| match section {
+        "syntax" => c.lighten(0.1),
+        _ => c,
+    });
+    assert_eq!(mapped.base, palette.base);
+    assert_ne!(mapped.syntax, palette.syntax);
+}
+
+#[test]
+fn tinted_full_amount_is_solid_tint() {
+    let tint = color("#336699");
+    let tinted = load_preset("nord").unwrap().tinted(tint, 1.0);
+    assert_eq!(tinted.base.background, Some(tint));
+    assert_eq!(tinted.terminal_ansi.red, Some(tint));
+}
+
+#[test]
+fn desaturated_reduces_saturation() {
+    let palette = load_preset("dracula").unwrap();
+    let muted = palette.desaturated(1.0);
+    let red = muted.terminal_ansi.red.unwrap();
+    assert_eq!(red.r, red.g);
+    assert_eq!(red.g, red.b);
+}
+
+#[test]
+fn dimmed_darkens_every_slot() {
+    let palette = load_preset("tokyonight").unwrap();
+    let dim = palette.dimmed(0.5);
+    let before = palette.base.foreground.unwrap().relative_luminance();
+    let after = dim.base.foreground.unwrap().relative_luminance();
+    assert!(after < before);
+    assert_eq!(palette.dimmed(1.0).syntax.keywords, Some(color("#000000")));
+}
+
+#[test]
+fn grayscale_produces_neutral_slots() {
+    let gray = load_preset("catppuccin").unwrap().grayscale();
+    for (_, c) in gray.syntax.populated_slots() {
+        assert!(c.r.abs_diff(c.g) <= 1 && c.g.abs_diff(c.b) <= 1, "not neutral: {c}");
+    }
+}
+
+#[test]
+fn hue_rotated_full_turn_is_near_identity() {
+    let palette = load_preset("gruvbox_dark").unwrap();
+    let rotated = palette.hue_rotated(360.0);
+    let (a, b) = (palette.syntax.strings.unwrap(), rotated.syntax.strings.unwrap());
+    assert!(a.delta_e(b) < 1.0);
+}
+
+#[test]
+fn transforms_keep_meta() {
+    let palette = load_preset("nord").unwrap();
+    assert_eq!(palette.grayscale().meta, palette.meta);
+}
+
+#[cfg(feature = "platform")]
+#[test]
+fn transforms_apply_to_platform_overrides() {
+    let palette = load_preset("tokyonight").unwrap();
+    let dim = palette.dimmed(1.0);
+    let terminal = dim.platform.get("terminal").unwrap();
+    assert_eq!(terminal.background, Some(color("#000000")));
+}
+
+#[test]
+fn transforms_apply_to_custom_sections() {
+    let mut palette = Palette::default();
+    palette.custom.entry("chart".into()).or_default().insert("series_1".into(), color("#ff0000"));
+
+    let mut seen = Vec::new();
+    let mapped = palette.map(|section, field, c| {
+        seen.push(format!("{section}.{field}"));
+        c
+    });
+    assert!(seen.contains(&"chart.series_1".to_owned()));
+    assert_eq!(mapped.custom, palette.custom);
+
+    let gray = palette.grayscale();
+    let c = gray.custom("chart", "series_1").unwrap();
+    assert!(c.r.abs_diff(c.g) <= 1 && c.g.abs_diff(c.b) <= 1);
+}
@@ -0,0 +1,218 @@
+use serde_json::Value;
+
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::preset;
+use palette_core::textmate::{font_style, scope_rules, SCOPES};
+use palette_core::style::TextStyle;
+use palette_core::vscode::{from_vscode_theme, is_vscode_theme};
+use palette_core::error::PaletteError;
+
+mod common;
+
+fn theme(palette: &Palette) -> Value {
+    serde_json::from_str(&palette.to_vscode_theme()).unwrap()
+}
+
+fn hex(color: Option<palette_core::Color>) -> Value {
+    Value::String(color.unwrap().to_hex())
+}
+
+#[test]
+fn header_fields() {
+    let json = theme(&preset("tokyonight").unwrap());
+    assert_eq!(json["name"], "TokyoNight (Night)");
+    assert_eq!(json["type"], "dark");
+    assert_eq!(json["semanticHighlighting"], true);
+    assert_eq!(theme(&preset("github_light").unwrap())["type"], "light");
+}
+
+#[test]
+fn workbench_colors_map_slots() {
+    let palette = preset("tokyonight").unwrap();
+    let colors = &theme(&palette)["colors"];
+
+    assert_eq!(colors["editor.background"], hex(palette.base.background));
+    assert_eq!(colors["editor.selectionBackground"], hex(palette.editor.selection_bg));
+    assert_eq!(colors["diffEditor.insertedLineBackground"], hex(palette.diff.added_bg));
+    assert_eq!(colors["statusBar.background"], hex(palette.surface.statusline));
+    assert_eq!(colors["sideBar.background"], hex(palette.surface.sidebar));
+    assert_eq!(colors["terminal.ansiBrightMagenta"], hex(palette.terminal_ansi.bright_magenta));
+    assert_eq!(
+        colors["editorError.foreground"],
+        hex(palette.editor.diagnostic_underline_error.or(palette.editor.diagnostic_error)),
+    );
+}
+
+#[test]
+fn unset_workbench_colors_are_omitted() {
+    let manifest = common::manifest_with_base(std::collections::BTreeMap::from([(
+        std::sync::Arc::from("background"),
+        std::sync::Arc::from("#000000"),
+    )]));
+    let json = theme(&Palette::from_manifest(&manifest).unwrap());
+
+    assert_eq!(json["colors"]["editor.background"], "#000000");
+    assert!(json["colors"].get("terminal.ansiRed").is_none());
+    assert_eq!(json["tokenColors"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+fn token_colors_cover_syntax_slots() {
+    let palette = preset("tokyonight").unwrap();
+    let json = theme(&palette);
+    let tokens = json["tokenColors"].as_array().unwrap();
+
+    assert_eq!(tokens.len(), scope_rules(&palette).len());
+    let keyword = tokens.iter().find(|t| t["name"] == "syntax.keywords").unwrap();
+    assert_eq!(keyword["settings"]["foreground"], hex(palette.syntax.keywords));
+    assert!(keyword["scope"].as_array().unwrap().contains(&Value::from("keyword")));
+}
+
+#[test]
+fn token_and_semantic_styles() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#000000"
+
+[syntax]
+comments = { fg = "#565F89", italic = true }
+variables_builtin = { fg = "#F7768E", bold = true }
+"##,
+    )
+    .unwrap();
+    let json = theme(&Palette::from_manifest(&manifest).unwrap());
+
+    let comment = &json["tokenColors"][0];
+    assert_eq!(comment["settings"]["fontStyle"], "italic");
+    let semantic = &json["semanticTokenColors"];
+    assert_eq!(semantic["comment"]["foreground"], "#565F89");
+    assert_eq!(semantic["comment"]["italic"], true);
+    assert_eq!(semantic["variable.defaultLibrary"]["bold"], true);
+}
+
+#[test]
+fn semantic_tokens_follow_manifest_overrides() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#000000"
+
+[syntax]
+properties = "#73DACA"
+variables = "#C0CAF5"
+
+[semantic_tokens]
+variable = "properties"
+"##,
+    )
+    .unwrap();
+    let json = theme(&Palette::from_manifest(&manifest).unwrap());
+    assert_eq!(json["semanticTokenColors"]["variable"], "#73DACA");
+    assert_eq!(json["semanticTokenColors"]["property"], "#73DACA");
+}
+
+#[test]
+fn font_style_words() {
+    let style = TextStyle { bold: true, undercurl: true, ..TextStyle::default() };
+    assert_eq!(font_style(&style), "bold underline");
+    assert_eq!(font_style(&TextStyle::default()), "");
+}
+
+#[test]
+fn scope_table_has_no_duplicate_selectors() {
+    let mut seen = std::collections::BTreeSet::new();
+    for (_, scopes) in SCOPES {
+        for scope in scopes {
+            assert!(seen.insert(*scope), "{scope} listed twice");
+        }
+    }
+}
+
+#[test]
+fn every_preset_exports_valid_json() {
+    for id in palette_core::preset_ids() {
+        let json = theme(&preset(id).unwrap());
+        assert!(json["colors"].as_object().unwrap().len() > 40, "{id}");
+    }
+}
+
+#[test]
+fn import_round_trips_exported_theme() {
+    let palette = preset("tokyonight").unwrap();
+    let manifest = from_vscode_theme(&palette.to_vscode_theme(), "tokyonight-vscode").unwrap();
+    let imported = Palette::from_manifest(&manifest).unwrap();
+
+    let meta = imported.meta.as_ref().unwrap();
+    assert_eq!(&*meta.name, "TokyoNight (Night)");
+    assert_eq!(&*meta.style, "dark");
+    assert_eq!(imported.base.background, palette.base.background);
+    assert_eq!(imported.surface.statusline, palette.surface.statusline);
+    assert_eq!(imported.diff.added_bg, palette.diff.added_bg);
+    assert_eq!(imported.terminal_ansi.bright_magenta, palette.terminal_ansi.bright_magenta);
+    for rule in scope_rules(&palette) {
+        assert_eq!(imported.slot(rule.slot.0, rule.slot.1), Some(&rule.foreground), "{:?}", rule.slot);
+    }
+    assert_eq!(imported.styles.syntax.get("comments"), palette.styles.syntax.get("comments"));
+}
+
+#[test]
+fn import_accepts_comments_and_trailing_commas() {
+    let json = r##"{
+  // exported by hand
+  "name": "Scratch",
+  "colors": {
+    "editor.background": "#1e1e1e",
+    "editor.foreground": "#d4d4d4",
+    "editor.selectionBackground": "#ffffff80", /* 50% white */
+  },
+  "tokenColors": [
+    { "scope": "comment", "settings": { "foreground": "#6A9955", "fontStyle": "italic" } },
+    { "scope": ["keyword.operator.arithmetic", "source.rust keyword"], "settings": { "foreground": "#569CD6" } },
+    { "scope": "entity.name.function, support.function", "settings": { "foreground": "#DCDCAA" } },
+    { "scope": "constant.numeric - constant.numeric.hex", "settings": { "foreground": "#B5CEA8" } },
+  ],
+  "semanticTokenColors": {
+    "parameter:rust": "#9CDCFE",
+    "variable.readonly": { "foreground": "#4FC1FF", "bold": true },
+  },
+}"##;
+    assert!(is_vscode_theme(json));
+    let palette = Palette::from_manifest(&from_vscode_theme(json, "scratch").unwrap()).unwrap();
+
+    assert_eq!(palette.meta.as_ref().unwrap().style.as_ref(), "dark");
+    assert_eq!(palette.base.foreground.unwrap().to_hex(), "#D4D4D4");
+    assert_eq!(palette.editor.selection_bg.unwrap().to_hex(), "#8F8F8F");
+    assert_eq!(palette.syntax.comments.unwrap().to_hex(), "#6A9955");
+    assert!(palette.styles.syntax.get("comments").unwrap().italic);
+    assert_eq!(palette.syntax.operators.unwrap().to_hex(), "#569CD6");
+    assert_eq!(palette.syntax.keywords.unwrap().to_hex(), "#569CD6");
+    assert_eq!(palette.syntax.functions.unwrap().to_hex(), "#DCDCAA");
+    assert_eq!(palette.syntax.numbers.unwrap().to_hex(), "#B5CEA8");
+    assert_eq!(palette.syntax.parameters.unwrap().to_hex(), "#9CDCFE");
+    assert_eq!(palette.syntax.constants.unwrap().to_hex(), "#4FC1FF");
+    assert!(palette.styles.syntax.get("constants").unwrap().bold);
+}
+
+#[test]
+fn import_requires_editor_background() {
+    let err = from_vscode_theme(r#"{ "tokenColors": [] }"#, "x").unwrap_err();
+    assert!(matches!(&err, PaletteError::InvalidTheme { reason, .. } if reason.contains("editor.background")), "{err}");
+    let err = from_vscode_theme("{ \"colors\": ", "x").unwrap_err();
+    assert!(matches!(err, PaletteError::InvalidTheme { .. }), "{err}");
+    assert!(!is_vscode_theme(r##"{ "name": "Campbell", "background": "#0C0C0C" }"##));
+}
+
+#[test]
+fn registry_imports_vscode_themes() {
+    let dir = tempfile::tempdir().unwrap();
+    let theme = preset("github_light").unwrap().to_vscode_theme();
+    std::fs::write(dir.path().join("github-color-theme.json"), theme).unwrap();
+
+    let mut reg = palette_core::Registry::new();
+    reg.add_dir(dir.path()).unwrap();
+    let palette = reg.load("github-color-theme").unwrap();
+    assert_eq!(palette.base.background, preset("github_light").unwrap().base.background);
+    assert_eq!(palette.meta.as_ref().unwrap().style.as_ref(), "light");
+}
@@ -220,3 +220,36 @@ fn js_registry_by_style_nonexistent_returns_empty() {
     let reg = JsRegistry::new();
     assert!(reg.by_style("nonexistent").is_empty());
 }
+
+#[test]
+fn palette_diff_returns_json_and_report() {
+    let night = load_preset("tokyonight").unwrap();
+    let storm = load_preset("tokyonight_storm").unwrap();
+
+    let json = night.diff(&storm).unwrap();
+    assert!(json.contains("\"preset_id\""));
+    assert!(json.contains("\"changed\""));
+
+    let report = night.diff_report(&storm);
+    assert!(report.starts_with("slot"));
+    assert!(report.contains("base.background"));
+}
+
+#[test]
+fn interpolate_js_blends_between_presets() {
+    use palette_core::wasm::interpolate_js;
+
+    let a = load_preset("tokyonight").unwrap();
+    let b = load_preset("nord").unwrap();
+    let start = interpolate_js(&a, &b, 0.0);
+    assert_eq!(start.name(), a.name());
+    let end = interpolate_js(&a, &b, 1.0);
+    assert_eq!(end.name(), b.name());
+}
+
+#[test]
+fn high_contrast_renames_palette() {
+    let palette = load_preset("nord").unwrap();
+    let hc = palette.high_contrast("aaa").unwrap();
+    assert_eq!(hc.preset_id(), Some("nord+hc".to_owned()));
+}
@@ -0,0 +1,89 @@
+use serde_json::Value;
+
+use palette_core::manifest::PaletteManifest;
+use palette_core::palette::Palette;
+use palette_core::preset;
+use palette_core::zed::{STYLE_COLORS, SYNTAX};
+
+mod common;
+
+fn style(palette: &Palette) -> Value {
+    let family: Value = serde_json::from_str(&palette.to_zed_theme()).unwrap();
+    assert_eq!(family["themes"].as_array().unwrap().len(), 1);
+    family["themes"][0]["style"].clone()
+}
+
+fn hex(color: Option<palette_core::Color>) -> Value {
+    Value::String(color.unwrap().to_hex())
+}
+
+#[test]
+fn family_header() {
+    let json: Value = serde_json::from_str(&preset("tokyonight").unwrap().to_zed_theme()).unwrap();
+    assert_eq!(json["name"], "TokyoNight (Night)");
+    assert_eq!(json["themes"][0]["name"], "TokyoNight (Night)");
+    assert_eq!(json["themes"][0]["appearance"], "dark");
+
+    let light: Value = serde_json::from_str(&preset("github_light").unwrap().to_zed_theme()).unwrap();
+    assert_eq!(light["themes"][0]["appearance"], "light");
+}
+
+#[test]
+fn style_colors_map_slots() {
+    let palette = preset("tokyonight").unwrap();
+    let style = style(&palette);
+
+    assert_eq!(style["editor.background"], hex(palette.base.background));
+    assert_eq!(style["editor.foreground"], hex(palette.base.foreground));
+    assert_eq!(style["created"], hex(palette.diff.added));
+    assert_eq!(style["error"], hex(palette.semantic.error));
+    assert_eq!(style["terminal.ansi.black"], hex(palette.terminal_ansi.black));
+    assert_eq!(style["terminal.ansi.bright_magenta"], hex(palette.terminal_ansi.bright_magenta));
+
+    let player = &style["players"][0];
+    assert_eq!(player["cursor"], hex(palette.cursor_pair().bg));
+    assert_eq!(player["selection"], hex(palette.selection_pair().bg));
+}
+
+#[test]
+fn syntax_entries_carry_styles() {
+    let manifest = PaletteManifest::from_toml(
+        r##"
+[base]
+background = "#000000"
+
+[syntax]
+comments = { fg = "#565F89", italic = true }
+keywords = { fg = "#BB9AF7", bold = true }
+strings = "#9ECE6A"
+"##,
+    )
+    .unwrap();
+    let style = style(&Palette::from_manifest(&manifest).unwrap());
+    let syntax = &style["syntax"];
+
+    assert_eq!(syntax["comment"]["color"], "#565F89");
+    assert_eq!(syntax["comment"]["font_style"], "italic");
+    assert_eq!(syntax["keyword"]["font_weight"], 700);
+    assert_eq!(syntax["string"], serde_json::json!({ "color": "#9ECE6A" }));
+    assert!(syntax.get("function").is_none());
+    assert!(style.get("terminal.ansi.red").is_none());
+}
+
+#[test]
+fn tables_have_no_duplicate_keys() {
+    for table in [STYLE_COLORS, SYNTAX] {
+        let mut seen = std::collections::BTreeSet::new();
+        for (key, _) in table {
+            assert!(seen.insert(*key), "{key} listed twice");
+        }
+    }
+}
+
+#[test]
+fn every_preset_exports_valid_json() {
+    for id in palette_core::preset_ids() {
+        let style = style(&preset(id).unwrap());
+        assert!(style["syntax"].as_object().unwrap().len() > 10, "{id}");
+    }
+}